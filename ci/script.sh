@@ -40,6 +40,8 @@ else
         "correct"
         "correct,radix"
         "correct,unchecked_index"
+        "format"
+        "format,radix"
     )
     CORE_FEATURES=(
         "${LEXICAL_FEATURES[@]}"
@@ -165,6 +165,24 @@ enum lexical_error_code {
     lexical_empty_fraction = -5,
     // Empty exponent found.
     lexical_empty_exponent = -6,
+    // Empty integer found, when integer digits are required.
+    lexical_empty_integer = -7,
+    // Invalid positive sign for the mantissa was found.
+    lexical_invalid_positive_mantissa_sign = -8,
+    // Invalid leading zeros were found.
+    lexical_invalid_leading_zeros = -9,
+    // Invalid special value (NaN or infinity) was found.
+    lexical_invalid_special = -10,
+    // Invalid positive sign for the exponent was found.
+    lexical_invalid_positive_exponent_sign = -11,
+    // Exponent was found without a fraction, when a fraction is required.
+    lexical_exponent_without_fraction = -12,
+    // Base prefix was missing or did not match the radix, when a base prefix is required.
+    lexical_invalid_base_prefix = -13,
+    // Type suffix does not fit the parsed type.
+    lexical_invalid_type_suffix = -14,
+    // Buffer is too small to hold the written number.
+    lexical_buffer_too_small = -15,
 };
 
 // C-compatible error for FFI.
@@ -203,6 +221,51 @@ inline bool lexical_error_is_empty_exponent(lexical_error* error)
     return error->code == lexical_empty_exponent;
 }
 
+inline bool lexical_error_is_empty_integer(lexical_error* error)
+{
+    return error->code == lexical_empty_integer;
+}
+
+inline bool lexical_error_is_invalid_positive_mantissa_sign(lexical_error* error)
+{
+    return error->code == lexical_invalid_positive_mantissa_sign;
+}
+
+inline bool lexical_error_is_invalid_leading_zeros(lexical_error* error)
+{
+    return error->code == lexical_invalid_leading_zeros;
+}
+
+inline bool lexical_error_is_invalid_special(lexical_error* error)
+{
+    return error->code == lexical_invalid_special;
+}
+
+inline bool lexical_error_is_invalid_positive_exponent_sign(lexical_error* error)
+{
+    return error->code == lexical_invalid_positive_exponent_sign;
+}
+
+inline bool lexical_error_is_exponent_without_fraction(lexical_error* error)
+{
+    return error->code == lexical_exponent_without_fraction;
+}
+
+inline bool lexical_error_is_invalid_base_prefix(lexical_error* error)
+{
+    return error->code == lexical_invalid_base_prefix;
+}
+
+inline bool lexical_error_is_invalid_type_suffix(lexical_error* error)
+{
+    return error->code == lexical_invalid_type_suffix;
+}
+
+inline bool lexical_error_is_buffer_too_small(lexical_error* error)
+{
+    return error->code == lexical_buffer_too_small;
+}
+
 // RESULT TAG
 
 // Tag for the result type in the tagged enum.
@@ -444,14 +507,38 @@ lexical_partial_result(f64);
         uint8_t* last                                                           \
     )
 
+// Declare extern non-panicking to lexical function definitions for type.
+#define lexical_try_decimal_to_range(type)                                      \
+    extern                                                                      \
+    lexical_result_type(usize)                                                  \
+    lexical_##type##toa_try(                                                    \
+        lexical_type(type) value,                                               \
+        uint8_t* first,                                                         \
+        uint8_t* last                                                           \
+    )
+
+// Declare extern non-panicking to lexical radix function definitions for type.
+#define lexical_try_radix_to_range(type)                                        \
+    extern                                                                      \
+    lexical_result_type(usize)                                                  \
+    lexical_##type##toa_try_radix(                                              \
+        lexical_type(type) value,                                               \
+        uint8_t radix,                                                          \
+        uint8_t* first,                                                         \
+        uint8_t* last                                                           \
+    )
+
 // Declare extern to lexical function definitions.
 #ifdef HAVE_RADIX
     #define lexical_to_lexical(type)                                            \
         lexical_decimal_to_range(type);                                         \
-        lexical_radix_to_range(type)
+        lexical_radix_to_range(type);                                           \
+        lexical_try_decimal_to_range(type);                                     \
+        lexical_try_radix_to_range(type)
 #else   // !HAVE_RADIX
     #define lexical_to_lexical(type)                                            \
-        lexical_decimal_to_range(type)
+        lexical_decimal_to_range(type);                                         \
+        lexical_try_decimal_to_range(type)
 #endif  // HAVE_RADIX
 
 // FROM LEXICAL
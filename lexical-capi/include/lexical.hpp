@@ -222,6 +222,15 @@ enum class error_code: int32_t {
     empty = ::lexical_empty,
     empty_fraction = ::lexical_empty_fraction,
     empty_exponent = ::lexical_empty_exponent,
+    empty_integer = ::lexical_empty_integer,
+    invalid_positive_mantissa_sign = ::lexical_invalid_positive_mantissa_sign,
+    invalid_leading_zeros = ::lexical_invalid_leading_zeros,
+    invalid_special = ::lexical_invalid_special,
+    invalid_positive_exponent_sign = ::lexical_invalid_positive_exponent_sign,
+    exponent_without_fraction = ::lexical_exponent_without_fraction,
+    invalid_base_prefix = ::lexical_invalid_base_prefix,
+    invalid_type_suffix = ::lexical_invalid_type_suffix,
+    buffer_too_small = ::lexical_buffer_too_small,
 };
 
 // C-compatible error type.
@@ -260,6 +269,51 @@ struct error
         return code == error_code::empty_exponent;
     }
 
+    inline bool is_empty_integer()
+    {
+        return code == error_code::empty_integer;
+    }
+
+    inline bool is_invalid_positive_mantissa_sign()
+    {
+        return code == error_code::invalid_positive_mantissa_sign;
+    }
+
+    inline bool is_invalid_leading_zeros()
+    {
+        return code == error_code::invalid_leading_zeros;
+    }
+
+    inline bool is_invalid_special()
+    {
+        return code == error_code::invalid_special;
+    }
+
+    inline bool is_invalid_positive_exponent_sign()
+    {
+        return code == error_code::invalid_positive_exponent_sign;
+    }
+
+    inline bool is_exponent_without_fraction()
+    {
+        return code == error_code::exponent_without_fraction;
+    }
+
+    inline bool is_invalid_base_prefix()
+    {
+        return code == error_code::invalid_base_prefix;
+    }
+
+    inline bool is_invalid_type_suffix()
+    {
+        return code == error_code::invalid_type_suffix;
+    }
+
+    inline bool is_buffer_too_small()
+    {
+        return code == error_code::buffer_too_small;
+    }
+
     inline friend bool operator==(const error& lhs, const error& rhs)
     {
         return std::make_tuple(lhs.code, lhs.index) == std::make_tuple(rhs.code, rhs.index);
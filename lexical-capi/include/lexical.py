@@ -222,6 +222,15 @@ class ErrorCode(enum.Enum):
     Empty = -4
     EmptyFraction = -5
     EmptyExponent = -6
+    EmptyInteger = -7
+    InvalidPositiveMantissaSign = -8
+    InvalidLeadingZeros = -9
+    InvalidSpecial = -10
+    InvalidPositiveExponentSign = -11
+    ExponentWithoutFraction = -12
+    InvalidBasePrefix = -13
+    InvalidTypeSuffix = -14
+    BufferTooSmall = -15
 
 class Error(Structure):
     '''C-compatible error for FFI.'''
@@ -259,6 +268,33 @@ class Error(Structure):
     def is_empty_exponent(self):
         return self.code == ErrorCode.EmptyExponent
 
+    def is_empty_integer(self):
+        return self.code == ErrorCode.EmptyInteger
+
+    def is_invalid_positive_mantissa_sign(self):
+        return self.code == ErrorCode.InvalidPositiveMantissaSign
+
+    def is_invalid_leading_zeros(self):
+        return self.code == ErrorCode.InvalidLeadingZeros
+
+    def is_invalid_special(self):
+        return self.code == ErrorCode.InvalidSpecial
+
+    def is_invalid_positive_exponent_sign(self):
+        return self.code == ErrorCode.InvalidPositiveExponentSign
+
+    def is_exponent_without_fraction(self):
+        return self.code == ErrorCode.ExponentWithoutFraction
+
+    def is_invalid_base_prefix(self):
+        return self.code == ErrorCode.InvalidBasePrefix
+
+    def is_invalid_type_suffix(self):
+        return self.code == ErrorCode.InvalidTypeSuffix
+
+    def is_buffer_too_small(self):
+        return self.code == ErrorCode.BufferTooSmall
+
 class LexicalError(Exception):
     '''Python-native exception raised during errors in lexical parsing.'''
 
@@ -279,6 +315,24 @@ class LexicalError(Exception):
             return 'Empty fraction found, starting at index {}'.format(self.error.index)
         elif code == ErrorCode.EmptyExponent:
             return 'Empty exponent found, starting at index {}'.format(self.error.index)
+        elif code == ErrorCode.EmptyInteger:
+            return 'Empty integer found, starting at index {}'.format(self.error.index)
+        elif code == ErrorCode.InvalidPositiveMantissaSign:
+            return 'Invalid positive mantissa sign found at index {}'.format(self.error.index)
+        elif code == ErrorCode.InvalidLeadingZeros:
+            return 'Invalid leading zeros found at index {}'.format(self.error.index)
+        elif code == ErrorCode.InvalidSpecial:
+            return 'Invalid special value found at index {}'.format(self.error.index)
+        elif code == ErrorCode.InvalidPositiveExponentSign:
+            return 'Invalid positive exponent sign at {}.'.format(self.error.index)
+        elif code == ErrorCode.ExponentWithoutFraction:
+            return 'Exponent without fraction at {}.'.format(self.error.index)
+        elif code == ErrorCode.InvalidBasePrefix:
+            return 'Invalid base prefix at {}.'.format(self.error.index)
+        elif code == ErrorCode.InvalidTypeSuffix:
+            return 'Invalid type suffix at {}.'.format(self.error.index)
+        elif code == ErrorCode.BufferTooSmall:
+            return 'Buffer too small, {} bytes required.'.format(self.error.index)
         else:
             raise ValueError('Invalid ErrorCode for lexical error.')
 
@@ -272,11 +272,81 @@ macro_rules! radix_to_range {
     );
 }
 
+/// Macro to generate the non-panicking decimal to_string API using a range.
+macro_rules! try_decimal_to_range {
+    ($name:ident, $cb:ident, $t:ty) => (
+        /// Serializer for a number-to-string conversions.
+        ///
+        /// Returns a C-compatible result containing the number of written
+        /// bytes, at the start of the range `[first, last)`, or a
+        /// `BufferTooSmall` error containing the number of bytes required,
+        /// if the range is too small. No null-terminator is written.
+        ///
+        /// The data in the range may be uninitialized, these values are
+        /// never read, only written to.
+        ///
+        /// * `value`   - Number to serialize.
+        /// * `first`   - Pointer to the start of the buffer to write to.
+        /// * `last`    - Pointer to the one-past-the-end of the buffer to write to.
+        ///
+        /// # Panics
+        ///
+        /// Panics if either pointer is null.
+        #[doc(hidden)]
+        #[no_mangle]
+        pub unsafe extern fn $name(value: $t, first: *mut u8, last: *mut u8)
+            -> $crate::result::Result<usize>
+        {
+            let bytes = $crate::api::slice_from_range_mut(first, last);
+            lexical_core::$cb(value, bytes).map(|slc| slc.len()).into()
+        }
+    );
+}
+
+/// Macro to generate the non-panicking radix to_string API using a range.
+macro_rules! try_radix_to_range {
+    ($name:ident, $cb:ident, $t:ty) => (
+        /// Serializer for a number-to-string conversions.
+        ///
+        /// Returns a C-compatible result containing the number of written
+        /// bytes, at the start of the range `[first, last)`, or a
+        /// `BufferTooSmall` error containing the number of bytes required,
+        /// if the range is too small. No null-terminator is written.
+        ///
+        /// The data in the range may be uninitialized, these values are
+        /// never read, only written to.
+        ///
+        /// * `value`   - Number to serialize.
+        /// * `radix`   - Radix for number encoding.
+        /// * `first`   - Pointer to the start of the buffer to write to.
+        /// * `last`    - Pointer to the one-past-the-end of the buffer to write to.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the radix is not in the range `[2, 36]`. Also panics
+        /// if either pointer is null.
+        #[cfg(feature = "radix")]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub unsafe extern fn $name(value: $t, radix: u8, first: *mut u8, last: *mut u8)
+            -> $crate::result::Result<usize>
+        {
+            let bytes = $crate::api::slice_from_range_mut(first, last);
+            lexical_core::$cb(value, radix, bytes).map(|slc| slc.len()).into()
+        }
+    );
+}
+
 // Macro to generate serializers implementing the ToLexical trait.
 macro_rules! to_lexical {
-    ($decimal_name:ident, $radix_name:ident, $t:ty) => (
+    (
+        $decimal_name:ident, $radix_name:ident,
+        $try_decimal_name:ident, $try_radix_name:ident, $t:ty
+    ) => (
         decimal_to_range!($decimal_name, write, $t);
         radix_to_range!($radix_name, write_radix, $t);
+        try_decimal_to_range!($try_decimal_name, try_write, $t);
+        try_radix_to_range!($try_radix_name, try_write_radix, $t);
     );
 }
 
@@ -304,20 +374,20 @@ from_lexical!(lexical_atoisize, lexical_atoisize_partial, lexical_atoisize_radix
 #[cfg(has_i128)] from_lexical!(lexical_atoi128, lexical_atoi128_partial, lexical_atoi128_radix, lexical_atoi128_partial_radix, i128);
 
 // FTOA
-to_lexical!(lexical_f32toa, lexical_f32toa_radix, f32);
-to_lexical!(lexical_f64toa, lexical_f64toa_radix, f64);
+to_lexical!(lexical_f32toa, lexical_f32toa_radix, lexical_f32toa_try, lexical_f32toa_try_radix, f32);
+to_lexical!(lexical_f64toa, lexical_f64toa_radix, lexical_f64toa_try, lexical_f64toa_try_radix, f64);
 
 // ITOA
-to_lexical!(lexical_u8toa, lexical_u8toa_radix, u8);
-to_lexical!(lexical_u16toa, lexical_u16toa_radix, u16);
-to_lexical!(lexical_u32toa, lexical_u32toa_radix, u32);
-to_lexical!(lexical_u64toa, lexical_u64toa_radix, u64);
-to_lexical!(lexical_usizetoa, lexical_usizetoa_radix, usize);
-#[cfg(has_i128)] to_lexical!(lexical_u128toa, lexical_u128toa_radix, u128);
+to_lexical!(lexical_u8toa, lexical_u8toa_radix, lexical_u8toa_try, lexical_u8toa_try_radix, u8);
+to_lexical!(lexical_u16toa, lexical_u16toa_radix, lexical_u16toa_try, lexical_u16toa_try_radix, u16);
+to_lexical!(lexical_u32toa, lexical_u32toa_radix, lexical_u32toa_try, lexical_u32toa_try_radix, u32);
+to_lexical!(lexical_u64toa, lexical_u64toa_radix, lexical_u64toa_try, lexical_u64toa_try_radix, u64);
+to_lexical!(lexical_usizetoa, lexical_usizetoa_radix, lexical_usizetoa_try, lexical_usizetoa_try_radix, usize);
+#[cfg(has_i128)] to_lexical!(lexical_u128toa, lexical_u128toa_radix, lexical_u128toa_try, lexical_u128toa_try_radix, u128);
 
-to_lexical!(lexical_i8toa, lexical_i8toa_radix, i8);
-to_lexical!(lexical_i16toa, lexical_i16toa_radix, i16);
-to_lexical!(lexical_i32toa, lexical_i32toa_radix, i32);
-to_lexical!(lexical_i64toa, lexical_i64toa_radix, i64);
-to_lexical!(lexical_isizetoa, lexical_isizetoa_radix, isize);
-#[cfg(has_i128)] to_lexical!(lexical_i128toa, lexical_i128toa_radix, i128);
+to_lexical!(lexical_i8toa, lexical_i8toa_radix, lexical_i8toa_try, lexical_i8toa_try_radix, i8);
+to_lexical!(lexical_i16toa, lexical_i16toa_radix, lexical_i16toa_try, lexical_i16toa_try_radix, i16);
+to_lexical!(lexical_i32toa, lexical_i32toa_radix, lexical_i32toa_try, lexical_i32toa_try_radix, i32);
+to_lexical!(lexical_i64toa, lexical_i64toa_radix, lexical_i64toa_try, lexical_i64toa_try_radix, i64);
+to_lexical!(lexical_isizetoa, lexical_isizetoa_radix, lexical_isizetoa_try, lexical_isizetoa_try_radix, isize);
+#[cfg(has_i128)] to_lexical!(lexical_i128toa, lexical_i128toa_radix, lexical_i128toa_try, lexical_i128toa_try_radix, i128);
@@ -28,8 +28,12 @@ macro_rules! set_string {
             -1
         } else {
             let slc = slice::from_raw_parts($ptr, $size);
-            lexical_core::$cb(slc);
-            0
+            if slc.is_ascii() {
+                lexical_core::$cb(slc);
+                0
+            } else {
+                -1
+            }
         }
     };
 }
@@ -59,7 +63,8 @@ pub unsafe extern fn lexical_get_exponent_default_char() -> u8
 ///
 /// # Panics
 ///
-/// Panics if the character is in the character set `[A-Da-d.+\-]`.
+/// Panics if the character is not ASCII, or is in the character set
+/// `[A-Da-d.+\-]`.
 #[doc(hidden)]
 #[no_mangle]
 pub unsafe extern fn lexical_set_exponent_default_char(ch: u8)
@@ -95,7 +100,8 @@ pub unsafe extern fn lexical_get_exponent_backup_char() -> u8
 ///
 /// # Panics
 ///
-/// Panics if the character is in the character set `[A-Za-z.+\-]`.
+/// Panics if the character is not ASCII, or is in the character set
+/// `[A-Za-z.+\-]`.
 #[cfg(feature ="radix")]
 #[doc(hidden)]
 #[no_mangle]
@@ -153,7 +159,7 @@ pub unsafe extern fn lexical_get_nan_string(ptr: *mut *const u8, size: *mut usiz
 
 /// Set representation of Not a Number from a pointer and size.
 ///
-/// Returns 0 on success, -1 on error.
+/// Returns 0 on success, -1 on error, such as if the string is not ASCII.
 ///
 /// * `ptr`     - Pointer to the first character in the contiguous string.
 /// * `size`    - Size of the string, without the null-terminator.
@@ -186,7 +192,7 @@ pub unsafe extern fn lexical_get_inf_string(ptr: *mut *const u8, size: *mut usiz
 
 /// Set the short representation of Infinity from a pointer and size.
 ///
-/// Returns 0 on success, -1 on error.
+/// Returns 0 on success, -1 on error, such as if the string is not ASCII.
 ///
 /// * `ptr`     - Pointer to the first character in the contiguous string.
 /// * `size`    - Size of the string, without the null-terminator.
@@ -219,7 +225,7 @@ pub unsafe extern fn lexical_get_infinity_string(ptr: *mut *const u8, size: *mut
 
 /// Set the long representation of Infinity from a pointer and size.
 ///
-/// Returns 0 on success, -1 on error.
+/// Returns 0 on success, -1 on error, such as if the string is not ASCII.
 ///
 /// * `ptr`     - Pointer to the first character in the contiguous string.
 /// * `size`    - Size of the string, without the null-terminator.
@@ -237,7 +243,7 @@ pub unsafe extern fn lexical_set_infinity_string(ptr: *const u8, size: usize)
 
 // CONSTANTS
 
-use lexical_core::Number;
+use lexical_core::ToLexical;
 
 /// Maximum number of bytes required to serialize an `i8` value to string.
 #[doc(hidden)]
@@ -267,6 +267,15 @@ TEST(test_set_nan_string, config_tests)
     lexical_set_string(lexical_set_nan_string);
 }
 
+TEST(test_set_nan_string_non_ascii, config_tests)
+{
+    std::string string = "NaN\xff";
+    auto* first = reinterpret_cast<uint8_t const*>(string.data());
+    EXPECT_EQ(lexical_set_nan_string(first, string.length()), -1);
+    lexical_get_string(lexical_get_nan_string);
+    EXPECT_EQ(value, "NaN");
+}
+
 TEST(test_get_inf_string, config_tests)
 {
     lexical_get_string(lexical_get_inf_string);
@@ -373,6 +382,78 @@ TEST(test_is_empty_exponent, error_tests)
     EXPECT_TRUE(lexical_error_is_empty_exponent(&empty_exponent));
 }
 
+TEST(test_is_empty_integer, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error empty_integer = { lexical_empty_integer, 0 };
+    EXPECT_FALSE(lexical_error_is_empty_integer(&overflow));
+    EXPECT_TRUE(lexical_error_is_empty_integer(&empty_integer));
+}
+
+TEST(test_is_invalid_positive_mantissa_sign, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_positive_mantissa_sign = { lexical_invalid_positive_mantissa_sign, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_positive_mantissa_sign(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_positive_mantissa_sign(&invalid_positive_mantissa_sign));
+}
+
+TEST(test_is_invalid_leading_zeros, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_leading_zeros = { lexical_invalid_leading_zeros, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_leading_zeros(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_leading_zeros(&invalid_leading_zeros));
+}
+
+TEST(test_is_invalid_special, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_special = { lexical_invalid_special, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_special(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_special(&invalid_special));
+}
+
+TEST(test_is_invalid_positive_exponent_sign, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_positive_exponent_sign = { lexical_invalid_positive_exponent_sign, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_positive_exponent_sign(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_positive_exponent_sign(&invalid_positive_exponent_sign));
+}
+
+TEST(test_is_exponent_without_fraction, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error exponent_without_fraction = { lexical_exponent_without_fraction, 0 };
+    EXPECT_FALSE(lexical_error_is_exponent_without_fraction(&overflow));
+    EXPECT_TRUE(lexical_error_is_exponent_without_fraction(&exponent_without_fraction));
+}
+
+TEST(test_is_invalid_base_prefix, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_base_prefix = { lexical_invalid_base_prefix, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_base_prefix(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_base_prefix(&invalid_base_prefix));
+}
+
+TEST(test_is_invalid_type_suffix, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_type_suffix = { lexical_invalid_type_suffix, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_type_suffix(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_type_suffix(&invalid_type_suffix));
+}
+
+TEST(test_is_buffer_too_small, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error buffer_too_small = { lexical_buffer_too_small, 0 };
+    EXPECT_FALSE(lexical_error_is_buffer_too_small(&overflow));
+    EXPECT_TRUE(lexical_error_is_buffer_too_small(&buffer_too_small));
+}
+
 // RESULT TESTS
 
 TEST(result, result_tests)
@@ -334,6 +334,78 @@ TEST(test_is_empty_exponent, error_tests)
     EXPECT_TRUE(empty_exponent.is_empty_exponent());
 }
 
+TEST(test_is_empty_integer, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error empty_integer = { error_code::empty_integer, 0 };
+    EXPECT_FALSE(overflow.is_empty_integer());
+    EXPECT_TRUE(empty_integer.is_empty_integer());
+}
+
+TEST(test_is_invalid_positive_mantissa_sign, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_positive_mantissa_sign = { error_code::invalid_positive_mantissa_sign, 0 };
+    EXPECT_FALSE(overflow.is_invalid_positive_mantissa_sign());
+    EXPECT_TRUE(invalid_positive_mantissa_sign.is_invalid_positive_mantissa_sign());
+}
+
+TEST(test_is_invalid_leading_zeros, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_leading_zeros = { error_code::invalid_leading_zeros, 0 };
+    EXPECT_FALSE(overflow.is_invalid_leading_zeros());
+    EXPECT_TRUE(invalid_leading_zeros.is_invalid_leading_zeros());
+}
+
+TEST(test_is_invalid_special, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_special = { error_code::invalid_special, 0 };
+    EXPECT_FALSE(overflow.is_invalid_special());
+    EXPECT_TRUE(invalid_special.is_invalid_special());
+}
+
+TEST(test_is_invalid_positive_exponent_sign, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_positive_exponent_sign = { error_code::invalid_positive_exponent_sign, 0 };
+    EXPECT_FALSE(overflow.is_invalid_positive_exponent_sign());
+    EXPECT_TRUE(invalid_positive_exponent_sign.is_invalid_positive_exponent_sign());
+}
+
+TEST(test_is_exponent_without_fraction, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error exponent_without_fraction = { error_code::exponent_without_fraction, 0 };
+    EXPECT_FALSE(overflow.is_exponent_without_fraction());
+    EXPECT_TRUE(exponent_without_fraction.is_exponent_without_fraction());
+}
+
+TEST(test_is_invalid_base_prefix, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_base_prefix = { error_code::invalid_base_prefix, 0 };
+    EXPECT_FALSE(overflow.is_invalid_base_prefix());
+    EXPECT_TRUE(invalid_base_prefix.is_invalid_base_prefix());
+}
+
+TEST(test_is_invalid_type_suffix, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_type_suffix = { error_code::invalid_type_suffix, 0 };
+    EXPECT_FALSE(overflow.is_invalid_type_suffix());
+    EXPECT_TRUE(invalid_type_suffix.is_invalid_type_suffix());
+}
+
+TEST(test_is_buffer_too_small, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error buffer_too_small = { error_code::buffer_too_small, 0 };
+    EXPECT_FALSE(overflow.is_buffer_too_small());
+    EXPECT_TRUE(buffer_too_small.is_buffer_too_small());
+}
+
 // RESULT TESTS
 
 TEST(result, result_tests)
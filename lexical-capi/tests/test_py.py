@@ -57,6 +57,11 @@ class ConfigTests(unittest.TestCase):
     def test_set_nan_string(self):
         lexical.set_nan_string('NaN')
 
+    def test_set_nan_string_non_ascii(self):
+        with self.assertRaises(OSError):
+            lexical.set_nan_string(b'NaN\xff')
+        self.assertEqual(lexical.get_nan_string(), 'NaN')
+
     def test_get_inf_string(self):
         self.assertEqual(lexical.get_inf_string(), 'inf')
 
@@ -138,6 +143,15 @@ class ErrorTests(unittest.TestCase):
         self.empty = lexical.Error(lexical.ErrorCode.Empty.value, 0)
         self.empty_fraction = lexical.Error(lexical.ErrorCode.EmptyFraction.value, 0)
         self.empty_exponent = lexical.Error(lexical.ErrorCode.EmptyExponent.value, 0)
+        self.empty_integer = lexical.Error(lexical.ErrorCode.EmptyInteger.value, 0)
+        self.invalid_positive_mantissa_sign = lexical.Error(lexical.ErrorCode.InvalidPositiveMantissaSign.value, 0)
+        self.invalid_leading_zeros = lexical.Error(lexical.ErrorCode.InvalidLeadingZeros.value, 0)
+        self.invalid_special = lexical.Error(lexical.ErrorCode.InvalidSpecial.value, 0)
+        self.invalid_positive_exponent_sign = lexical.Error(lexical.ErrorCode.InvalidPositiveExponentSign.value, 0)
+        self.exponent_without_fraction = lexical.Error(lexical.ErrorCode.ExponentWithoutFraction.value, 0)
+        self.invalid_base_prefix = lexical.Error(lexical.ErrorCode.InvalidBasePrefix.value, 0)
+        self.invalid_type_suffix = lexical.Error(lexical.ErrorCode.InvalidTypeSuffix.value, 0)
+        self.buffer_too_small = lexical.Error(lexical.ErrorCode.BufferTooSmall.value, 0)
 
     def test_is_overflow(self):
         self.assertTrue(self.overflow.is_overflow())
@@ -163,6 +177,42 @@ class ErrorTests(unittest.TestCase):
         self.assertFalse(self.overflow.is_empty_exponent())
         self.assertTrue(self.empty_exponent.is_empty_exponent())
 
+    def test_is_empty_integer(self):
+        self.assertFalse(self.overflow.is_empty_integer())
+        self.assertTrue(self.empty_integer.is_empty_integer())
+
+    def test_is_invalid_positive_mantissa_sign(self):
+        self.assertFalse(self.overflow.is_invalid_positive_mantissa_sign())
+        self.assertTrue(self.invalid_positive_mantissa_sign.is_invalid_positive_mantissa_sign())
+
+    def test_is_invalid_leading_zeros(self):
+        self.assertFalse(self.overflow.is_invalid_leading_zeros())
+        self.assertTrue(self.invalid_leading_zeros.is_invalid_leading_zeros())
+
+    def test_is_invalid_special(self):
+        self.assertFalse(self.overflow.is_invalid_special())
+        self.assertTrue(self.invalid_special.is_invalid_special())
+
+    def test_is_invalid_positive_exponent_sign(self):
+        self.assertFalse(self.overflow.is_invalid_positive_exponent_sign())
+        self.assertTrue(self.invalid_positive_exponent_sign.is_invalid_positive_exponent_sign())
+
+    def test_is_exponent_without_fraction(self):
+        self.assertFalse(self.overflow.is_exponent_without_fraction())
+        self.assertTrue(self.exponent_without_fraction.is_exponent_without_fraction())
+
+    def test_is_invalid_base_prefix(self):
+        self.assertFalse(self.overflow.is_invalid_base_prefix())
+        self.assertTrue(self.invalid_base_prefix.is_invalid_base_prefix())
+
+    def test_is_invalid_type_suffix(self):
+        self.assertFalse(self.overflow.is_invalid_type_suffix())
+        self.assertTrue(self.invalid_type_suffix.is_invalid_type_suffix())
+
+    def test_is_buffer_too_small(self):
+        self.assertFalse(self.overflow.is_buffer_too_small())
+        self.assertTrue(self.buffer_too_small.is_buffer_too_small())
+
 class ResultTests(unittest.TestCase):
     '''Test complete and partial result types.'''
 
@@ -32,6 +32,16 @@ pub(super) trait FloatType:
     type ExtendedFloat: ExtendedFloatType<Self>;
 }
 
+impl FloatType for f16 {
+    type Mantissa = Self::Unsigned;
+    type ExtendedFloat = ExtendedFloat<Self::Mantissa>;
+}
+
+impl FloatType for bf16 {
+    type Mantissa = Self::Unsigned;
+    type ExtendedFloat = ExtendedFloat<Self::Mantissa>;
+}
+
 impl FloatType for f32 {
     type Mantissa = Self::Unsigned;
     type ExtendedFloat = ExtendedFloat<Self::Mantissa>;
@@ -69,6 +79,50 @@ pub(super) trait ExtendedFloatType<F: FloatType>:
     fn set_exp(&mut self, i32);
 }
 
+impl ExtendedFloatType<f16> for ExtendedFloat<u16> {
+    perftools_inline!{
+    fn mant(&self) -> u16 {
+        self.mant
+    }}
+
+    perftools_inline!{
+    fn exp(&self) -> i32 {
+        self.exp
+    }}
+
+    perftools_inline!{
+    fn set_mant(&mut self, mant: u16) {
+        self.mant = mant;
+    }}
+
+    perftools_inline!{
+    fn set_exp(&mut self, exp: i32) {
+        self.exp = exp;
+    }}
+}
+
+impl ExtendedFloatType<bf16> for ExtendedFloat<u16> {
+    perftools_inline!{
+    fn mant(&self) -> u16 {
+        self.mant
+    }}
+
+    perftools_inline!{
+    fn exp(&self) -> i32 {
+        self.exp
+    }}
+
+    perftools_inline!{
+    fn set_mant(&mut self, mant: u16) {
+        self.mant = mant;
+    }}
+
+    perftools_inline!{
+    fn set_exp(&mut self, exp: i32) {
+        self.exp = exp;
+    }}
+}
+
 impl ExtendedFloatType<f32> for ExtendedFloat<u32> {
     perftools_inline!{
     fn mant(&self) -> u32 {
@@ -59,7 +59,7 @@ pub(super) fn parse_mantissa(state: FloatState, radix: u32, max_digits: usize)
     let small_powers = Bigint::small_powers(radix);
     let count = state.mantissa_digits();
     let bits = count / integral_binary_factor(radix).as_usize();
-    let bytes = bits / Limb::BITS;
+    let bytes = bits / <Limb as Integer>::BITS;
 
     // Main loop
     let step = small_powers.len() - 2;
@@ -102,6 +102,50 @@ pub(super) fn parse_mantissa(state: FloatState, radix: u32, max_digits: usize)
     result
 }
 
+/// Implied method to calculate the number of digits from a 16-bit float.
+perftools_inline!{
+fn max_digits_f16(radix: u32) -> Option<usize> {
+    match radix {
+        6  => Some(21),
+        10 => Some(23),
+        12 => Some(23),
+        14 => Some(23),
+        18 => Some(23),
+        20 => Some(23),
+        22 => Some(24),
+        24 => Some(24),
+        26 => Some(24),
+        28 => Some(24),
+        30 => Some(24),
+        34 => Some(24),
+        36 => Some(24),
+        // Powers of two and odd numbers should be unreachable
+        _  => None,
+    }
+}}
+
+/// Implied method to calculate the number of digits from a 16-bit brain float.
+perftools_inline!{
+fn max_digits_bf16(radix: u32) -> Option<usize> {
+    match radix {
+        6  => Some(87),
+        10 => Some(98),
+        12 => Some(101),
+        14 => Some(103),
+        18 => Some(106),
+        20 => Some(107),
+        22 => Some(107),
+        24 => Some(108),
+        26 => Some(109),
+        28 => Some(109),
+        30 => Some(110),
+        34 => Some(111),
+        36 => Some(111),
+        // Powers of two and odd numbers should be unreachable
+        _  => None,
+    }
+}}
+
 /// Implied method to calculate the number of digits from a 32-bit float.
 perftools_inline!{
 fn max_digits_f32(radix: u32) -> Option<usize> {
@@ -160,6 +204,14 @@ fn max_digits_f64(radix: u32) -> Option<usize> {
 ///
 /// `−emin + p2 + ⌊(emin + 1) log(2, b) − log(1 − 2^(−p2), b)⌋`
 ///
+/// For f16, this follows as:
+///     emin = -14
+///     p2 = 11
+///
+/// For bf16, this follows as:
+///     emin = -126
+///     p2 = 8
+///
 /// For f32, this follows as:
 ///     emin = -126
 ///     p2 = 24
@@ -177,9 +229,11 @@ pub(super) fn max_digits<F>(radix: u32)
     -> Option<usize>
     where F: Float
 {
-    match F::BITS {
-        32 => max_digits_f32(radix),
-        64 => max_digits_f64(radix),
+    match F::MANTISSA_SIZE {
+        7  => max_digits_bf16(radix),
+        10 => max_digits_f16(radix),
+        23 => max_digits_f32(radix),
+        52 => max_digits_f64(radix),
         _  => unreachable!(),
     }
 }}
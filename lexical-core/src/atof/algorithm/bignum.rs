@@ -1,5 +1,8 @@
 //! Big integer type definition.
 
+// Bigfloat is only used by the correct parser.
+#![allow(dead_code)]
+
 use arrayvec;
 use float::*;
 use util::*;
@@ -126,6 +129,15 @@ pub trait ToBigfloat<M: Mantissa> {
     fn to_bigfloat(&self) -> Bigfloat;
 }
 
+impl ToBigfloat<u16> for ExtendedFloat<u16> {
+    perftools_inline!{
+    fn to_bigfloat(&self) -> Bigfloat {
+        let mut bigfloat = Bigfloat::from_u32(self.mant.as_u32());
+        bigfloat.exp = self.exp;
+        bigfloat
+    }}
+}
+
 impl ToBigfloat<u32> for ExtendedFloat<u32> {
     perftools_inline!{
     fn to_bigfloat(&self) -> Bigfloat {
@@ -25,6 +25,13 @@ perftools_inline!{
 fn process_mantissa<'a, M: Mantissa>(state: &RawFloatState<'a>, radix: u32)
     -> (M, usize)
 {
+    #[cfg(feature = "format")] {
+        if state.digit_separator != 0 {
+            let separator = state.digit_separator;
+            return atoi::standalone_mantissa_separator(state.integer, state.fraction, radix, separator);
+        }
+    }
+
     atoi::standalone_mantissa(state.integer, state.fraction, radix)
 }}
 
@@ -263,12 +270,10 @@ pub(super) fn moderate_path<F, M>(mantissa: M, radix: u32, exponent: i32, trunca
 
 /// Fallback method. Do not inline so the stack requirements only occur
 /// if required.
-fn pown_fallback<'a, F>(state: FloatState, mantissa: u64, radix: u32, lossy: bool, sign: Sign)
+fn pown_fallback<'a, F>(state: FloatState, mantissa: u64, radix: u32, lossy: bool, kind: RoundingKind)
     -> F
     where F: FloatType
 {
-    let kind = global_rounding(sign);
-
     // Moderate path (use an extended 80-bit representation).
     let exponent = state.mantissa_exponent();
     let is_truncated = state.truncated != 0;
@@ -290,13 +295,14 @@ fn pown_fallback<'a, F>(state: FloatState, mantissa: u64, radix: u32, lossy: boo
 }
 
 /// Parse non-power-of-two radix string to native float.
-fn pown_to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign)
+fn pown_to_native<F>(bytes: &[u8], options: &ParseOptions, lossy: bool, sign: Sign)
     -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
     where F: FloatType
 {
     // Parse the mantissa and exponent.
+    let radix = options.radix;
     let mut state = RawFloatState::new();
-    let ptr = state.parse(bytes, radix)?;
+    let ptr = state.parse(bytes, options)?;
     let (mantissa, truncated) = process_mantissa::<u64>(&state, radix);
 
     // Process the state to a float.
@@ -307,17 +313,17 @@ fn pown_to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign)
         F::ZERO
     } else if truncated.is_zero() {
         // Try the fast path, no mantissa truncation.
-        let mant_exp = mantissa_exponent(state.exponent, state.fraction.len(), 0);
+        let mant_exp = mantissa_exponent(state.exponent, state.fraction_digits(), 0);
         if let Some(float) = fast_path::<F>(mantissa, radix, mant_exp) {
             float
         } else {
             let state = state.process(truncated);
-            pown_fallback(state, mantissa, radix, lossy, sign)
+            pown_fallback(state, mantissa, radix, lossy, options_rounding(options, sign))
         }
     } else {
         // Can only use the moderate/slow path.
         let state = state.process(truncated);
-        pown_fallback(state, mantissa, radix, lossy, sign)
+        pown_fallback(state, mantissa, radix, lossy, options_rounding(options, sign))
     };
     Ok((float, ptr))
 }
@@ -326,13 +332,14 @@ fn pown_to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign)
 
 /// Parse power-of-two radix string to native float.
 #[cfg(feature = "radix")]
-fn pow2_to_native<F>(bytes: &[u8], radix: u32, pow2_exp: i32, sign: Sign)
+fn pow2_to_native<F>(bytes: &[u8], options: &ParseOptions, pow2_exp: i32, sign: Sign)
     -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
     where F: FloatType
 {
     // Parse the mantissa and exponent.
+    let radix = options.radix;
     let mut state = RawFloatState::new();
-    let ptr = state.parse(bytes, radix)?;
+    let ptr = state.parse(bytes, options)?;
     let (mut mantissa, truncated) = process_mantissa::<u64>(&state, radix);
 
     // We have a power of 2, can get an exact value even if the mantissa
@@ -341,12 +348,13 @@ fn pow2_to_native<F>(bytes: &[u8], radix: u32, pow2_exp: i32, sign: Sign)
     let mantissa_size = F::MANTISSA_SIZE + 1;
     let float = if !truncated.is_zero() {
         // Truncated mantissa.
-        let kind = global_rounding(sign);
+        let kind = options_rounding(options, sign);
         let state = state.process(truncated);
         if kind != RoundingKind::Downward {
             if cfg!(feature = "rounding") || kind == RoundingKind::NearestTieEven {
                 // Need to check if we're exactly halfway and if there are truncated digits.
-                if is_halfway::<F>(mantissa) && is_odd::<F>(mantissa) {
+                // Any non-zero truncated digit puts the value above halfway.
+                if is_halfway::<F>(mantissa) && (is_odd::<F>(mantissa) || has_truncated_digits(&state)) {
                     mantissa += 1;
                 }
             } else if kind == RoundingKind::NearestTieAwayZero {
@@ -354,38 +362,65 @@ fn pow2_to_native<F>(bytes: &[u8], radix: u32, pow2_exp: i32, sign: Sign)
                 if is_halfway::<F>(mantissa) {
                     mantissa += 1;
                 }
-            } else {
-                // Need to check if there are any bytes present.
-                // Check if there were any truncated bytes.
-                let index = state.mantissa_digits() - state.truncated_digits();
-                let iter = state.mantissa_iter().skip(index);
-                let count = iter.take_while(|&&c| c == b'0').count();
-                let is_truncated = count < state.truncated_digits();
-                if is_truncated {
-                    mantissa += 1;
-                }
+            } else if has_truncated_digits(&state) {
+                mantissa += 1;
             }
         }
 
         // Create exact representation and return.
-        let exponent = state.mantissa_exponent().saturating_mul(pow2_exp);
+        let exponent = pow2_mantissa_exponent(&state, pow2_exp, options.hex_float);
         let fp = ExtendedFloat { mant: mantissa, exp: exponent };
         fp.into_rounded_float_impl::<F>(kind)
     } else if mantissa >> mantissa_size != 0 {
         // Would be truncated, use the extended float.
-        let kind = global_rounding(sign);
+        let kind = options_rounding(options, sign);
         let state = state.process(truncated);
-        let exponent = state.mantissa_exponent().saturating_mul(pow2_exp);
+        let exponent = pow2_mantissa_exponent(&state, pow2_exp, options.hex_float);
         let fp = ExtendedFloat { mant: mantissa, exp: exponent };
         fp.into_rounded_float_impl::<F>(kind)
+    } else if options.hex_float {
+        // Nothing above the hidden bit, can use the fast path with the
+        // binary exponent.
+        let shift = mantissa_exponent(0, state.fraction_digits(), 0).saturating_mul(pow2_exp);
+        let exponent = state.exponent.saturating_add(shift);
+        pow2_fast_path(mantissa, 2, 1, exponent)
     } else {
         // Nothing above the hidden bit, so no rounding-error, can use the fast path.
-        let mant_exp = mantissa_exponent(state.exponent, state.fraction.len(), 0);
+        let mant_exp = mantissa_exponent(state.exponent, state.fraction_digits(), 0);
         pow2_fast_path(mantissa, radix, pow2_exp, mant_exp)
     };
     Ok((float, ptr))
 }
 
+// Check if any of the truncated mantissa digits are non-zero.
+perftools_inline!{
+#[cfg(feature = "radix")]
+fn has_truncated_digits(state: &FloatState)
+    -> bool
+{
+    let index = state.mantissa_digits() - state.truncated_digits();
+    let iter = state.mantissa_iter().skip(index);
+    let count = iter.take_while(|&&c| c == b'0').count();
+    count < state.truncated_digits()
+}}
+
+// Calculate the binary exponent of the mantissa from the float state.
+//
+// Hexadecimal floats have a binary exponent, so only the digits shifted
+// from the mantissa are scaled by the radix.
+perftools_inline!{
+#[cfg(feature = "radix")]
+fn pow2_mantissa_exponent(state: &FloatState, pow2_exp: i32, binary_exponent: bool)
+    -> i32
+{
+    if binary_exponent {
+        let shift = mantissa_exponent(0, state.fraction_len(), state.truncated_digits());
+        state.raw_exponent.saturating_add(shift.saturating_mul(pow2_exp))
+    } else {
+        state.mantissa_exponent().saturating_mul(pow2_exp)
+    }
+}}
+
 // Check if value is power of 2 and get the power.
 perftools_inline!{
 fn pow2_exponent(radix: u32) -> i32 {
@@ -405,19 +440,19 @@ fn pow2_exponent(radix: u32) -> i32 {
 //
 // The float string must be non-special, non-zero, and positive.
 perftools_inline!{
-fn to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign)
+fn to_native<F>(bytes: &[u8], options: &ParseOptions, lossy: bool, sign: Sign)
     -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
     where F: FloatType
 {
     #[cfg(not(feature = "radix"))] {
-        pown_to_native(bytes, radix, lossy, sign)
+        pown_to_native(bytes, options, lossy, sign)
     }
 
     #[cfg(feature = "radix")] {
-        let pow2_exp = pow2_exponent(radix);
+        let pow2_exp = pow2_exponent(options.radix);
         match pow2_exp {
-            0 => pown_to_native(bytes, radix, lossy, sign),
-            _ => pow2_to_native(bytes, radix, pow2_exp, sign),
+            0 => pown_to_native(bytes, options, lossy, sign),
+            _ => pow2_to_native(bytes, options, pow2_exp, sign),
         }
     }
 }}
@@ -425,36 +460,68 @@ fn to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign)
 // ATOF/ATOD
 // ---------
 
+// Parse 16-bit float from string.
+perftools_inline!{
+pub(crate) fn atoh(bytes: &[u8], options: &ParseOptions, sign: Sign)
+    -> StdResult<(f16, *const u8), (ErrorCode, *const u8)>
+{
+    to_native::<f16>(bytes, options, false, sign)
+}}
+
+// Parse 16-bit brain float from string.
+perftools_inline!{
+pub(crate) fn atobh(bytes: &[u8], options: &ParseOptions, sign: Sign)
+    -> StdResult<(bf16, *const u8), (ErrorCode, *const u8)>
+{
+    to_native::<bf16>(bytes, options, false, sign)
+}}
+
+// Parse 16-bit float from string.
+perftools_inline!{
+pub(crate) fn atoh_lossy(bytes: &[u8], options: &ParseOptions, sign: Sign)
+    -> StdResult<(f16, *const u8), (ErrorCode, *const u8)>
+{
+    to_native::<f16>(bytes, options, true, sign)
+}}
+
+// Parse 16-bit brain float from string.
+perftools_inline!{
+pub(crate) fn atobh_lossy(bytes: &[u8], options: &ParseOptions, sign: Sign)
+    -> StdResult<(bf16, *const u8), (ErrorCode, *const u8)>
+{
+    to_native::<bf16>(bytes, options, true, sign)
+}}
+
 // Parse 32-bit float from string.
 perftools_inline!{
-pub(crate) fn atof(bytes: &[u8], radix: u32, sign: Sign)
+pub(crate) fn atof(bytes: &[u8], options: &ParseOptions, sign: Sign)
     -> StdResult<(f32, *const u8), (ErrorCode, *const u8)>
 {
-    to_native::<f32>(bytes, radix, false, sign)
+    to_native::<f32>(bytes, options, false, sign)
 }}
 
 // Parse 64-bit float from string.
 perftools_inline!{
-pub(crate) fn atod(bytes: &[u8], radix: u32, sign: Sign)
+pub(crate) fn atod(bytes: &[u8], options: &ParseOptions, sign: Sign)
     -> StdResult<(f64, *const u8), (ErrorCode, *const u8)>
 {
-    to_native::<f64>(bytes, radix, false, sign)
+    to_native::<f64>(bytes, options, false, sign)
 }}
 
 // Parse 32-bit float from string.
 perftools_inline!{
-pub(crate) fn atof_lossy(bytes: &[u8], radix: u32, sign: Sign)
+pub(crate) fn atof_lossy(bytes: &[u8], options: &ParseOptions, sign: Sign)
     -> StdResult<(f32, *const u8), (ErrorCode, *const u8)>
 {
-    to_native::<f32>(bytes, radix, true, sign)
+    to_native::<f32>(bytes, options, true, sign)
 }}
 
 // Parse 64-bit float from string.
 perftools_inline!{
-pub(crate) fn atod_lossy(bytes: &[u8], radix: u32, sign: Sign)
+pub(crate) fn atod_lossy(bytes: &[u8], options: &ParseOptions, sign: Sign)
     -> StdResult<(f64, *const u8), (ErrorCode, *const u8)>
 {
-    to_native::<f64>(bytes, radix, true, sign)
+    to_native::<f64>(bytes, options, true, sign)
 }}
 
 // TESTS
@@ -468,7 +535,7 @@ mod tests {
     fn new_state<'a>(integer: &'a [u8], fraction: &'a [u8], exponent: i32)
         -> RawFloatState<'a>
     {
-        RawFloatState { integer, fraction, exponent }
+        RawFloatState { integer, fraction, exponent, ..RawFloatState::new() }
     }
 
     #[test]
@@ -720,7 +787,7 @@ mod tests {
 
     #[test]
     fn atof_test() {
-        let atof10 = move |x| match atof(x, 10, Sign::Positive) {
+        let atof10 = move |x| match atof(x, &ParseOptions::new(), Sign::Positive) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -774,13 +841,13 @@ mod tests {
 
     #[test]
     fn atod_test() {
-        let adod_impl = move | x, r | match atod(x, r, Sign::Positive) {
+        let adod_impl = move | x, options: &ParseOptions | match atod(x, options, Sign::Positive) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
         #[cfg(feature = "radix")]
-        let atod2 = move |x| adod_impl(x, 2);
-        let atod10 = move |x| adod_impl(x, 10);
+        let atod2 = move |x| adod_impl(x, &ParseOptions::radix(2));
+        let atod10 = move |x| adod_impl(x, &ParseOptions::new());
 
         assert_eq!(Ok((0.0, 1)), atod10(b"0"));
         assert_eq!(Ok((1.2345, 6)), atod10(b"1.2345"));
@@ -875,7 +942,7 @@ mod tests {
 
     #[test]
     fn atof_lossy_test() {
-        let atof10 = move |x| match atof_lossy(x, 10, Sign::Positive) {
+        let atof10 = move |x| match atof_lossy(x, &ParseOptions::new(), Sign::Positive) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -888,7 +955,7 @@ mod tests {
 
     #[test]
     fn atod_lossy_test() {
-        let atod10 = move |x| match atod_lossy(x, 10, Sign::Positive) {
+        let atod10 = move |x| match atod_lossy(x, &ParseOptions::new(), Sign::Positive) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
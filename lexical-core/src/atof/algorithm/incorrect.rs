@@ -14,6 +14,18 @@ perftools_inline!{
 fn process_integer<F: StablePower>(state: &RawFloatState, radix: u32)
     -> F
 {
+    #[cfg(feature = "format")] {
+        if state.digit_separator != 0 && state.integer.len() != 0 {
+            // This cannot error, since we cannot overflow and cannot have
+            // invalid digits.
+            let separator = state.digit_separator;
+            return atoi::parse_digits_separator::<Wrapped<F>>(state.integer, radix, separator, Sign::Positive)
+                .unwrap()
+                .0
+                .into_inner();
+        }
+    }
+
     match state.integer.len() {
         0 => F::ZERO,
         // This cannot error, since we cannot overflow and cannot have
@@ -25,11 +37,51 @@ fn process_integer<F: StablePower>(state: &RawFloatState, radix: u32)
     }
 }}
 
+// Process the fraction component of the raw float, skipping digit separators.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn process_fraction_separator<F: StablePower>(state: &RawFloatState, radix: u32)
+    -> F
+{
+    // Break the fraction into 12-digit pieces, like `process_fraction`.
+    let separator = state.digit_separator;
+    let mut fraction = F::ZERO;
+    let mut digits: i32 = 0;
+    let mut value: u64 = 0;
+    let mut count = 0;
+    for &c in state.fraction.iter().filter(|&&c| c != separator) {
+        // This cannot error, since we have validated digits.
+        value = value * (radix as u64) + ((c as char).to_digit(radix).unwrap() as u64);
+        count += 1;
+        if count == 12 {
+            digits = digits.saturating_add(count);
+            if !value.is_zero() {
+                fraction += F::iterative_pow(as_cast(value), radix, -digits);
+            }
+            value = 0;
+            count = 0;
+        }
+    }
+    if count != 0 {
+        digits = digits.saturating_add(count);
+        if !value.is_zero() {
+            fraction += F::iterative_pow(as_cast(value), radix, -digits);
+        }
+    }
+    fraction
+}}
+
 // Process the fraction component of the raw float.
 perftools_inline!{
 fn process_fraction<F: StablePower>(state: &RawFloatState, radix: u32)
     -> F
 {
+    #[cfg(feature = "format")] {
+        if state.digit_separator != 0 {
+            return process_fraction_separator(state, radix);
+        }
+    }
+
     match state.fraction.len() {
         0 => F::ZERO,
         _ => {
@@ -52,19 +104,32 @@ fn process_fraction<F: StablePower>(state: &RawFloatState, radix: u32)
     }
 }}
 
+// Get the base of the exponent, which is 2 for hexadecimal floats.
+perftools_inline!{
+fn exponent_base(options: &ParseOptions) -> u32 {
+    #[cfg(feature = "radix")] {
+        if options.hex_float {
+            return 2;
+        }
+    }
+
+    options.radix
+}}
+
 // Convert the float string to a native floating-point number.
 perftools_inline!{
-fn to_native<F: StablePower>(bytes: &[u8], radix: u32)
+fn to_native<F: StablePower>(bytes: &[u8], options: &ParseOptions)
     -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
 {
+    let radix = options.radix;
     let mut state = RawFloatState::new();
-    let ptr = state.parse(bytes, radix)?;
+    let ptr = state.parse(bytes, options)?;
 
     let integer: F = process_integer(&state, radix);
     let fraction: F = process_fraction(&state, radix);
     let mut value = integer + fraction;
     if !state.exponent.is_zero() && !value.is_zero() {
-        value = value.iterative_pow(radix, state.exponent);
+        value = value.iterative_pow(exponent_base(options), state.exponent);
     }
     Ok((value, ptr))
 }}
@@ -74,34 +139,78 @@ fn to_native<F: StablePower>(bytes: &[u8], radix: u32)
 
 // Parse 32-bit float from string.
 perftools_inline!{
-pub(crate) fn atof<'a>(bytes: &'a [u8], radix: u32, _: Sign)
+pub(crate) fn atof<'a>(bytes: &'a [u8], options: &ParseOptions, _: Sign)
     -> StdResult<(f32, *const u8), (ErrorCode, *const u8)>
 {
-    to_native::<f32>(bytes, radix)
+    to_native::<f32>(bytes, options)
 }}
 
 // Parse 64-bit float from string.
 perftools_inline!{
-pub(crate) fn atod<'a>(bytes: &'a [u8], radix: u32, _: Sign)
+pub(crate) fn atod<'a>(bytes: &'a [u8], options: &ParseOptions, _: Sign)
     -> StdResult<(f64, *const u8), (ErrorCode, *const u8)>
 {
-    to_native::<f64>(bytes, radix)
+    to_native::<f64>(bytes, options)
 }}
 
 // Parse 32-bit float from string.
 perftools_inline!{
-pub(crate) fn atof_lossy<'a>(bytes: &'a [u8], radix: u32, _: Sign)
+pub(crate) fn atof_lossy<'a>(bytes: &'a [u8], options: &ParseOptions, _: Sign)
     -> StdResult<(f32, *const u8), (ErrorCode, *const u8)>
 {
-    to_native::<f32>(bytes, radix)
+    to_native::<f32>(bytes, options)
 }}
 
 // Parse 64-bit float from string.
 perftools_inline!{
-pub(crate) fn atod_lossy<'a>(bytes: &'a [u8], radix: u32, _: Sign)
+pub(crate) fn atod_lossy<'a>(bytes: &'a [u8], options: &ParseOptions, _: Sign)
     -> StdResult<(f64, *const u8), (ErrorCode, *const u8)>
 {
-    to_native::<f64>(bytes, radix)
+    to_native::<f64>(bytes, options)
+}}
+
+// Parse 16-bit float from string.
+//
+// Rounds the parsed 64-bit float, which may round incorrectly near
+// halfway between 2 floats, like the 64-bit float itself.
+perftools_inline!{
+pub(crate) fn atoh<'a>(bytes: &'a [u8], options: &ParseOptions, sign: Sign)
+    -> StdResult<(f16, *const u8), (ErrorCode, *const u8)>
+{
+    atod(bytes, options, sign).map(|(value, ptr)| (f16::from_f64(value), ptr))
+}}
+
+// Parse 16-bit brain float from string.
+//
+// Rounds the parsed 64-bit float, which may round incorrectly near
+// halfway between 2 floats, like the 64-bit float itself.
+perftools_inline!{
+pub(crate) fn atobh<'a>(bytes: &'a [u8], options: &ParseOptions, sign: Sign)
+    -> StdResult<(bf16, *const u8), (ErrorCode, *const u8)>
+{
+    atod(bytes, options, sign).map(|(value, ptr)| (bf16::from_f64(value), ptr))
+}}
+
+// Parse 16-bit float from string.
+//
+// Rounds the parsed 64-bit float, which may round incorrectly near
+// halfway between 2 floats, like the 64-bit float itself.
+perftools_inline!{
+pub(crate) fn atoh_lossy<'a>(bytes: &'a [u8], options: &ParseOptions, sign: Sign)
+    -> StdResult<(f16, *const u8), (ErrorCode, *const u8)>
+{
+    atod(bytes, options, sign).map(|(value, ptr)| (f16::from_f64(value), ptr))
+}}
+
+// Parse 16-bit brain float from string.
+//
+// Rounds the parsed 64-bit float, which may round incorrectly near
+// halfway between 2 floats, like the 64-bit float itself.
+perftools_inline!{
+pub(crate) fn atobh_lossy<'a>(bytes: &'a [u8], options: &ParseOptions, sign: Sign)
+    -> StdResult<(bf16, *const u8), (ErrorCode, *const u8)>
+{
+    atod(bytes, options, sign).map(|(value, ptr)| (bf16::from_f64(value), ptr))
 }}
 
 // TESTS
@@ -114,7 +223,7 @@ mod tests {
     fn new_state<'a>(integer: &'a [u8], fraction: &'a [u8], exponent: i32)
         -> RawFloatState<'a>
     {
-        RawFloatState { integer, fraction, exponent }
+        RawFloatState { integer, fraction, exponent, ..RawFloatState::new() }
     }
 
     #[test]
@@ -133,7 +242,7 @@ mod tests {
 
     #[test]
     fn atof_test() {
-        let atof10 = move |x| match atof(x, 10, Sign::Positive) {
+        let atof10 = move |x| match atof(x, &ParseOptions::new(), Sign::Positive) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -146,7 +255,7 @@ mod tests {
 
     #[test]
     fn atod_test() {
-        let atod10 = move |x| match atod(x, 10, Sign::Positive) {
+        let atod10 = move |x| match atod(x, &ParseOptions::new(), Sign::Positive) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -163,7 +272,7 @@ mod tests {
 
     #[test]
     fn atof_lossy_test() {
-        let atof10 = move |x| match atof_lossy(x, 10, Sign::Positive) {
+        let atof10 = move |x| match atof_lossy(x, &ParseOptions::new(), Sign::Positive) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -176,7 +285,7 @@ mod tests {
 
     #[test]
     fn atod_lossy_test() {
-        let atod10 = move |x| match atod_lossy(x, 10, Sign::Positive) {
+        let atod10 = move |x| match atod_lossy(x, &ParseOptions::new(), Sign::Positive) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -1,24 +1,11 @@
 //! Algorithms for parsing strings to floats.
 
 // Hide implementation details.
-mod state;
-
-cfg_if! {
-if #[cfg(feature = "correct")] {
-pub(crate) mod bhcomp;
-pub(crate) mod bigcomp;
-mod alias;
 mod bignum;
-mod cached;
-mod cached_float80;
-mod errors;
-mod exponent;
 mod large_powers;
 mod math;
 mod small_powers;
-
-#[cfg(has_i128)]
-mod cached_float160;
+mod state;
 
 #[cfg(limb_width_32)]
 mod large_powers_32;
@@ -32,12 +19,28 @@ mod large_powers_64;
 // Required for fast-path, keep on all platforms.
 mod small_powers_64;
 
+cfg_if! {
+if #[cfg(feature = "correct")] {
+pub(crate) mod bhcomp;
+pub(crate) mod bigcomp;
+mod alias;
+mod cached;
+mod cached_float80;
+mod errors;
+mod exponent;
+
+#[cfg(has_i128)]
+mod cached_float160;
+
 }}  // cfg_if
 
+// Export the big integer, also used by the exact float writers.
+pub(crate) use self::bignum::Bigint;
+pub(crate) use self::math::{LargeOps, Limb, SharedOps, SmallOps};
+
 // Export algorithms.
 #[cfg(feature = "correct")]
 pub(crate) mod correct;
 
 #[cfg(not(feature = "correct"))]
 pub(crate) mod incorrect;
-
@@ -113,6 +113,7 @@ pub(in atof::algorithm) fn get_small_powers(radix: u32)
 }
 
 /// Get the correct 64-bit small power from the radix.
+#[allow(dead_code)]     // Only used by the correct parser.
 pub(in atof::algorithm) fn get_small_powers_64(radix: u32)
     -> &'static [u64]
 {
@@ -11,6 +11,9 @@ use super::alias::*;
 use super::exponent::*;
 }}  // cfg_if
 
+#[cfg(all(feature = "correct", feature = "format"))]
+use lib::iter;
+
 // PARSE
 // -----
 
@@ -45,6 +48,58 @@ fn consume_digits<'a>(digits: &'a [u8], radix: u32) -> (&'a [u8], &'a [u8]) {
     }
 }}
 
+// Count the number of digits in a substring, ignoring digit separators.
+perftools_inline!{
+#[cfg(all(feature = "correct", feature = "format"))]
+fn count_digits(digits: &[u8], separator: u8) -> usize {
+    match separator {
+        0 => digits.len(),
+        _ => digits.iter().filter(|&&c| c != separator).count(),
+    }
+}}
+
+// DIGIT ITERATOR
+// --------------
+
+cfg_if! {
+if #[cfg(all(feature = "correct", feature = "format"))] {
+/// Iterator over the digits of a substring, skipping digit separators.
+#[derive(Clone)]
+pub(super) struct DigitIter<'a> {
+    iter: SliceIter<'a, u8>,
+    separator: u8,
+}
+
+impl<'a> DigitIter<'a> {
+    /// Create new digit iterator.
+    perftools_inline!{
+    fn new(digits: &'a [u8], separator: u8) -> DigitIter<'a> {
+        DigitIter { iter: digits.iter(), separator }
+    }}
+
+    /// Get the number of remaining digits.
+    perftools_inline!{
+    pub(super) fn len(&self) -> usize {
+        count_digits(self.iter.as_slice(), self.separator)
+    }}
+}
+
+impl<'a> Iterator for DigitIter<'a> {
+    type Item = &'a u8;
+
+    perftools_inline!{
+    fn next(&mut self) -> Option<&'a u8> {
+        let separator = self.separator;
+        self.iter.find(|&&c| c != separator)
+    }}
+}
+
+pub(super) type ChainedDigitIter<'a> = iter::Chain<DigitIter<'a>, DigitIter<'a>>;
+} else if #[cfg(feature = "correct")] {
+pub(super) type DigitIter<'a> = SliceIter<'a, u8>;
+pub(super) type ChainedDigitIter<'a> = ChainedSliceIter<'a, u8>;
+}}  // cfg_if
+
 // RAW FLOAT STATE
 // ---------------
 
@@ -58,6 +113,9 @@ pub(super) struct RawFloatState<'a> {
     pub(super) fraction: &'a [u8],
     /// Parsed exponent.
     pub(super) exponent: i32,
+    /// Digit separator in the integer and fraction substrings.
+    #[cfg(feature = "format")]
+    pub(super) digit_separator: u8,
 }
 
 impl<'a> RawFloatState<'a> {
@@ -68,6 +126,21 @@ impl<'a> RawFloatState<'a> {
             integer: &[],
             fraction: &[],
             exponent: 0,
+            #[cfg(feature = "format")]
+            digit_separator: 0,
+        }
+    }}
+
+    /// Get the number of fraction digits.
+    perftools_inline!{
+    #[cfg(feature = "correct")]
+    pub(super) fn fraction_digits(&self) -> usize {
+        #[cfg(feature = "format")] {
+            count_digits(self.fraction, self.digit_separator)
+        }
+
+        #[cfg(not(feature = "format"))] {
+            self.fraction.len()
         }
     }}
 
@@ -121,32 +194,106 @@ impl<'a> RawFloatState<'a> {
     // exponent.
     perftools_inline!{
     fn trim(&mut self) {
+        #[cfg(feature = "format")] {
+            if self.digit_separator != 0 {
+                let separator = self.digit_separator;
+                self.integer = ltrim_char2_slice(self.integer, b'0', separator).0;
+                self.fraction = rtrim_char2_slice(self.fraction, b'0', separator).0;
+                return;
+            }
+        }
+
         self.integer = ltrim_0!(self.integer).0;
         self.fraction = rtrim_0!(self.fraction).0;
     }}
 
+    // Parse the float state from raw bytes with a number format.
+    perftools_inline!{
+    #[cfg(feature = "format")]
+    fn parse_format(&mut self, bytes: &'a [u8], options: &ParseOptions)
+        -> StdResult<*const u8, (ErrorCode, *const u8)>
+    {
+        let radix = options.radix;
+        let format = options.number_format();
+        let separator = format.digit_separator();
+        self.digit_separator = separator;
+
+        // Extract the integer substring.
+        let flags = format.component_separator_flags(INTEGER_SHIFT);
+        let (integer, mut digits) = atoi::consume_digits_separator(bytes, radix, separator, flags);
+        self.integer = integer;
+        if format.contains(NumberFormat::REQUIRED_INTEGER_DIGITS) && integer.is_empty() {
+            return Err((ErrorCode::EmptyInteger, bytes.as_ptr()));
+        } else if format.contains(NumberFormat::NO_FLOAT_LEADING_ZEROS) && atoi::has_leading_zeros(integer, separator) {
+            return Err((ErrorCode::InvalidLeadingZeros, bytes.as_ptr()));
+        }
+
+        // Parse the remaining digits, which may include a fraction,
+        // an exponent, or both.
+        let exp_char = options.exponent_char().to_ascii_lowercase();
+        let has_fraction = digits.first() == Some(&options.decimal_point);
+        if has_fraction {
+            // Extract the fraction.
+            let flags = format.component_separator_flags(FRACTION_SHIFT);
+            let (fraction, trailing) = atoi::consume_digits_separator(&index!(digits[1..]), radix, separator, flags);
+            if format.contains(NumberFormat::REQUIRED_FRACTION_DIGITS) && fraction.is_empty() {
+                return Err((ErrorCode::EmptyFraction, trailing.as_ptr()));
+            } else if format.contains(NumberFormat::NO_EXPONENT_WITHOUT_FRACTION_DIGITS)
+                && fraction.is_empty()
+                && trailing.first().map_or(false, |c| c.to_ascii_lowercase() == exp_char)
+            {
+                return Err((ErrorCode::EmptyFraction, trailing.as_ptr()));
+            }
+            self.fraction = fraction;
+            digits = trailing;
+        }
+        if let Some(c) = digits.first() {
+            if c.to_ascii_lowercase() == exp_char {
+                // Parse the exponent.
+                if format.contains(NumberFormat::NO_EXPONENT_WITHOUT_FRACTION) && !has_fraction {
+                    return Err((ErrorCode::ExponentWithoutFraction, digits.as_ptr()));
+                }
+                let (exp, first) = atoi::standalone_exponent_format(&index!(digits[1..]), options.exponent_radix(), format)?;
+                self.exponent = exp;
+                let last = index!(digits[digits.len()..]).as_ptr();
+                digits = unsafe { slice::from_raw_parts(first, distance(first, last)) };
+            }
+        }
+        self.validate(bytes)?;
+        self.trim();
+
+        Ok(digits.as_ptr())
+    }}
+
     // Parse the float state from raw bytes.
     perftools_inline!{
-    pub(super) fn parse(&mut self, bytes: &'a [u8], radix: u32)
+    pub(super) fn parse(&mut self, bytes: &'a [u8], options: &ParseOptions)
         -> StdResult<*const u8, (ErrorCode, *const u8)>
     {
+        #[cfg(feature = "format")] {
+            if !options.number_format().is_empty() {
+                return self.parse_format(bytes, options);
+            }
+        }
+
+        let radix = options.radix;
         let mut digits = bytes;
         digits = self.extract_integer(digits, radix);
         // Parse the remaining digits, which may include a fraction,
         // an exponent, or both.
-        let exp_char = exponent_notation_char(radix).to_ascii_lowercase();
+        let exp_char = options.exponent_char().to_ascii_lowercase();
         if let Some(c) = digits.first() {
-            if *c == b'.' {
+            if *c == options.decimal_point {
                 // Extract the fraction, and then check for a subsequent exponent.
                 digits = self.extract_fraction(digits, radix);
                 if let Some(c) = digits.first() {
                     if c.to_ascii_lowercase() == exp_char {
-                        digits = self.parse_exponent(digits, radix)?;
+                        digits = self.parse_exponent(digits, options.exponent_radix())?;
                     }
                 }
             } else if c.to_ascii_lowercase() == exp_char {
                 // Parse the exponent.
-                digits = self.parse_exponent(digits, radix)?;
+                digits = self.parse_exponent(digits, options.exponent_radix())?;
             }
         }
         self.validate(bytes)?;
@@ -161,11 +308,23 @@ impl<'a> RawFloatState<'a> {
     pub(super) fn process(self, truncated: usize) -> FloatState<'a> {
         let integer = self.integer;
         let fraction = self.fraction;
-        let digits_start = match integer.len() {
-            0 => ltrim_char_slice(fraction, b'0').1,
-            _ => 0,
-        };
-        FloatState { integer, fraction, digits_start, truncated, raw_exponent: self.exponent }
+
+        #[cfg(feature = "format")] {
+            let digit_separator = self.digit_separator;
+            let digits_start = match integer.len() {
+                0 => ltrim_char2_slice(fraction, b'0', digit_separator).1,
+                _ => 0,
+            };
+            FloatState { integer, fraction, digits_start, truncated, raw_exponent: self.exponent, digit_separator }
+        }
+
+        #[cfg(not(feature = "format"))] {
+            let digits_start = match integer.len() {
+                0 => ltrim_char_slice(fraction, b'0').1,
+                _ => 0,
+            };
+            FloatState { integer, fraction, digits_start, truncated, raw_exponent: self.exponent }
+        }
     }}
 }
 
@@ -186,43 +345,70 @@ pub(super) struct FloatState<'a> {
     pub(super) truncated: usize,
     /// Raw exponent for the float.
     pub(super) raw_exponent: i32,
+    /// Digit separator in the integer and fraction substrings.
+    #[cfg(feature = "format")]
+    pub(super) digit_separator: u8,
 }
 
 #[cfg(feature = "correct")]
 impl<'a> FloatState<'a> {
-    /// Get the length of the integer substring.
+    /// Get the number of digits in a substring of the float.
     perftools_inline!{
-    pub(super) fn integer_len(&self) -> usize {
-        self.integer.len()
+    fn count_digits(&self, digits: &[u8]) -> usize {
+        #[cfg(feature = "format")] {
+            count_digits(digits, self.digit_separator)
+        }
+
+        #[cfg(not(feature = "format"))] {
+            digits.len()
+        }
+    }}
+
+    /// Iterate over the digits in a substring of the float.
+    perftools_inline!{
+    fn digit_iter(&self, digits: &'a [u8]) -> DigitIter<'a> {
+        #[cfg(feature = "format")] {
+            DigitIter::new(digits, self.digit_separator)
+        }
+
+        #[cfg(not(feature = "format"))] {
+            digits.iter()
+        }
     }}
 
     /// Get number of parsed integer digits.
     perftools_inline!{
     pub(super) fn integer_digits(&self) -> usize {
-        self.integer_len()
+        self.count_digits(self.integer)
     }}
 
     /// Iterate over the integer digits.
     perftools_inline!{
-    pub(super) fn integer_iter(&self) -> SliceIter<u8> {
-        self.integer.iter()
+    pub(super) fn integer_iter(&self) -> DigitIter<'a> {
+        self.digit_iter(self.integer)
     }}
 
-    /// Get the length of the fraction substring.
+    /// Get the number of parsed fraction digits.
     perftools_inline!{
     pub(super) fn fraction_len(&self) -> usize {
-        self.fraction.len()
+        self.count_digits(self.fraction)
     }}
 
-    /// Iterate over the fraction digits.
+    /// Get the number of fraction digits, after any leading zeros.
     perftools_inline!{
     pub(super) fn fraction_digits(&self) -> usize {
-        self.fraction_len() - self.digits_start
+        self.count_digits(&index!(self.fraction[self.digits_start..]))
+    }}
+
+    /// Get the number of leading zeros in the fraction.
+    perftools_inline!{
+    pub(super) fn fraction_zeros(&self) -> usize {
+        self.count_digits(&index!(self.fraction[..self.digits_start]))
     }}
 
     /// Iterate over the digits, by chaining two slices.
     perftools_inline!{
-    pub(super) fn fraction_iter(&self) -> SliceIter<u8> {
+    pub(super) fn fraction_iter(&self) -> DigitIter<'a> {
         // We need to rtrim the zeros in the slice fraction.
         // These are useless and just add computational complexity later,
         // just like leading zeros in the integer.
@@ -230,7 +416,7 @@ impl<'a> FloatState<'a> {
         // but we should remove them before doing anything costly.
         // In practice, we only call `mantissa_iter()` once per parse,
         // so this is effectively free.
-        self.fraction[self.digits_start..].iter()
+        self.digit_iter(&index!(self.fraction[self.digits_start..]))
     }}
 
     /// Get the number of digits in the mantissa.
@@ -242,7 +428,7 @@ impl<'a> FloatState<'a> {
 
     /// Iterate over the mantissa digits, by chaining two slices.
     perftools_inline!{
-    pub(super) fn mantissa_iter(&self) -> ChainedSliceIter<u8> {
+    pub(super) fn mantissa_iter(&self) -> ChainedDigitIter<'a> {
         self.integer_iter().chain(self.fraction_iter())
     }}
 
@@ -261,7 +447,7 @@ impl<'a> FloatState<'a> {
     /// Get the scientific exponent from the raw exponent.
     perftools_inline!{
     pub(super) fn scientific_exponent(&self) -> i32 {
-        scientific_exponent(self.raw_exponent, self.integer_digits(), self.digits_start)
+        scientific_exponent(self.raw_exponent, self.integer_digits(), self.fraction_zeros())
     }}
 }
 
@@ -275,13 +461,13 @@ mod tests {
     fn new_state<'a>(integer: &'a [u8], fraction: &'a [u8], exponent: i32)
         -> RawFloatState<'a>
     {
-        RawFloatState { integer, fraction, exponent }
+        RawFloatState { integer, fraction, exponent, ..RawFloatState::new() }
     }
 
-    fn check_parse(digits: &str, radix: u32, expected: StdResult<RawFloatState, ErrorCode>)
+    fn check_parse(digits: &str, options: &ParseOptions, expected: StdResult<RawFloatState, ErrorCode>)
     {
         let mut state = RawFloatState::new();
-        match state.parse(digits.as_bytes(), radix) {
+        match state.parse(digits.as_bytes(), options) {
             Ok(_)       => {
                 let expected = expected.unwrap();
                 assert_eq!(state.integer, expected.integer);
@@ -294,22 +480,62 @@ mod tests {
 
     #[test]
     fn parse_test() {
+        let options = ParseOptions::new();
+
         // Valid
-        check_parse("1.2345", 10, Ok(new_state(b"1", b"2345", 0)));
-        check_parse("12.345", 10, Ok(new_state(b"12", b"345", 0)));
-        check_parse("12345.6789", 10, Ok(new_state(b"12345", b"6789", 0)));
-        check_parse("1.2345e10", 10, Ok(new_state(b"1", b"2345", 10)));
-        check_parse("1.2345e+10", 10, Ok(new_state(b"1", b"2345", 10)));
-        check_parse("1.2345e-10", 10, Ok(new_state(b"1", b"2345", -10)));
-        check_parse("100000000000000000000", 10, Ok(new_state(b"100000000000000000000", b"", 0)));
-        check_parse("100000000000000000001", 10, Ok(new_state(b"100000000000000000001", b"", 0)));
-        check_parse("179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497791.9999999999999999999999999999999999999999999999999999999999999999999999", 10, Ok(new_state(b"179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497791", b"9999999999999999999999999999999999999999999999999999999999999999999999", 0)));
-        check_parse("1009e-31", 10, Ok(new_state(b"1009", b"", -31)));
-        check_parse("001.0", 10, Ok(new_state(b"1", b"", 0)));
+        check_parse("1.2345", &options, Ok(new_state(b"1", b"2345", 0)));
+        check_parse("12.345", &options, Ok(new_state(b"12", b"345", 0)));
+        check_parse("12345.6789", &options, Ok(new_state(b"12345", b"6789", 0)));
+        check_parse("1.2345e10", &options, Ok(new_state(b"1", b"2345", 10)));
+        check_parse("1.2345e+10", &options, Ok(new_state(b"1", b"2345", 10)));
+        check_parse("1.2345e-10", &options, Ok(new_state(b"1", b"2345", -10)));
+        check_parse("100000000000000000000", &options, Ok(new_state(b"100000000000000000000", b"", 0)));
+        check_parse("100000000000000000001", &options, Ok(new_state(b"100000000000000000001", b"", 0)));
+        check_parse("179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497791.9999999999999999999999999999999999999999999999999999999999999999999999", &options, Ok(new_state(b"179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497791", b"9999999999999999999999999999999999999999999999999999999999999999999999", 0)));
+        check_parse("1009e-31", &options, Ok(new_state(b"1009", b"", -31)));
+        check_parse("001.0", &options, Ok(new_state(b"1", b"", 0)));
 
         // Invalid
-        check_parse("1.2345e", 10, Err(ErrorCode::EmptyExponent));
-        check_parse(".", 10, Err(ErrorCode::EmptyFraction));
+        check_parse("1.2345e", &options, Err(ErrorCode::EmptyExponent));
+        check_parse(".", &options, Err(ErrorCode::EmptyFraction));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn parse_format_test() {
+        let mut options = ParseOptions::new();
+        let flags = NumberFormat::INTERNAL_DIGIT_SEPARATOR | NumberFormat::TRAILING_DIGIT_SEPARATOR;
+        options.set_format(NumberFormat::compile(b'_', flags).unwrap());
+
+        // Valid
+        check_parse("1_2.3_4", &options, Ok(new_state(b"1_2", b"3_4", 0)));
+        check_parse("1_2_.3_4_e1_0_", &options, Ok(new_state(b"1_2_", b"3_4", 10)));
+        check_parse("0_0_1.0_0", &options, Ok(new_state(b"1", b"", 0)));
+        check_parse("1_0.1_0_0", &options, Ok(new_state(b"1_0", b"1", 0)));
+
+        // Invalid
+        check_parse("_", &options, Err(ErrorCode::EmptyFraction));
+        check_parse("1_2.e", &options, Err(ErrorCode::EmptyExponent));
+    }
+
+    #[cfg(all(feature = "correct", feature = "format"))]
+    #[test]
+    fn float_state_separator_test() {
+        let mut state = new_state(b"1_2", b"0_0_3_4", 0);
+        state.digit_separator = b'_';
+        assert_eq!(state.fraction_digits(), 4);
+        let state = state.process(0);
+        assert_eq!(state.integer_digits(), 2);
+        assert_eq!(state.fraction_len(), 4);
+        assert_eq!(state.mantissa_iter().collect::<Vec<_>>(), b"120034".iter().collect::<Vec<_>>());
+        assert_eq!(state.scientific_exponent(), 1);
+
+        let mut state = new_state(b"", b"0_0_1_2", 0);
+        state.digit_separator = b'_';
+        let state = state.process(0);
+        assert_eq!(state.fraction_digits(), 2);
+        assert_eq!(state.fraction_iter().len(), 2);
+        assert_eq!(state.scientific_exponent(), -3);
     }
 
     #[cfg(feature = "correct")]
@@ -322,6 +548,8 @@ mod tests {
             digits_start: 0,
             truncated: 0,
             raw_exponent: 0,
+            #[cfg(feature = "format")]
+            digit_separator: 0,
         };
         assert_eq!(state.scientific_exponent(), 0);
 
@@ -332,6 +560,8 @@ mod tests {
             digits_start: 0,
             truncated: 0,
             raw_exponent: 0,
+            #[cfg(feature = "format")]
+            digit_separator: 0,
         };
         assert_eq!(state.scientific_exponent(), -1);
     }
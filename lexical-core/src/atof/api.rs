@@ -18,41 +18,73 @@ if #[cfg(feature = "correct")] {
 /// Trait to define parsing of a string to float.
 trait StringToFloat: Float {
     /// Serialize string to float, favoring correctness.
-    fn default(bytes: &[u8], radix: u32, sign: Sign) -> StdResult<(Self, *const u8), (ErrorCode, *const u8)>;
+    fn default(bytes: &[u8], options: &ParseOptions, sign: Sign) -> StdResult<(Self, *const u8), (ErrorCode, *const u8)>;
 
     /// Serialize string to float, prioritizing speed over correctness.
-    fn lossy(bytes: &[u8], radix: u32, sign: Sign) -> StdResult<(Self, *const u8), (ErrorCode, *const u8)>;
+    fn lossy(bytes: &[u8], options: &ParseOptions, sign: Sign) -> StdResult<(Self, *const u8), (ErrorCode, *const u8)>;
+}
+
+impl StringToFloat for f16 {
+    perftools_inline_always!{
+    fn default(bytes: &[u8], options: &ParseOptions, sign: Sign)
+        -> StdResult<(f16, *const u8), (ErrorCode, *const u8)>
+    {
+        algorithm::atoh(bytes, options, sign)
+    }}
+
+    perftools_inline_always!{
+    fn lossy(bytes: &[u8], options: &ParseOptions, sign: Sign)
+        -> StdResult<(f16, *const u8), (ErrorCode, *const u8)>
+    {
+        algorithm::atoh_lossy(bytes, options, sign)
+    }}
+}
+
+impl StringToFloat for bf16 {
+    perftools_inline_always!{
+    fn default(bytes: &[u8], options: &ParseOptions, sign: Sign)
+        -> StdResult<(bf16, *const u8), (ErrorCode, *const u8)>
+    {
+        algorithm::atobh(bytes, options, sign)
+    }}
+
+    perftools_inline_always!{
+    fn lossy(bytes: &[u8], options: &ParseOptions, sign: Sign)
+        -> StdResult<(bf16, *const u8), (ErrorCode, *const u8)>
+    {
+        algorithm::atobh_lossy(bytes, options, sign)
+    }}
 }
 
 impl StringToFloat for f32 {
     perftools_inline_always!{
-    fn default(bytes: &[u8], radix: u32, sign: Sign)
+    fn default(bytes: &[u8], options: &ParseOptions, sign: Sign)
         -> StdResult<(f32, *const u8), (ErrorCode, *const u8)>
     {
-        algorithm::atof(bytes, radix, sign)
+        algorithm::atof(bytes, options, sign)
     }}
 
     perftools_inline_always!{
-    fn lossy(bytes: &[u8], radix: u32, sign: Sign)
+    fn lossy(bytes: &[u8], options: &ParseOptions, sign: Sign)
         -> StdResult<(f32, *const u8), (ErrorCode, *const u8)>
     {
-        algorithm::atof_lossy(bytes, radix, sign)
+        algorithm::atof_lossy(bytes, options, sign)
     }}
 }
 
 impl StringToFloat for f64 {
     perftools_inline_always!{
-    fn default(bytes: &[u8], radix: u32, sign: Sign)
+    fn default(bytes: &[u8], options: &ParseOptions, sign: Sign)
         -> StdResult<(f64, *const u8), (ErrorCode, *const u8)>
     {
-        algorithm::atod(bytes, radix, sign)
+        algorithm::atod(bytes, options, sign)
     }}
 
     perftools_inline_always!{
-    fn lossy(bytes: &[u8], radix: u32, sign: Sign)
+    fn lossy(bytes: &[u8], options: &ParseOptions, sign: Sign)
         -> StdResult<(f64, *const u8), (ErrorCode, *const u8)>
     {
-        algorithm::atod_lossy(bytes, radix, sign)
+        algorithm::atod_lossy(bytes, options, sign)
     }}
 }
 
@@ -60,18 +92,90 @@ impl StringToFloat for f64 {
 // Utilities to filter special values.
 
 perftools_inline!{
-fn is_nan(bytes: &[u8]) -> bool {
-    case_insensitive_equal_to_slice(bytes, get_nan_string())
+fn is_nan(bytes: &[u8], options: &ParseOptions) -> bool {
+    is_special_string(bytes, options.nan_string, options)
 }}
 
 perftools_inline!{
-fn is_inf(bytes: &[u8]) -> bool {
-    case_insensitive_equal_to_slice(bytes, get_inf_string())
+fn is_inf(bytes: &[u8], options: &ParseOptions) -> bool {
+    is_special_string(bytes, options.inf_string, options)
 }}
 
 perftools_inline!{
-fn is_infinity(bytes: &[u8]) -> bool {
-    case_insensitive_equal_to_slice(bytes, get_infinity_string())
+fn is_infinity(bytes: &[u8], options: &ParseOptions) -> bool {
+    is_special_string(bytes, options.infinity_string, options)
+}}
+
+// Determine if the special string has the letter case required by the number format.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn is_special_case(bytes: &[u8], string: &[u8], case: Option<LetterCase>) -> bool {
+    match case {
+        Some(case)  => bytes.iter().zip(string.iter()).all(|(&x, &y)| x == case.convert(y)),
+        None        => true,
+    }
+}}
+
+// Check a special value against the special value rules of the number format.
+//
+// `signed` is the input including any sign, and `bytes` the input after
+// the sign. Inputs that are not special values are always valid.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn check_special_format(signed: &[u8], bytes: &[u8], options: &ParseOptions)
+    -> StdResult<(), (ErrorCode, *const u8)>
+{
+    let format = options.format;
+    let error = Err((ErrorCode::InvalidSpecial, bytes.as_ptr()));
+    let kind = if let Some(kind) = find_special_alias(bytes, options) {
+        kind
+    } else if is_infinity(bytes, options) {
+        if format.contains(NumberFormat::NO_LONG_INFINITY) || !is_special_case(bytes, options.infinity_string, format.infinity_case()) {
+            return error;
+        }
+        SpecialKind::Infinity
+    } else if is_inf(bytes, options) {
+        if !is_special_case(bytes, options.inf_string, format.infinity_case()) {
+            return error;
+        }
+        SpecialKind::Infinity
+    } else {
+        // The NaN string may have a signaling prefix and payload.
+        let nan = match options.nan_payloads {
+            true  => nan_prefix(bytes, options).map(|(is_signaling, end)| &index!(bytes[is_signaling as usize..end])),
+            false if is_nan(bytes, options) => Some(bytes),
+            false => None,
+        };
+        match nan {
+            Some(nan) if !is_special_case(nan, options.nan_string, format.nan_case()) => return error,
+            Some(_) => SpecialKind::NaN,
+            None    => return Ok(()),
+        }
+    };
+
+    // Check the sign before the special value.
+    let is_valid_sign = match (signed.len() != bytes.len(), index!(signed[0]), kind) {
+        (false, _, _)               => true,
+        (true, _, SpecialKind::NaN) if format.contains(NumberFormat::NO_SIGNED_NAN) => false,
+        (true, b'+', _)             => !format.contains(NumberFormat::NO_POSITIVE_SPECIAL_SIGN),
+        (true, _, _)                => true,
+    };
+    match is_valid_sign {
+        true  => Ok(()),
+        false => Err((ErrorCode::InvalidSpecial, signed.as_ptr())),
+    }
+}}
+
+// Determine if special values are disallowed by the options or number format.
+perftools_inline!{
+fn is_special_disallowed(options: &ParseOptions) -> bool {
+    #[cfg(feature = "format")] {
+        options.no_special || options.format.contains(NumberFormat::NO_SPECIAL)
+    }
+
+    #[cfg(not(feature = "format"))] {
+        options.no_special
+    }
 }}
 
 // PARSER
@@ -81,67 +185,227 @@ fn last(bytes: &[u8]) -> *const u8 {
     index!(bytes[bytes.len()..]).as_ptr()
 }}
 
+// Parse a C99 hexadecimal float, with a `0x` or `0X` prefix.
 perftools_inline!{
-fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign)
+#[cfg(feature = "radix")]
+fn parse_hex_float<F: StringToFloat>(bytes: &[u8], options: &ParseOptions, lossy: bool, sign: Sign)
     -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
 {
+    // Check the prefix, and then parse the remaining digits as
+    // a radix-16 mantissa with a binary exponent.
+    if index!(bytes[0]) != b'0' {
+        return Err((ErrorCode::InvalidDigit, bytes.as_ptr()));
+    }
+    let bytes = &index!(bytes[1..]);
+    match bytes.first() {
+        Some(&b'x') | Some(&b'X') => (),
+        _ => return Err((ErrorCode::InvalidDigit, bytes.as_ptr())),
+    }
+    let bytes = &index!(bytes[1..]);
+    let mut options = *options;
+    options.radix = 16;
+    match lossy {
+        true  => F::lossy(bytes, &options, sign),
+        false => F::default(bytes, &options, sign),
+    }
+}}
+
+// Parse a float with a base prefix, such as `0x`.
+perftools_inline!{
+#[cfg(feature = "radix")]
+fn parse_prefix_float<F: StringToFloat>(bytes: &[u8], options: &ParseOptions, lossy: bool, sign: Sign)
+    -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
+{
+    let (radix, bytes) = parse_base_prefix(bytes, options, false)?;
+    let mut options = *options;
+    options.radix = radix;
+    match lossy {
+        true  => F::lossy(bytes, &options, sign),
+        false => F::default(bytes, &options, sign),
+    }
+}}
+
+perftools_inline!{
+fn parse_float<F: StringToFloat>(bytes: &[u8], options: &ParseOptions, lossy: bool, sign: Sign)
+    -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
+{
+    #[cfg(feature = "radix")] {
+        if options.hex_float {
+            return parse_hex_float(bytes, options, lossy, sign);
+        } else if options.base_prefix != BasePrefix::Disabled {
+            return parse_prefix_float(bytes, options, lossy, sign);
+        }
+    }
+
     match lossy {
-        true  => F::lossy(bytes, radix, sign),
-        false => F::default(bytes, radix, sign),
+        true  => F::lossy(bytes, options, sign),
+        false => F::default(bytes, options, sign),
     }
 }}
 
 // Parse infinity from string.
 perftools_inline!{
-fn parse_infinity<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign)
+fn parse_infinity<F: StringToFloat>(bytes: &[u8], options: &ParseOptions, lossy: bool, sign: Sign)
     -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
 {
     // Check long infinity first before short infinity.
     // Short infinity short-circuits, we want to parse as many characters
     // as possible.
-    if is_infinity(bytes) || is_inf(bytes) {
+    if is_infinity(bytes, options) || is_inf(bytes, options) {
         // Have a valid long-form or short-form infinity.
+        if is_special_disallowed(options) {
+            return Err((ErrorCode::InvalidSpecial, bytes.as_ptr()));
+        }
         Ok((F::INFINITY, last(bytes)))
     } else {
         // Not infinity, may be valid with a different radix.
         if cfg!(feature = "radix"){
-            parse_float(bytes, radix, lossy, sign)
+            parse_float(bytes, options, lossy, sign)
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
     }
 }}
 
+// Get if the bytes start with a NaN string, with an optional signaling
+// prefix, and the length of the prefix and NaN string.
+perftools_inline!{
+fn nan_prefix(bytes: &[u8], options: &ParseOptions) -> Option<(bool, usize)> {
+    let is_signaling = match index!(bytes[0]) {
+        b's' | b'S' => true,
+        _           => false,
+    };
+    let start = is_signaling as usize;
+    let end = start + options.nan_string.len();
+    if bytes.len() >= end && is_special_string(&index!(bytes[start..end]), options.nan_string, options) {
+        Some((is_signaling, end))
+    } else {
+        None
+    }
+}}
+
+// Parse the NaN payload between parentheses, like C99 `strtod`.
+//
+// The payload may be hexadecimal, with a `0x` prefix, octal, with
+// a leading `0`, or decimal. Returns the payload and a pointer to
+// the end of the payload, after the closing parenthesis. Overflow is
+// reported at the start of the payload.
+perftools_inline!{
+fn parse_nan_payload(bytes: &[u8])
+    -> StdResult<(u64, *const u8), (ErrorCode, *const u8)>
+{
+    debug_assert!(index!(bytes[0]) == b'(');
+    let digits = &index!(bytes[1..]);
+    let (radix, digits) = match (digits.first(), digits.get(1)) {
+        (Some(&b'0'), Some(&b'x')) | (Some(&b'0'), Some(&b'X')) => (16, &index!(digits[2..])),
+        (Some(&b'0'), _) => (8, digits),
+        _ => (10, digits),
+    };
+
+    let mut payload: u64 = 0;
+    for (i, &c) in digits.iter().enumerate() {
+        let digit = match (c as char).to_digit(radix) {
+            Some(digit) => digit as u64,
+            None if c == b')' && (i != 0 || radix != 16) => {
+                return Ok((payload, index!(digits[i+1..]).as_ptr()));
+            },
+            None => return Err((ErrorCode::InvalidDigit, index!(digits[i..]).as_ptr())),
+        };
+        payload = match payload.checked_mul(radix as u64).and_then(|v| v.checked_add(digit)) {
+            Some(v) => v,
+            None    => return Err((ErrorCode::Overflow, index!(bytes[1..]).as_ptr())),
+        };
+    }
+    Err((ErrorCode::InvalidDigit, last(digits)))
+}}
+
+// Parse NaN with an optional signaling prefix and payload from string.
+perftools_inline!{
+fn parse_nan_with_payload<F: StringToFloat>(bytes: &[u8], options: &ParseOptions, lossy: bool, sign: Sign)
+    -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
+{
+    let (is_signaling, length) = match nan_prefix(bytes, options) {
+        Some(prefix) => prefix,
+        None if cfg!(feature = "radix") => return parse_float(bytes, options, lossy, sign),
+        None => return Err((ErrorCode::InvalidDigit, bytes.as_ptr())),
+    };
+    if is_special_disallowed(options) {
+        return Err((ErrorCode::InvalidSpecial, bytes.as_ptr()));
+    }
+
+    // Parse the payload, if present.
+    let rest = &index!(bytes[length..]);
+    let (payload, ptr) = match rest.first() {
+        Some(&b'(') => parse_nan_payload(rest)?,
+        _ if is_signaling => (default_signaling_payload::<F>(), rest.as_ptr()),
+        _ => (0, rest.as_ptr()),
+    };
+    match nan_from_payload(is_signaling, payload) {
+        Some(value) => Ok((value, ptr)),
+        None if payload == 0 => Err((ErrorCode::InvalidDigit, index!(rest[1..]).as_ptr())),
+        None => Err((ErrorCode::Overflow, index!(rest[1..]).as_ptr())),
+    }
+}}
+
 // Parse NaN from string.
 perftools_inline!{
-fn parse_nan<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign)
+fn parse_nan<F: StringToFloat>(bytes: &[u8], options: &ParseOptions, lossy: bool, sign: Sign)
     -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
 {
-    if is_nan(bytes) {
+    if options.nan_payloads {
+        parse_nan_with_payload(bytes, options, lossy, sign)
+    } else if is_nan(bytes, options) {
         // Have a valid NaN.
+        if is_special_disallowed(options) {
+            return Err((ErrorCode::InvalidSpecial, bytes.as_ptr()));
+        }
         Ok((F::NAN, last(bytes)))
     } else {
         // Not NaN, may be valid with a different radix.
         if cfg!(feature = "radix"){
-            parse_float(bytes, radix, lossy, sign)
+            parse_float(bytes, options, lossy, sign)
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
     }
 }}
 
+// Parse a special value from an alias spanning the bytes.
+perftools_inline!{
+fn parse_special_alias<F: StringToFloat>(bytes: &[u8], kind: SpecialKind, options: &ParseOptions)
+    -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
+{
+    if is_special_disallowed(options) {
+        return Err((ErrorCode::InvalidSpecial, bytes.as_ptr()));
+    }
+    match kind {
+        SpecialKind::NaN        => Ok((F::NAN, last(bytes))),
+        SpecialKind::Infinity   => Ok((F::INFINITY, last(bytes))),
+    }
+}}
+
 // ATOF/ATOD
 
 // Standalone atof processor.
 perftools_inline!{
-fn atof<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool)
+fn atof<F: StringToFloat>(bytes: &[u8], options: &ParseOptions, lossy: bool)
     -> StdResult<(F, *const u8), (ErrorCode, *const u8)>
 {
-    // Filter out empty inputs.
+    // Skip whitespace, and filter out empty inputs.
+    let input = skip_leading_whitespace(bytes, options);
+    let bytes = trim_trailing_whitespace(input, options);
     if bytes.is_empty() {
         return Err((ErrorCode::Empty, bytes.as_ptr()));
     }
 
+    #[cfg(feature = "format")] {
+        if options.format.contains(NumberFormat::NO_POSITIVE_MANTISSA_SIGN) && index!(bytes[0]) == b'+' {
+            return Err((ErrorCode::InvalidPositiveMantissaSign, bytes.as_ptr()));
+        }
+    }
+
+    #[cfg(feature = "format")]
+    let signed = bytes;
     let (sign, bytes) = match index!(bytes[0]) {
         b'+' => (Sign::Positive, &index!(bytes[1..])),
         b'-' => (Sign::Negative, &index!(bytes[1..])),
@@ -153,13 +417,24 @@ fn atof<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool)
         return Err((ErrorCode::Empty, bytes.as_ptr()));
     }
 
+    #[cfg(feature = "format")] {
+        if options.format.intersects(NumberFormat::SPECIAL_FLAG_MASK) {
+            check_special_format(signed, bytes, options)?;
+        }
+    }
+
     // Special case checks
-    // Use predictive parsing to filter special cases. This leads to
-    // dramatic performance gains.
-    let (float, ptr): (F, *const u8) = match index!(bytes[0]) {
-        b'i' | b'I' => parse_infinity(bytes, radix, lossy, sign),
-        b'N' | b'n' => parse_nan(bytes, radix, lossy, sign),
-        _           => parse_float(bytes, radix, lossy, sign),
+    // Aliases may start with any character, such as `1.#INF`, so they
+    // are checked first. Otherwise, use predictive parsing to filter
+    // special cases. This leads to dramatic performance gains.
+    let (float, ptr): (F, *const u8) = match find_special_alias(bytes, options) {
+        Some(kind) => parse_special_alias(bytes, kind, options),
+        None => match index!(bytes[0]) {
+            b'i' | b'I' => parse_infinity(bytes, options, lossy, sign),
+            b'N' | b'n' => parse_nan(bytes, options, lossy, sign),
+            b'S' | b's' if options.nan_payloads => parse_nan(bytes, options, lossy, sign),
+            _           => parse_float(bytes, options, lossy, sign),
+        },
     }?;
 
     // Process the sign.
@@ -167,26 +442,26 @@ fn atof<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool)
         Sign::Positive => float,
         Sign::Negative => -float,
     };
-    Ok((signed_float, ptr))
+    Ok((signed_float, skip_trailing_whitespace(input, ptr, options)))
 }}
 
 perftools_inline!{
-fn atof_lossy<F: StringToFloat>(bytes: &[u8], radix: u32)
+fn atof_lossy<F: StringToFloat>(bytes: &[u8], options: &ParseOptions)
     -> Result<(F, usize)>
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, true) {
+    match atof::<F>(bytes, options, true) {
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
 }}
 
 perftools_inline!{
-fn atof_nonlossy<F: StringToFloat>(bytes: &[u8], radix: u32)
+fn atof_nonlossy<F: StringToFloat>(bytes: &[u8], options: &ParseOptions)
     -> Result<(F, usize)>
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, false) {
+    match atof::<F>(bytes, options, false) {
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
@@ -195,8 +470,12 @@ fn atof_nonlossy<F: StringToFloat>(bytes: &[u8], radix: u32)
 // FROM LEXICAL
 // ------------
 
-from_lexical!(atof_nonlossy, f32);
-from_lexical!(atof_nonlossy, f64);
+from_lexical!(atof_nonlossy, f16, Float);
+from_lexical!(atof_nonlossy, bf16, Float);
+from_lexical!(atof_nonlossy, f32, Float);
+from_lexical!(atof_nonlossy, f64, Float);
+from_lexical_lossy!(atof_lossy, f16);
+from_lexical_lossy!(atof_lossy, bf16);
 from_lexical_lossy!(atof_lossy, f32);
 from_lexical_lossy!(atof_lossy, f64);
 
@@ -417,6 +696,323 @@ mod tests {
         assert_f64_eq!(1234.0, f64::from_lexical_lossy_radix(b"YA", 36).unwrap());
     }
 
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_hex_float_test() {
+        let options = ParseOptions::hex_float();
+        assert_eq!(Ok(12.0), f64::from_lexical_with_options(b"0x1.8p+3", &options));
+        assert_eq!(Ok(12.0), f64::from_lexical_with_options(b"0X1.8P3", &options));
+        assert_eq!(Ok(-24.0), f64::from_lexical_with_options(b"-0x18", &options));
+        assert_eq!(Ok(0.5), f64::from_lexical_with_options(b"0x.8", &options));
+        assert_eq!(Ok(0.1), f64::from_lexical_with_options(b"0x1.999999999999ap-4", &options));
+        assert_eq!(Ok(0.1), f32::from_lexical_with_options(b"0x1.99999ap-4", &options));
+        assert_eq!(Ok(1e-45), f32::from_lexical_with_options(b"0x1p-149", &options));
+        assert_eq!(Ok(5e-324), f64::from_lexical_with_options(b"0x0.0000000000001p-1022", &options));
+        assert_eq!(Ok(f64::MAX), f64::from_lexical_with_options(b"0x1.fffffffffffffp+1023", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"0x1p+1024", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b"0x1p-1100", &options));
+        assert_eq!(Ok(1.0), f64::from_lexical_with_options(b"0x1.00000000000000000001p0", &options));
+        assert_eq!(Ok(1.0), f64::from_lexical_with_options(b"0x100p-8", &options));
+
+        // Halfway cases with truncated mantissas.
+        #[cfg(feature = "correct")]
+        assert_eq!(Ok(1.0), f64::from_lexical_with_options(b"0x1.0000000000000800p0", &options));
+        #[cfg(feature = "correct")]
+        assert_eq!(Ok(1.0000000000000004), f64::from_lexical_with_options(b"0x1.0000000000001800p0", &options));
+        #[cfg(feature = "correct")]
+        assert_eq!(Ok(1.0000000000000002), f64::from_lexical_with_options(b"0x1.0000000000000801p0", &options));
+        #[cfg(feature = "correct")]
+        assert_eq!(Ok(1.0000000000000002), f64::from_lexical_with_options(b"0x1.00000000000008000000000000000001p0", &options));
+        #[cfg(feature = "correct")]
+        assert_eq!(Ok(0x3F800000), f32::from_lexical_with_options(b"0x1.00000100000000000000000p0", &options).map(f32::to_bits));
+        #[cfg(feature = "correct")]
+        assert_eq!(Ok(0x3F800001), f32::from_lexical_with_options(b"0x1.00000100000000000000001p0", &options).map(f32::to_bits));
+        #[cfg(feature = "correct")]
+        assert_eq!(Ok(0x3F800002), f32::from_lexical_with_options(b"0x1.00000300000000000000000p0", &options).map(f32::to_bits));
+
+        assert!(f64::from_lexical_with_options(b"inf", &options).unwrap().is_infinite());
+        assert!(f64::from_lexical_with_options(b"NaN", &options).unwrap().is_nan());
+        assert_eq!(Ok(30.0), f64::from_lexical_with_options(b"0x1e", &options));
+        assert_eq!(Ok((8.0, 5)), f64::from_lexical_partial_with_options(b"0x1p3;", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), f64::from_lexical_with_options(b"1.5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_with_options(b"01.5", &options));
+        assert_eq!(Err((ErrorCode::EmptyFraction, 3).into()), f64::from_lexical_with_options(b"-0x", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), f64::from_lexical_with_options(b"0x1p", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), f64::from_lexical_with_options(b"0x1p1a", &options));
+    }
+
+    #[test]
+    fn f64_nan_payload_test() {
+        let mut options = ParseOptions::new();
+        let to_bits = | r: Result<f64> | r.map(f64::to_bits);
+        assert!(f64::from_lexical_with_options(b"nan(0x1)", &options).is_err());
+
+        options.set_nan_payloads(true);
+        assert_eq!(Ok(0x7FF8000000000000), to_bits(f64::from_lexical_with_options(b"nan", &options)));
+        assert_eq!(Ok(0xFFF8000000000000), to_bits(f64::from_lexical_with_options(b"-NaN", &options)));
+        assert_eq!(Ok(0x7FF8000000001234), to_bits(f64::from_lexical_with_options(b"nan(0x1234)", &options)));
+        assert_eq!(Ok(0x7FF8000000001234), to_bits(f64::from_lexical_with_options(b"NAN(0X1234)", &options)));
+        assert_eq!(Ok(0x7FF800000000000A), to_bits(f64::from_lexical_with_options(b"nan(012)", &options)));
+        assert_eq!(Ok(0x7FF800000000000C), to_bits(f64::from_lexical_with_options(b"nan(12)", &options)));
+        assert_eq!(Ok(0x7FF8000000000000), to_bits(f64::from_lexical_with_options(b"nan()", &options)));
+        assert_eq!(Ok(0x7FF4000000000000), to_bits(f64::from_lexical_with_options(b"snan", &options)));
+        assert_eq!(Ok(0xFFF0000000000001), to_bits(f64::from_lexical_with_options(b"-sNaN(1)", &options)));
+        assert_eq!(Ok(0x7FFFFFFFFFFFFFFF), to_bits(f64::from_lexical_with_options(b"nan(0x7ffffffffffff)", &options)));
+        assert_eq!(Ok((0x7FF8000000000001, 6)), f64::from_lexical_partial_with_options(b"nan(1);", &options).map(|(v, n)| (v.to_bits(), n)));
+        assert_eq!(Ok((0x7FF8000000000000, 3)), f64::from_lexical_partial_with_options(b"nan;", &options).map(|(v, n)| (v.to_bits(), n)));
+        assert_eq!(Err((ErrorCode::Overflow, 4).into()), to_bits(f64::from_lexical_with_options(b"nan(0x8000000000000)", &options)));
+        assert_eq!(Err((ErrorCode::Overflow, 4).into()), to_bits(f64::from_lexical_with_options(b"nan(99999999999999999999)", &options)));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), to_bits(f64::from_lexical_with_options(b"snan(0)", &options)));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 6).into()), to_bits(f64::from_lexical_with_options(b"nan(0x)", &options)));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), to_bits(f64::from_lexical_with_options(b"nan(08)", &options)));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 6).into()), to_bits(f64::from_lexical_with_options(b"nan(12", &options)));
+        assert!(f64::from_lexical_with_options(b"sn", &options).is_err());
+
+        assert_eq!(Ok(0x7FC01234), f32::from_lexical_with_options(b"nan(0x1234)", &options).map(f32::to_bits));
+        assert_eq!(Ok(0x7FA00000), f32::from_lexical_with_options(b"snan", &options).map(f32::to_bits));
+        assert_eq!(Err((ErrorCode::Overflow, 4).into()), f32::from_lexical_with_options(b"nan(0x400000)", &options));
+    }
+
+    #[test]
+    fn f64_whitespace_test() {
+        let mut options = ParseOptions::new();
+        assert_eq!(Err((ErrorCode::EmptyFraction, 0).into()), f64::from_lexical_with_options(b" 1.5", &options));
+
+        options.set_whitespace(Whitespace::Both);
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b" 1.5\n", &options));
+        assert_eq!(Ok(-1e5), f64::from_lexical_with_options(b"\t-1e5 ", &options));
+        assert!(f64::from_lexical_with_options(b" inf ", &options).unwrap().is_infinite());
+        assert!(f64::from_lexical_with_options(b" NaN ", &options).unwrap().is_nan());
+        assert_eq!(Ok((1.5, 5)), f64::from_lexical_partial_with_options(b" 1.5 ;", &options));
+        assert_eq!(Ok(1.5), f64::from_lexical_lossy_with_options(b" 1.5 ", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), f64::from_lexical_with_options(b"  1.5x", &options));
+        assert_eq!(Err((ErrorCode::EmptyFraction, 2).into()), f64::from_lexical_with_options(b"  . ", &options));
+        assert_eq!(Err((ErrorCode::Empty, 2).into()), f64::from_lexical_with_options(b"  ", &options));
+        assert_eq!(Err((ErrorCode::EmptyFraction, 0).into()), f64::from_lexical_with_options(b"\x0C1.5", &options));
+
+        options.set_whitespace_set(WhitespaceSet::C);
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"\x0C1.5\x0B", &options));
+    }
+
+    const ALIASES: &'static [SpecialAlias] = &[
+        SpecialAlias { alias: b"1.#INF", kind: SpecialKind::Infinity },
+        SpecialAlias { alias: b"1.#QNAN", kind: SpecialKind::NaN },
+        SpecialAlias { alias: b"1.#IND", kind: SpecialKind::NaN },
+        SpecialAlias { alias: b"\xE2\x88\x9E", kind: SpecialKind::Infinity },
+        SpecialAlias { alias: b"NA", kind: SpecialKind::NaN },
+    ];
+
+    #[test]
+    fn f64_special_alias_test() {
+        let mut options = ParseOptions::new();
+        assert!(f64::from_lexical_with_options(b"1.#INF", &options).is_err());
+
+        options.set_special_aliases(ALIASES);
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"1.#INF", &options));
+        assert_eq!(Ok(f64::NEG_INFINITY), f64::from_lexical_with_options(b"-1.#inf", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options("+∞".as_bytes(), &options));
+        assert!(f64::from_lexical_with_options(b"-1.#IND", &options).unwrap().is_nan());
+        assert!(f64::from_lexical_with_options(b"1.#QNAN", &options).unwrap().is_nan());
+        assert!(f64::from_lexical_with_options(b"NA", &options).unwrap().is_nan());
+        assert!(f64::from_lexical_with_options(b"NaN", &options).unwrap().is_nan());
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"Infinity", &options));
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"1.5", &options));
+        assert_eq!(Ok((1.0, 2)), f64::from_lexical_partial_with_options(b"1.#INFx", &options));
+
+        options.set_whitespace(Whitespace::Both);
+        assert_eq!(Ok((f64::NEG_INFINITY, 9)), f64::from_lexical_partial_with_options(b" -1.#INF ", &options));
+    }
+
+    #[test]
+    fn f64_special_case_sensitive_test() {
+        let mut options = ParseOptions::new();
+        options.set_special_aliases(ALIASES);
+        options.set_special_case_sensitive(true);
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"inf", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"1.#INF", &options));
+        assert!(f64::from_lexical_with_options(b"NaN", &options).unwrap().is_nan());
+        assert!(f64::from_lexical_with_options(b"INF", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"nan", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"na", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"1.#inf", &options).is_err());
+    }
+
+    #[test]
+    fn f64_no_special_test() {
+        let mut options = ParseOptions::new();
+        options.set_special_aliases(ALIASES);
+        options.set_nan_payloads(true);
+        options.set_no_special(true);
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"inf", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 1).into()), f64::from_lexical_with_options(b"-Infinity", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"NaN", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"nan(0x1234)", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 1).into()), f64::from_lexical_with_options(b"-1.#IND", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"NA", &options));
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"1.5", &options));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_base_prefix_test() {
+        let mut options = ParseOptions::new();
+        options.set_base_prefix(BasePrefix::Detect);
+        assert_eq!(Ok(31.5), f64::from_lexical_with_options(b"0x1F.8", &options));
+        assert_eq!(Ok(-2.5), f64::from_lexical_with_options(b"-0b10.1", &options));
+        assert_eq!(Ok(17.5), f64::from_lexical_with_options(b"017.5", &options));
+        assert_eq!(Ok(1e2), f64::from_lexical_with_options(b"1e2", &options));
+        assert_eq!(Err((ErrorCode::Empty, 2).into()), f64::from_lexical_with_options(b"0x", &options));
+
+        let mut options = ParseOptions::new();
+        options.set_radix(2);
+        options.set_base_prefix(BasePrefix::Required);
+        assert_eq!(Ok(2.5), f64::from_lexical_with_options(b"0b10.1", &options));
+        assert_eq!(Err((ErrorCode::InvalidBasePrefix, 0).into()), f64::from_lexical_with_options(b"10.1", &options));
+    }
+
+    #[test]
+    fn f16_decimal_test() {
+        let to_bits = | r: Result<f16> | r.map(f16::to_bits);
+        assert_eq!(Ok(0x0000), to_bits(f16::from_lexical(b"0")));
+        assert_eq!(Ok(0x8000), to_bits(f16::from_lexical(b"-0.0")));
+        assert_eq!(Ok(0x3C00), to_bits(f16::from_lexical(b"1")));
+        assert_eq!(Ok(0x3E00), to_bits(f16::from_lexical(b"1.5")));
+        assert_eq!(Ok(0xC500), to_bits(f16::from_lexical(b"-5")));
+        assert_eq!(Ok(0x2E66), to_bits(f16::from_lexical(b"0.1")));
+        assert_eq!(Ok(0x5BB6), to_bits(f16::from_lexical(b"246.8")));
+        assert_eq!(Ok(0x6C00), to_bits(f16::from_lexical(b"4096")));
+        assert_eq!(Ok(0x6C00), to_bits(f16::from_lexical(b"4098")));
+        assert_eq!(Ok(0x6C01), to_bits(f16::from_lexical(b"4099")));
+        assert_eq!(Ok(0x6C02), to_bits(f16::from_lexical(b"4102")));
+        assert_eq!(Ok(0x7BFF), to_bits(f16::from_lexical(b"65504")));
+        assert_eq!(Ok(0x7BFF), to_bits(f16::from_lexical(b"6.55e4")));
+        assert_eq!(Ok(0x7BFF), to_bits(f16::from_lexical(b"65519.99")));
+        assert_eq!(Ok(0x7C00), to_bits(f16::from_lexical(b"65520")));
+        assert_eq!(Ok(0xFC00), to_bits(f16::from_lexical(b"-1e5")));
+        assert_eq!(Ok(0x0400), to_bits(f16::from_lexical(b"6.104e-5")));
+        assert_eq!(Ok(0x0001), to_bits(f16::from_lexical(b"5.96e-8")));
+        assert_eq!(Ok(0x0001), to_bits(f16::from_lexical(b"3e-8")));
+        assert_eq!(Ok(0x0000), to_bits(f16::from_lexical(b"2.98e-8")));
+        assert_eq!(Ok(0x0000), to_bits(f16::from_lexical(b"1e-300")));
+        assert_eq!(Ok(0x7C00), to_bits(f16::from_lexical(b"inf")));
+        assert!(f16::from_lexical(b"NaN").unwrap().is_nan());
+        assert_eq!(Ok((0x3E00, 3)), f16::from_lexical_partial(b"1.5;").map(|(v, n)| (v.to_bits(), n)));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f16::from_lexical(b"1a"));
+        assert_eq!(Err(ErrorCode::Empty.into()), f16::from_lexical(b""));
+        assert_eq!(Ok(0x2E66), to_bits(f16::from_lexical_lossy(b"0.1")));
+    }
+
+    #[test]
+    fn bf16_decimal_test() {
+        let to_bits = | r: Result<bf16> | r.map(bf16::to_bits);
+        assert_eq!(Ok(0x3F80), to_bits(bf16::from_lexical(b"1")));
+        assert_eq!(Ok(0x4049), to_bits(bf16::from_lexical(b"3.14159")));
+        assert_eq!(Ok(0x3DCD), to_bits(bf16::from_lexical(b"0.1")));
+        assert_eq!(Ok(0x4380), to_bits(bf16::from_lexical(b"257")));
+        assert_eq!(Ok(0x4382), to_bits(bf16::from_lexical(b"259")));
+        assert_eq!(Ok(0x7F7F), to_bits(bf16::from_lexical(b"3.39e38")));
+        assert_eq!(Ok(0x7F80), to_bits(bf16::from_lexical(b"3.4e38")));
+        assert_eq!(Ok(0x0080), to_bits(bf16::from_lexical(b"1.1754944e-38")));
+        assert_eq!(Ok(0x0001), to_bits(bf16::from_lexical(b"9.2e-41")));
+        assert_eq!(Ok(0x0000), to_bits(bf16::from_lexical(b"4e-41")));
+        assert_eq!(Ok(0x3DCD), to_bits(bf16::from_lexical_lossy(b"0.1")));
+    }
+
+    #[test]
+    fn f16_nan_payload_test() {
+        let mut options = ParseOptions::new();
+        options.set_nan_payloads(true);
+        let to_bits = | r: Result<f16> | r.map(f16::to_bits);
+        assert_eq!(Ok(0x7E00), to_bits(f16::from_lexical_with_options(b"nan", &options)));
+        assert_eq!(Ok(0x7E12), to_bits(f16::from_lexical_with_options(b"nan(0x12)", &options)));
+        assert_eq!(Ok(0x7D00), to_bits(f16::from_lexical_with_options(b"snan", &options)));
+        assert_eq!(Err((ErrorCode::Overflow, 4).into()), to_bits(f16::from_lexical_with_options(b"nan(0x200)", &options)));
+        assert_eq!(Ok(0x7FC1), bf16::from_lexical_with_options(b"nan(1)", &options).map(bf16::to_bits));
+    }
+
+    #[cfg(feature = "correct")]
+    #[test]
+    fn f16_roundtrip_test() {
+        // Every finite float is written with the shortest digits,
+        // which parse back to the same float.
+        let mut buffer = [b'\0'; 64];
+        for bits in 0..0x7C00u16 {
+            let value = f16::from_bits(bits);
+            let string = value.to_lexical(&mut buffer);
+            assert_eq!(Ok(bits), f16::from_lexical(string).map(f16::to_bits));
+        }
+        for bits in 0..0x7F80u16 {
+            let value = bf16::from_bits(bits);
+            let string = value.to_lexical(&mut buffer);
+            assert_eq!(Ok(bits), bf16::from_lexical(string).map(bf16::to_bits));
+        }
+    }
+
+    #[cfg(feature = "correct")]
+    #[test]
+    fn f16_halfway_test() {
+        // The exact decimal value of the halfway point between 2 floats
+        // rounds to even, and any digit after the value rounds up.
+        let mut buffer = [b'\0'; 256];
+        let mut check = | lower: f64, upper: f64, even: u16, above: u16, parse: &dyn Fn(&[u8]) -> u16 | {
+            let halfway = (lower + upper) / 2.0;
+            let len = halfway.to_lexical_exact(&mut buffer).len();
+            assert_eq!(parse(&buffer[..len]), even);
+            let len = match buffer[..len].contains(&b'.') {
+                true  => len + copy_to_dst(&mut buffer[len..], b"1"),
+                false => len + copy_to_dst(&mut buffer[len..], b".1"),
+            };
+            assert_eq!(parse(&buffer[..len]), above);
+        };
+
+        let parse_f16 = | bytes: &[u8] | f16::from_lexical(bytes).unwrap().to_bits();
+        for bits in 0..0x7C00u16 {
+            let even = bits + (bits & 1);
+            let lower = f16::from_bits(bits).to_f64();
+            let upper = f16::from_bits(bits + 1).to_f64();
+            let upper = match upper.is_infinite() {
+                true  => 65536.0,
+                false => upper,
+            };
+            check(lower, upper, even, bits + 1, &parse_f16);
+        }
+
+        let parse_bf16 = | bytes: &[u8] | bf16::from_lexical(bytes).unwrap().to_bits();
+        for bits in 0..0x7F80u16 {
+            let even = bits + (bits & 1);
+            let lower = bf16::from_bits(bits).to_f64();
+            let upper = bf16::from_bits(bits + 1).to_f64();
+            let upper = match upper.is_infinite() {
+                true  => 2f64.powi(128),
+                false => upper,
+            };
+            check(lower, upper, even, bits + 1, &parse_bf16);
+        }
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f16_radix_test() {
+        let to_bits = | r: Result<f16> | r.map(f16::to_bits);
+        assert_eq!(Ok(0x64D2), to_bits(f16::from_lexical_radix(b"YA", 36)));
+        assert_eq!(Ok(0x0001), to_bits(f16::from_lexical_radix(b"0.000000000000000000000001", 2)));
+        assert_eq!(Ok(0x0000), to_bits(f16::from_lexical_radix(b"0.0000000000000000000000001", 2)));
+        assert_eq!(Ok(0x0002), to_bits(f16::from_lexical_radix(b"0.0000000000000000000000011", 2)));
+        assert_eq!(Ok(0x7BFF), to_bits(f16::from_lexical_radix(b"1111111111100000", 2)));
+        assert_eq!(Ok(0x7C00), to_bits(f16::from_lexical_radix(b"1111111111110000", 2)));
+        assert_eq!(Ok(0x7BFF), to_bits(f16::from_lexical_radix(b"FFE0", 16)));
+        assert_eq!(Ok(0x0001), bf16::from_lexical_radix(b"1e-10000101", 2).map(bf16::to_bits));
+
+        let options = ParseOptions::hex_float();
+        assert_eq!(Ok(0x0001), to_bits(f16::from_lexical_with_options(b"0x1p-24", &options)));
+        assert_eq!(Ok(0x0000), to_bits(f16::from_lexical_with_options(b"0x1p-25", &options)));
+        assert_eq!(Ok(0x0001), to_bits(f16::from_lexical_with_options(b"0x1.8p-25", &options)));
+        assert_eq!(Ok(0x03FF), to_bits(f16::from_lexical_with_options(b"0x1.ff8p-15", &options)));
+        assert_eq!(Ok(0x7BFF), to_bits(f16::from_lexical_with_options(b"0x1.ffcp15", &options)));
+        assert_eq!(Ok(0x7C00), to_bits(f16::from_lexical_with_options(b"0x1.ffep15", &options)));
+        assert_eq!(Ok(0x0001), bf16::from_lexical_with_options(b"0x1p-133", &options).map(bf16::to_bits));
+    }
+
     #[test]
     fn f32_lossy_decimal_test() {
         assert_eq!(Err(ErrorCode::EmptyFraction.into()), f32::from_lexical_lossy(b"."));
@@ -439,6 +1035,183 @@ mod tests {
         assert_eq!(Ok(5.002868148396374), f64::from_lexical_lossy(b"5.002868148396374"));
     }
 
+    #[cfg(feature = "format")]
+    #[test]
+    fn f64_digit_separator_test() {
+        let mut options = ParseOptions::new();
+        let format = NumberFormat::compile(b'_', NumberFormat::INTERNAL_DIGIT_SEPARATOR).unwrap();
+        options.set_format(format);
+        assert_f64_eq!(1000000.0, f64::from_lexical_with_options(b"1_000_000", &options).unwrap());
+        assert_f64_eq!(0.000001, f64::from_lexical_with_options(b"0.000_001", &options).unwrap());
+        assert_f64_eq!(-1234.5678, f64::from_lexical_with_options(b"-1_234.567_8", &options).unwrap());
+        assert_f64_eq!(1.5e100, f64::from_lexical_with_options(b"1.5e1_00", &options).unwrap());
+        assert_f64_eq!(1.5e-100, f64::from_lexical_with_options(b"1.5e-1_00", &options).unwrap());
+        assert_f64_eq!(0.0, f64::from_lexical_with_options(b"0_0.0_0", &options).unwrap());
+        assert_f64_eq!(123456789012345678901234567890.0, f64::from_lexical_with_options(b"123_456_789_012_345_678_901_234_567_890", &options).unwrap());
+        assert_f64_eq!(1.7976931348623157e308, f64::from_lexical_with_options(b"179_769_313_486_231_570_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000_000", &options).unwrap());
+        assert_f64_eq!(9007199254740992.0, f64::from_lexical_with_options(b"9_007_199_254_740_993", &options).unwrap());
+        assert_f64_eq!(9007199254740994.0, f64::from_lexical_with_options(b"9_007_199_254_740_993.000_000_000_000_000_000_1", &options).unwrap());
+        assert_f64_eq!(2.2250738585072014e-308, f64::from_lexical_with_options(b"2.225_073_858_507_201_4e-3_08", &options).unwrap());
+        assert_f64_eq!(0.1, f64::from_lexical_lossy_with_options(b"0.1_0", &options).unwrap());
+        assert_f64_eq!(1.5e3, f64::from_lexical_lossy_with_options(b"1_5_0_0", &options).unwrap());
+
+        // Misplaced separators.
+        assert_eq!(Err((ErrorCode::EmptyFraction, 0).into()), f64::from_lexical_with_options(b"_1.0", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_with_options(b"1_.0", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), f64::from_lexical_with_options(b"1._0", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), f64::from_lexical_with_options(b"1.0_", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_with_options(b"1__0", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), f64::from_lexical_with_options(b"1e_0", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), f64::from_lexical_with_options(b"1e0_", &options));
+        assert_eq!(Ok((1.0, 1)), f64::from_lexical_partial_with_options(b"1_", &options));
+
+        let flags = NumberFormat::INTERNAL_DIGIT_SEPARATOR
+            | NumberFormat::LEADING_DIGIT_SEPARATOR
+            | NumberFormat::TRAILING_DIGIT_SEPARATOR
+            | NumberFormat::CONSECUTIVE_DIGIT_SEPARATOR;
+        let format = NumberFormat::compile(b'\'', flags).unwrap();
+        options.set_format(format);
+        assert_f64_eq!(-1000.5, f64::from_lexical_with_options(b"-''1''000''.''5''", &options).unwrap());
+        assert_f64_eq!(1.5e10, f64::from_lexical_with_options(b"1.5e''1''0''", &options).unwrap());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), f32::from_lexical_with_options(b"'1'.'", &options));
+        assert_eq!(Err((ErrorCode::EmptyFraction, 0).into()), f64::from_lexical_with_options(b"'.'", &options));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn f64_format_preset_test() {
+        let options = ParseOptions::format(NumberFormat::JSON);
+        assert_f64_eq!(1.5, f64::from_lexical_with_options(b"1.5", &options).unwrap());
+        assert_f64_eq!(-0.5e-3, f64::from_lexical_with_options(b"-0.5e-3", &options).unwrap());
+        assert_f64_eq!(1.5e3, f64::from_lexical_with_options(b"1.5E+3", &options).unwrap());
+        assert_f64_eq!(10.0, f64::from_lexical_with_options(b"10", &options).unwrap());
+        assert_eq!(Err((ErrorCode::EmptyInteger, 0).into()), f64::from_lexical_with_options(b".5", &options));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 1).into()), f64::from_lexical_with_options(b"-.5", &options));
+        assert_eq!(Err((ErrorCode::EmptyFraction, 2).into()), f64::from_lexical_with_options(b"5.", &options));
+        assert_eq!(Err((ErrorCode::EmptyFraction, 2).into()), f64::from_lexical_with_options(b"5.e3", &options));
+        assert_eq!(Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()), f64::from_lexical_with_options(b"+5", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), f64::from_lexical_with_options(b"05.0", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"NaN", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 1).into()), f64::from_lexical_with_options(b"-inf", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_with_options(b"1e", &options));
+
+        let options = ParseOptions::format(NumberFormat::TOML);
+        assert_f64_eq!(1000.5, f64::from_lexical_with_options(b"+1_000.5", &options).unwrap());
+        assert_f64_eq!(1e10, f64::from_lexical_with_options(b"1e1_0", &options).unwrap());
+        assert!(f64::from_lexical_with_options(b"inf", &options).unwrap().is_infinite());
+        assert_eq!(Ok(f64::NEG_INFINITY), f64::from_lexical_with_options(b"-inf", &options));
+        assert!(f64::from_lexical_with_options(b"+nan", &options).unwrap().is_nan());
+        assert!(f64::from_lexical_with_options(b"-nan", &options).unwrap().is_nan());
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"NaN", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 1).into()), f64::from_lexical_with_options(b"+INF", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"infinity", &options));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 0).into()), f64::from_lexical_with_options(b".5", &options));
+        assert_eq!(Err((ErrorCode::EmptyFraction, 2).into()), f64::from_lexical_with_options(b"5.", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), f64::from_lexical_with_options(b"00.5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_with_options(b"1__0.5", &options));
+
+        let options = ParseOptions::format(NumberFormat::YAML);
+        assert_f64_eq!(0.5, f64::from_lexical_with_options(b".5", &options).unwrap());
+        assert_f64_eq!(5.0, f64::from_lexical_with_options(b"+05.", &options).unwrap());
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"inf", &options));
+
+        let options = ParseOptions::format(NumberFormat::XML);
+        assert_f64_eq!(0.5, f64::from_lexical_with_options(b"+.5", &options).unwrap());
+        assert_f64_eq!(5.0, f64::from_lexical_with_options(b"5.", &options).unwrap());
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"INF", &options));
+        assert_eq!(Ok(f64::NEG_INFINITY), f64::from_lexical_with_options(b"-INF", &options));
+        assert!(f64::from_lexical_with_options(b"NaN", &options).unwrap().is_nan());
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_with_options(b"5e", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"inf", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"nan", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"NAN", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"INFINITY", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"infinity", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"+INF", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"-NaN", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 1).into()), f64::from_lexical_with_options(b"-inf", &options));
+
+        let mut payload_options = options;
+        payload_options.set_nan_payloads(true);
+        assert!(f64::from_lexical_with_options(b"NaN(1)", &payload_options).unwrap().is_nan());
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"snan", &payload_options));
+
+        let options = ParseOptions::format(NumberFormat::RUST_LITERAL);
+        assert_f64_eq!(1000.5, f64::from_lexical_with_options(b"1__000_.5_", &options).unwrap());
+        assert_f64_eq!(1.0, f64::from_lexical_with_options(b"1.", &options).unwrap());
+        assert_f64_eq!(1e10, f64::from_lexical_with_options(b"1e_1_0_", &options).unwrap());
+        assert_f64_eq!(1.5e5, f64::from_lexical_with_options(b"1.5e5", &options).unwrap());
+        assert_eq!(Err((ErrorCode::EmptyFraction, 2).into()), f64::from_lexical_with_options(b"1.e5", &options));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 0).into()), f64::from_lexical_with_options(b".5", &options));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 0).into()), f64::from_lexical_with_options(b"_1.5", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), f64::from_lexical_with_options(b"1._5", &options));
+        assert_eq!(Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()), f64::from_lexical_with_options(b"+1.5", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"NaN", &options));
+
+        let options = ParseOptions::format(NumberFormat::PYTHON_LITERAL);
+        assert_f64_eq!(0.5, f64::from_lexical_with_options(b".5", &options).unwrap());
+        assert_f64_eq!(5.0, f64::from_lexical_with_options(b"05.", &options).unwrap());
+        assert_f64_eq!(0.0, f64::from_lexical_with_options(b"00", &options).unwrap());
+        assert_f64_eq!(1000.5, f64::from_lexical_with_options(b"1_000.5", &options).unwrap());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), f64::from_lexical_with_options(b"1_000_.5", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"inf", &options));
+
+        let options = ParseOptions::format(NumberFormat::C_LITERAL);
+        assert_f64_eq!(0.5, f64::from_lexical_with_options(b".5", &options).unwrap());
+        assert_f64_eq!(5.0, f64::from_lexical_with_options(b"5.", &options).unwrap());
+        assert_eq!(Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()), f64::from_lexical_with_options(b"+5.", &options));
+        assert_eq!(Err((ErrorCode::InvalidSpecial, 0).into()), f64::from_lexical_with_options(b"infinity", &options));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn f64_strict_format_test() {
+        let options = ParseOptions::format(NumberFormat::REQUIRED_INTEGER_DIGITS);
+        assert_f64_eq!(0.5, f64::from_lexical_with_options(b"0.5", &options).unwrap());
+        assert_eq!(Err((ErrorCode::EmptyInteger, 0).into()), f64::from_lexical_with_options(b".5", &options));
+
+        let options = ParseOptions::format(NumberFormat::REQUIRED_FRACTION_DIGITS);
+        assert_f64_eq!(0.5, f64::from_lexical_with_options(b".5", &options).unwrap());
+        assert_f64_eq!(5.0, f64::from_lexical_with_options(b"5", &options).unwrap());
+        assert_eq!(Err((ErrorCode::EmptyFraction, 2).into()), f64::from_lexical_with_options(b"5.", &options));
+
+        let options = ParseOptions::format(NumberFormat::REQUIRED_EXPONENT_DIGITS);
+        assert_f64_eq!(5e3, f64::from_lexical_with_options(b"5e3", &options).unwrap());
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_with_options(b"5e", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 3).into()), f64::from_lexical_with_options(b"5e-", &options));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_with_options(b"5ex", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), f64::from_lexical(b"5ex"));
+
+        let options = ParseOptions::format(NumberFormat::NO_POSITIVE_MANTISSA_SIGN);
+        assert_f64_eq!(-5.0, f64::from_lexical_with_options(b"-5", &options).unwrap());
+        assert_f64_eq!(5e3, f64::from_lexical_with_options(b"5e+3", &options).unwrap());
+        assert_eq!(Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()), f64::from_lexical_with_options(b"+5", &options));
+
+        let options = ParseOptions::format(NumberFormat::NO_POSITIVE_EXPONENT_SIGN);
+        assert_f64_eq!(5e-3, f64::from_lexical_with_options(b"+5e-3", &options).unwrap());
+        assert_eq!(Err((ErrorCode::InvalidPositiveExponentSign, 2).into()), f64::from_lexical_with_options(b"5e+3", &options));
+
+        let options = ParseOptions::format(NumberFormat::NO_FLOAT_LEADING_ZEROS);
+        assert_f64_eq!(0.5, f64::from_lexical_with_options(b"0.5", &options).unwrap());
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 1).into()), f64::from_lexical_with_options(b"-01.5", &options));
+
+        let options = ParseOptions::format(NumberFormat::NO_EXPONENT_WITHOUT_FRACTION);
+        assert_f64_eq!(5e3, f64::from_lexical_with_options(b"5.0e3", &options).unwrap());
+        assert_f64_eq!(5e3, f64::from_lexical_with_options(b"5.e3", &options).unwrap());
+        assert_eq!(Err((ErrorCode::ExponentWithoutFraction, 1).into()), f64::from_lexical_with_options(b"5e3", &options));
+
+        let format = NumberFormat::REQUIRED_DIGITS | NumberFormat::NO_EXPONENT_WITHOUT_FRACTION;
+        let options = ParseOptions::format(format);
+        assert_f64_eq!(5e3, f64::from_lexical_with_options(b"5.0e3", &options).unwrap());
+        assert_eq!(Err((ErrorCode::EmptyFraction, 2).into()), f64::from_lexical_with_options(b"5.e3", &options));
+
+        let options = ParseOptions::format(NumberFormat::NO_EXPONENT_WITHOUT_FRACTION_DIGITS);
+        assert_f64_eq!(5e3, f64::from_lexical_with_options(b"5e3", &options).unwrap());
+        assert_f64_eq!(5.0, f64::from_lexical_with_options(b"5.", &options).unwrap());
+        assert_f64_eq!(5e3, f64::from_lexical_with_options(b"5.0E3", &options).unwrap());
+        assert_eq!(Err((ErrorCode::EmptyFraction, 2).into()), f64::from_lexical_with_options(b"5.e3", &options));
+    }
+
     #[cfg(feature = "std")]
     proptest! {
         #[test]
@@ -6,3 +6,4 @@ mod api;
 
 // Re-exports
 pub use self::api::*;
+pub(crate) use self::algorithm::{Bigint, LargeOps, Limb, SharedOps, SmallOps};
@@ -160,6 +160,172 @@ pub(crate) fn standalone<T>(bytes: &[u8], radix: u32, is_signed: bool)
     parse_digits(digits, radix, sign)
 }}
 
+// STANDALONE SEPARATOR
+// --------------------
+
+// Consume digits and digit separators until an invalid character is found.
+//
+// The flags must be shifted so the component flags are the integer flags
+// (see `NumberFormat::component_separator_flags`). Digit separators are
+// only consumed if they are valid at their location, so the remaining
+// bytes start with any misplaced digit separator.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(crate) fn consume_digits_separator<'a>(bytes: &'a [u8], radix: u32, separator: u8, flags: NumberFormat)
+    -> (&'a [u8], &'a [u8])
+{
+    let leading = flags.contains(NumberFormat::INTEGER_LEADING_DIGIT_SEPARATOR);
+    let internal = flags.contains(NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR);
+    let trailing = flags.contains(NumberFormat::INTEGER_TRAILING_DIGIT_SEPARATOR);
+    let consecutive = flags.contains(NumberFormat::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR);
+
+    let is_digit = | c: u8 | (c as char).is_digit(radix);
+    let len = bytes.len();
+    let mut index = 0;
+    while index < len {
+        let c = index!(bytes[index]);
+        if is_digit(c) {
+            index += 1;
+            continue;
+        } else if separator == 0 || c != separator {
+            break;
+        }
+
+        // Find the end of the digit separators, and classify them
+        // by whether digits occur before and after them.
+        let mut end = index + 1;
+        while end < len && index!(bytes[end]) == separator {
+            end += 1;
+        }
+        let has_previous = index != 0;
+        let has_next = end < len && is_digit(index!(bytes[end]));
+        let is_valid = match (has_previous, has_next) {
+            (false, true)   => leading,
+            (true, true)    => internal,
+            (true, false)   => trailing,
+            (false, false)  => false,
+        };
+        if !is_valid || (end - index > 1 && !consecutive) {
+            break;
+        }
+        index = end;
+    }
+    (&index!(bytes[..index]), &index!(bytes[index..]))
+}}
+
+// Determine if the digits have a leading zero followed by other digits.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(crate) fn has_leading_zeros(digits: &[u8], separator: u8) -> bool {
+    let mut iter = digits.iter().filter(|&&c| separator == 0 || c != separator);
+    match iter.next() {
+        Some(&b'0') => iter.next().is_some(),
+        _           => false,
+    }
+}}
+
+/// Iterate over the digits, skipping digit separators, and iteratively process them.
+#[cfg(feature = "format")]
+macro_rules! parse_digits_separator {
+    ($value:ident, $digits:ident, $radix:ident, $separator:ident, $op:ident, $code:ident) => (
+        for c in $digits.iter() {
+            if *c == $separator {
+                continue;
+            }
+            let digit = match to_digit!(*c, $radix) {
+                Some(v) => v,
+                None    => return Ok(($value, c)),
+            };
+            $value = match $value.checked_mul(as_cast($radix)) {
+                Some(v) => v,
+                None    => return Err((ErrorCode::$code, c)),
+            };
+            $value = match $value.$op(as_cast(digit)) {
+                Some(v) => v,
+                None    => return Err((ErrorCode::$code, c)),
+            };
+        }
+    );
+}
+
+// Parse the digits for the atoi processor, skipping digit separators.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(crate) fn parse_digits_separator<T>(digits: &[u8], radix: u32, separator: u8, sign: Sign)
+    -> StdResult<(T, *const u8), (ErrorCode, *const u8)>
+    where T: Integer
+{
+    let mut value = T::ZERO;
+    if sign == Sign::Positive {
+        parse_digits_separator!(value, digits, radix, separator, checked_add, Overflow);
+    } else {
+        parse_digits_separator!(value, digits, radix, separator, checked_sub, Underflow);
+    }
+    let ptr = index!(digits[digits.len()..]).as_ptr();
+    Ok((value, ptr))
+}}
+
+// Standalone atoi processor for a number format.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(crate) fn standalone_format<T>(bytes: &[u8], radix: u32, format: NumberFormat, is_signed: bool)
+    -> StdResult<(T, *const u8), (ErrorCode, *const u8)>
+    where T: Integer
+{
+    let (sign, digits) = parse_sign!(bytes, is_signed, Empty);
+    if format.contains(NumberFormat::NO_POSITIVE_MANTISSA_SIGN) && index!(bytes[0]) == b'+' {
+        return Err((ErrorCode::InvalidPositiveMantissaSign, bytes.as_ptr()));
+    }
+
+    parse_digits_format(digits, radix, format, sign)
+}}
+
+// Parse the digits for the atoi processor with a number format.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn parse_digits_format<T>(digits: &[u8], radix: u32, format: NumberFormat, sign: Sign)
+    -> StdResult<(T, *const u8), (ErrorCode, *const u8)>
+    where T: Integer
+{
+    let separator = format.digit_separator();
+    let flags = format.component_separator_flags(INTEGER_SHIFT);
+    let (digits, _) = consume_digits_separator(digits, radix, separator, flags);
+    if has_leading_zeros(digits, separator) {
+        let is_zero = digits.iter().all(|&c| c == b'0' || c == separator);
+        if format.contains(NumberFormat::NO_INTEGER_LEADING_ZEROS)
+            || (format.contains(NumberFormat::NO_NONZERO_INTEGER_LEADING_ZEROS) && !is_zero)
+        {
+            return Err((ErrorCode::InvalidLeadingZeros, digits.as_ptr()));
+        }
+    }
+    parse_digits_separator(digits, radix, separator, sign)
+}}
+
+// STANDALONE PREFIX
+// -----------------
+
+// Standalone atoi processor with base prefixes.
+perftools_inline!{
+#[cfg(feature = "radix")]
+fn standalone_prefix<T>(bytes: &[u8], options: &ParseOptions, is_signed: bool)
+    -> StdResult<(T, *const u8), (ErrorCode, *const u8)>
+    where T: Integer
+{
+    let (sign, digits) = parse_sign!(bytes, is_signed, Empty);
+    let (radix, digits) = parse_base_prefix(digits, options, true)?;
+
+    #[cfg(feature = "format")] {
+        let format = options.number_format();
+        if format.contains(NumberFormat::NO_POSITIVE_MANTISSA_SIGN) && index!(bytes[0]) == b'+' {
+            return Err((ErrorCode::InvalidPositiveMantissaSign, bytes.as_ptr()));
+        } else if !format.is_empty() {
+            return parse_digits_format(digits, radix, format, sign);
+        }
+    }
+
+    parse_digits(digits, radix, sign)
+}}
+
 // STANDALONE U128
 // ---------------
 
@@ -386,6 +552,40 @@ pub(crate) fn standalone_mantissa<'a, T>(integer: &'a [u8], fraction: &'a [u8],
     (value, 0)
 }}
 
+// Calculate the mantissa and the number of truncated digits from a digits
+// iterator, skipping digit separators.
+// All the data **must** be a valid digit or digit separator.
+perftools_inline!{
+#[cfg(all(feature = "correct", feature = "format"))]
+pub(crate) fn standalone_mantissa_separator<'a, T>(integer: &'a [u8], fraction: &'a [u8], radix: u32, separator: u8)
+    -> (T, usize)
+    where T: UnsignedInteger
+{
+    let is_digit = | c: &&u8 | **c != separator;
+    let mut integer_iter = integer.iter().filter(is_digit);
+    let mut fraction_iter = fraction.iter().filter(is_digit);
+    let mut value: T = T::ZERO;
+    while let Some(c) = integer_iter.next() {
+        value = match add_digit(value, to_digit!(*c, radix).unwrap(), radix) {
+            Some(v) => v,
+            None    => {
+                let truncated = 1 + integer_iter.count() + fraction_iter.count();
+                return (value, truncated);
+            },
+        };
+    }
+    while let Some(c) = fraction_iter.next() {
+        value = match add_digit(value, to_digit!(*c, radix).unwrap(), radix) {
+            Some(v) => v,
+            None    => {
+                let truncated = 1 + fraction_iter.count();
+                return (value, truncated);
+            },
+        };
+    }
+    (value, 0)
+}}
+
 // Calculate the mantissa when it cannot have sign or other invalid digits.
 perftools_inline!{
 #[cfg(not(feature = "correct"))]
@@ -421,8 +621,8 @@ macro_rules! add_digit {
 
 // Iterate over the digits and iteratively process them.
 macro_rules! parse_digits_exponent {
-    ($value:ident, $digits:ident, $radix:ident, $op:ident, $default:expr) => (
-        let mut iter = $digits.iter();
+    ($value:ident, $iter:expr, $radix:ident, $op:ident, $default:expr) => (
+        let mut iter = $iter;
         while let Some(c) = iter.next() {
             let digit = match to_digit(c, $radix) {
                 Ok(v)  => v,
@@ -452,9 +652,38 @@ pub(crate) fn standalone_exponent(bytes: &[u8], radix: u32)
     let (sign, digits) = parse_sign!(bytes, true, EmptyExponent);
     let mut value = 0;
     if sign == Sign::Positive {
-        parse_digits_exponent!(value, digits, radix, checked_add, i32::max_value());
+        parse_digits_exponent!(value, digits.iter(), radix, checked_add, i32::max_value());
+    } else {
+        parse_digits_exponent!(value, digits.iter(), radix, checked_sub, i32::min_value());
+    }
+    let ptr = index!(digits[digits.len()..]).as_ptr();
+    Ok((value, ptr))
+}}
+
+// Specialized parser for the exponent with a number format, which
+// validates digits and returns a default min or max value on overflow.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(crate) fn standalone_exponent_format(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> StdResult<(i32, *const u8), (ErrorCode, *const u8)>
+{
+    let (sign, digits) = parse_sign!(bytes, true, EmptyExponent);
+    if format.contains(NumberFormat::NO_POSITIVE_EXPONENT_SIGN) && index!(bytes[0]) == b'+' {
+        return Err((ErrorCode::InvalidPositiveExponentSign, bytes.as_ptr()));
+    }
+
+    let separator = format.digit_separator();
+    let flags = format.component_separator_flags(EXPONENT_SHIFT);
+    let (digits, _) = consume_digits_separator(digits, radix, separator, flags);
+    if format.contains(NumberFormat::REQUIRED_EXPONENT_DIGITS) && digits.is_empty() {
+        return Err((ErrorCode::EmptyExponent, digits.as_ptr()));
+    }
+    let iter = digits.iter().filter(|&&c| c != separator);
+    let mut value = 0;
+    if sign == Sign::Positive {
+        parse_digits_exponent!(value, iter, radix, checked_add, i32::max_value());
     } else {
-        parse_digits_exponent!(value, digits, radix, checked_sub, i32::min_value());
+        parse_digits_exponent!(value, iter, radix, checked_sub, i32::min_value());
     }
     let ptr = index!(digits[digits.len()..]).as_ptr();
     Ok((value, ptr))
@@ -463,15 +692,47 @@ pub(crate) fn standalone_exponent(bytes: &[u8], radix: u32)
 // INTERNAL
 // --------
 
+// Parse the integer with the atoi processor for the options.
+perftools_inline!{
+fn atoi_options<T>(bytes: &[u8], options: &ParseOptions, is_signed: bool)
+    -> StdResult<(T, *const u8), (ErrorCode, *const u8)>
+    where T: Atoi
+{
+    let bytes = skip_leading_whitespace(bytes, options);
+    let (value, ptr) = atoi_digits::<T>(bytes, options, is_signed)?;
+    Ok((value, skip_trailing_whitespace(bytes, ptr, options)))
+}}
+
+// Parse the integer without surrounding whitespace.
+perftools_inline!{
+fn atoi_digits<T>(bytes: &[u8], options: &ParseOptions, is_signed: bool)
+    -> StdResult<(T, *const u8), (ErrorCode, *const u8)>
+    where T: Atoi
+{
+    #[cfg(feature = "radix")] {
+        if options.base_prefix != BasePrefix::Disabled {
+            return standalone_prefix(bytes, options, is_signed);
+        }
+    }
+
+    #[cfg(feature = "format")] {
+        let format = options.number_format();
+        if !format.is_empty() {
+            return standalone_format(bytes, options.radix, format, is_signed);
+        }
+    }
+    T::atoi(bytes, options.radix, is_signed)
+}}
+
 // Handle unsigned +/- numbers and forward to implied implementation.
 //  Can just use local namespace
 perftools_inline!{
-pub(crate) fn standalone_unsigned<'a, T>(bytes: &'a [u8], radix: u32)
+pub(crate) fn standalone_unsigned<'a, T>(bytes: &'a [u8], options: &ParseOptions)
     -> Result<(T, usize)>
     where T: Atoi + UnsignedInteger
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match T::atoi(bytes, radix, false) {
+    match atoi_options::<T>(bytes, options, false) {
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
@@ -480,12 +741,12 @@ pub(crate) fn standalone_unsigned<'a, T>(bytes: &'a [u8], radix: u32)
 // Handle signed +/- numbers and forward to implied implementation.
 //  Can just use local namespace
 perftools_inline!{
-pub(crate) fn standalone_signed<'a, T>(bytes: &'a [u8], radix: u32)
+pub(crate) fn standalone_signed<'a, T>(bytes: &'a [u8], options: &ParseOptions)
     -> Result<(T, usize)>
     where T: Atoi + SignedInteger
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match T::atoi(bytes, radix, true) {
+    match atoi_options::<T>(bytes, options, true) {
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
@@ -494,19 +755,19 @@ pub(crate) fn standalone_signed<'a, T>(bytes: &'a [u8], radix: u32)
 // FROM LEXICAL
 // ------------
 
-from_lexical!(standalone_unsigned, u8);
-from_lexical!(standalone_unsigned, u16);
-from_lexical!(standalone_unsigned, u32);
-from_lexical!(standalone_unsigned, u64);
-from_lexical!(standalone_unsigned, usize);
-#[cfg(has_i128)] from_lexical!(standalone_unsigned, u128);
+from_lexical!(standalone_unsigned, u8, Unsigned);
+from_lexical!(standalone_unsigned, u16, Unsigned);
+from_lexical!(standalone_unsigned, u32, Unsigned);
+from_lexical!(standalone_unsigned, u64, Unsigned);
+from_lexical!(standalone_unsigned, usize, Unsigned);
+#[cfg(has_i128)] from_lexical!(standalone_unsigned, u128, Unsigned);
 
-from_lexical!(standalone_signed, i8);
-from_lexical!(standalone_signed, i16);
-from_lexical!(standalone_signed, i32);
-from_lexical!(standalone_signed, i64);
-from_lexical!(standalone_signed, isize);
-#[cfg(has_i128)] from_lexical!(standalone_signed, i128);
+from_lexical!(standalone_signed, i8, Signed);
+from_lexical!(standalone_signed, i16, Signed);
+from_lexical!(standalone_signed, i32, Signed);
+from_lexical!(standalone_signed, i64, Signed);
+from_lexical!(standalone_signed, isize, Signed);
+#[cfg(has_i128)] from_lexical!(standalone_signed, i128, Signed);
 
 // TESTS
 // -----
@@ -662,6 +923,162 @@ mod tests {
         assert_eq!(Err((ErrorCode::Overflow, 19).into()), i64::from_lexical(b"406260572150672006000066000000060060007667760000000000000000000+00000006766767766666767665670000000000000000000000666"));
     }
 
+    #[cfg(feature = "format")]
+    #[test]
+    fn consume_digits_separator_test() {
+        let internal = NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR;
+        let leading = NumberFormat::INTEGER_LEADING_DIGIT_SEPARATOR;
+        let trailing = NumberFormat::INTEGER_TRAILING_DIGIT_SEPARATOR;
+        let consecutive = NumberFormat::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR;
+        assert_eq!(super::consume_digits_separator(b"1_2", 10, b'_', internal), (&b"1_2"[..], &b""[..]));
+        assert_eq!(super::consume_digits_separator(b"1__2", 10, b'_', internal), (&b"1"[..], &b"__2"[..]));
+        assert_eq!(super::consume_digits_separator(b"1__2", 10, b'_', internal | consecutive), (&b"1__2"[..], &b""[..]));
+        assert_eq!(super::consume_digits_separator(b"_12", 10, b'_', internal), (&b""[..], &b"_12"[..]));
+        assert_eq!(super::consume_digits_separator(b"_12", 10, b'_', leading), (&b"_12"[..], &b""[..]));
+        assert_eq!(super::consume_digits_separator(b"12_.", 10, b'_', internal), (&b"12"[..], &b"_."[..]));
+        assert_eq!(super::consume_digits_separator(b"12_.", 10, b'_', trailing), (&b"12_"[..], &b"."[..]));
+        assert_eq!(super::consume_digits_separator(b"_", 10, b'_', leading | trailing), (&b""[..], &b"_"[..]));
+        assert_eq!(super::consume_digits_separator(b"1_2", 10, 0, internal), (&b"1"[..], &b"_2"[..]));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn digit_separator_test() {
+        let mut options = ParseOptions::new();
+        let format = NumberFormat::compile(b'_', NumberFormat::INTERNAL_DIGIT_SEPARATOR).unwrap();
+        options.set_format(format);
+        assert_eq!(Ok(1000000), u32::from_lexical_with_options(b"1_000_000", &options));
+        assert_eq!(Ok(-1000), i32::from_lexical_with_options(b"-1_000", &options));
+        assert_eq!(Ok(1000), i32::from_lexical_with_options(b"+1_000", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), i32::from_lexical_with_options(b"_1000", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), i32::from_lexical_with_options(b"1000_", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical_with_options(b"1__000", &options));
+        assert_eq!(Err((ErrorCode::Overflow, 4).into()), u8::from_lexical_with_options(b"2_5_6", &options));
+        assert_eq!(Ok((1000, 5)), i32::from_lexical_partial_with_options(b"1_000_", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical(b"1_000"));
+
+        let format = NumberFormat::compile(b'\'', NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR).unwrap();
+        options.set_format(format);
+        assert_eq!(Ok(1000), u64::from_lexical_with_options(b"1'000", &options));
+
+        let flags = NumberFormat::INTERNAL_DIGIT_SEPARATOR
+            | NumberFormat::LEADING_DIGIT_SEPARATOR
+            | NumberFormat::TRAILING_DIGIT_SEPARATOR
+            | NumberFormat::CONSECUTIVE_DIGIT_SEPARATOR;
+        let format = NumberFormat::compile(b'_', flags).unwrap();
+        options.set_format(format);
+        assert_eq!(Ok(-1000), i16::from_lexical_with_options(b"-__1__000__", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i16::from_lexical_with_options(b"-__", &options));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn format_preset_test() {
+        let options = ParseOptions::format(NumberFormat::JSON);
+        assert_eq!(Ok(10), i32::from_lexical_with_options(b"10", &options));
+        assert_eq!(Ok(0), i32::from_lexical_with_options(b"0", &options));
+        assert_eq!(Ok(-1), i32::from_lexical_with_options(b"-1", &options));
+        assert_eq!(Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()), i32::from_lexical_with_options(b"+1", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), i32::from_lexical_with_options(b"01", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 1).into()), i32::from_lexical_with_options(b"-00", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical_with_options(b"1_0", &options));
+
+        let options = ParseOptions::format(NumberFormat::TOML);
+        assert_eq!(Ok(1000), i32::from_lexical_with_options(b"+1_000", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), i32::from_lexical_with_options(b"0_1", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical_with_options(b"1__0", &options));
+
+        let options = ParseOptions::format(NumberFormat::RUST_LITERAL);
+        assert_eq!(Ok(1000), u64::from_lexical_with_options(b"1__000_", &options));
+        assert_eq!(Ok(1), u64::from_lexical_with_options(b"01", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u64::from_lexical_with_options(b"_1", &options));
+        assert_eq!(Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()), u64::from_lexical_with_options(b"+1", &options));
+
+        let options = ParseOptions::format(NumberFormat::PYTHON_LITERAL);
+        assert_eq!(Ok(1000), i16::from_lexical_with_options(b"1_000", &options));
+        assert_eq!(Ok(0), i16::from_lexical_with_options(b"00", &options));
+        assert_eq!(Ok(0), i16::from_lexical_with_options(b"0_0", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), i16::from_lexical_with_options(b"01", &options));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), i16::from_lexical_with_options(b"0_1", &options));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn standalone_exponent_format_test() {
+        let format = NumberFormat::REQUIRED_EXPONENT_DIGITS | NumberFormat::NO_POSITIVE_EXPONENT_SIGN;
+        assert_eq!(super::standalone_exponent_format(b"-10", 10, format).map(|(v, _)| v), Ok(-10));
+        assert_eq!(super::standalone_exponent_format(b"10", 10, format).map(|(v, _)| v), Ok(10));
+        assert_eq!(super::standalone_exponent_format(b"+10", 10, format).map_err(|(c, _)| c), Err(ErrorCode::InvalidPositiveExponentSign));
+        assert_eq!(super::standalone_exponent_format(b"-x", 10, format).map_err(|(c, _)| c), Err(ErrorCode::EmptyExponent));
+        assert_eq!(super::standalone_exponent_format(b"x", 10, NumberFormat::NO_SPECIAL).map(|(v, _)| v), Ok(0));
+    }
+
+    #[test]
+    fn whitespace_test() {
+        let mut options = ParseOptions::new();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), i32::from_lexical_with_options(b" 42", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), i32::from_lexical_with_options(b"42\n", &options));
+
+        options.set_whitespace(Whitespace::Leading);
+        assert_eq!(Ok(42), i32::from_lexical_with_options(b" \t42", &options));
+        assert_eq!(Ok(-42), i32::from_lexical_with_options(b"\n-42", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), i32::from_lexical_with_options(b" 42 ", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), i32::from_lexical_with_options(b"  - 42", &options));
+        assert_eq!(Err((ErrorCode::Empty, 3).into()), u32::from_lexical_with_options(b"   ", &options));
+
+        options.set_whitespace(Whitespace::Trailing);
+        assert_eq!(Ok(42), u32::from_lexical_with_options(b"42\r\n", &options));
+        assert_eq!(Ok((42, 3)), u32::from_lexical_partial_with_options(b"42 x", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), u32::from_lexical_with_options(b"42 x", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u32::from_lexical_with_options(b" 42", &options));
+
+        options.set_whitespace(Whitespace::Both);
+        assert_eq!(Ok(42), u64::from_lexical_with_options(b"  42  ", &options));
+        assert_eq!(Err((ErrorCode::Overflow, 4).into()), u8::from_lexical_with_options(b"  256  ", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u8::from_lexical_with_options(b"\x0B1\x0C", &options));
+
+        options.set_whitespace_set(WhitespaceSet::C);
+        assert_eq!(Ok(1), u8::from_lexical_with_options(b"\x0B1\x0C", &options));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn base_prefix_test() {
+        let mut options = ParseOptions::new();
+        options.set_base_prefix(BasePrefix::Detect);
+        assert_eq!(Ok(31), i32::from_lexical_with_options(b"0x1F", &options));
+        assert_eq!(Ok(31), i32::from_lexical_with_options(b"0X1f", &options));
+        assert_eq!(Ok(-10), i32::from_lexical_with_options(b"-0b1010", &options));
+        assert_eq!(Ok(15), u32::from_lexical_with_options(b"0o17", &options));
+        assert_eq!(Ok(15), u32::from_lexical_with_options(b"017", &options));
+        assert_eq!(Ok(17), u32::from_lexical_with_options(b"17", &options));
+        assert_eq!(Ok(0), u32::from_lexical_with_options(b"0", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), u32::from_lexical_with_options(b"08", &options));
+        assert_eq!(Err((ErrorCode::Empty, 2).into()), u32::from_lexical_with_options(b"0x", &options));
+        assert_eq!(Err((ErrorCode::Empty, 3).into()), i32::from_lexical_with_options(b"-0b", &options));
+
+        options.set_prefix_case_sensitive(true);
+        assert_eq!(Ok(31), i32::from_lexical_with_options(b"0x1F", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical_with_options(b"0X1F", &options));
+
+        let mut options = ParseOptions::new();
+        options.set_radix(16);
+        options.set_base_prefix(BasePrefix::Required);
+        assert_eq!(Ok(31), u32::from_lexical_with_options(b"0x1F", &options));
+        assert_eq!(Ok(-31), i32::from_lexical_with_options(b"-0x1F", &options));
+        assert_eq!(Err((ErrorCode::InvalidBasePrefix, 0).into()), u32::from_lexical_with_options(b"1F", &options));
+        assert_eq!(Err((ErrorCode::InvalidBasePrefix, 1).into()), i32::from_lexical_with_options(b"-0b1", &options));
+
+        options.set_base_prefix(BasePrefix::Optional);
+        assert_eq!(Ok(31), u32::from_lexical_with_options(b"0x1F", &options));
+        assert_eq!(Ok(31), u32::from_lexical_with_options(b"1F", &options));
+        assert_eq!(Ok(0xB1), u32::from_lexical_with_options(b"0b1", &options));
+
+        let mut options = ParseOptions::new();
+        options.set_base_prefix(BasePrefix::Required);
+        assert_eq!(Ok(10), u32::from_lexical_with_options(b"10", &options));
+    }
+
     #[cfg(feature = "std")]
     proptest! {
         #[test]
@@ -169,6 +169,38 @@ pub trait FloatRounding<M: Mantissa>: Float {
     const CARRY_MASK: M;
 }
 
+// Literals don't work for generic types, we need to use this as a hack.
+macro_rules! float_rounding_f16 {
+    ($($t:tt)*) => ($(
+        impl FloatRounding<$t> for f16 {
+            const DEFAULT_SHIFT: i32    = $t::FULL - f16::MANTISSA_SIZE - 1;
+            const CARRY_MASK: $t        = 0x800;
+        }
+    )*)
+}
+
+#[cfg(has_i128)]
+float_rounding_f16! { u64 u128 }
+
+#[cfg(not(has_i128))]
+float_rounding_f16! { u64 }
+
+// Literals don't work for generic types, we need to use this as a hack.
+macro_rules! float_rounding_bf16 {
+    ($($t:tt)*) => ($(
+        impl FloatRounding<$t> for bf16 {
+            const DEFAULT_SHIFT: i32    = $t::FULL - bf16::MANTISSA_SIZE - 1;
+            const CARRY_MASK: $t        = 0x100;
+        }
+    )*)
+}
+
+#[cfg(has_i128)]
+float_rounding_bf16! { u64 u128 }
+
+#[cfg(not(has_i128))]
+float_rounding_bf16! { u64 }
+
 // Literals don't work for generic types, we need to use this as a hack.
 macro_rules! float_rounding_f32 {
     ($($t:tt)*) => ($(
@@ -325,17 +357,17 @@ pub(crate) fn internal_rounding(kind: RoundingKind, sign: Sign)
     }
 }}
 
-// Get the global, default rounding scheme.
+// Get the rounding scheme from the parser options.
 perftools_inline!{
 #[cfg(feature = "correct")]
 #[allow(unused_variables)]
-pub(crate) fn global_rounding(sign: Sign) -> RoundingKind {
+pub(crate) fn options_rounding(options: &ParseOptions, sign: Sign) -> RoundingKind {
     #[cfg(not(feature = "rounding"))] {
         RoundingKind::NearestTieEven
     }
 
     #[cfg(feature = "rounding")] {
-        internal_rounding(get_float_rounding(), sign)
+        internal_rounding(options.rounding, sign)
     }
 }}
 
@@ -50,10 +50,19 @@
 //  ax.legend(loc=2, prop={'size': 14})
 //  plt.show()
 
+use float::rounding::internal_rounding;
+use lib::cmp;
 use util::*;
 
+use super::fixed::*;
+use super::notation::*;
+use super::shortest::half_decimal;
+
+#[cfg(feature = "radix")]
+use super::hex::{double_hex, double_hex_len, float_hex, float_hex_len};
+
 #[cfg(feature = "radix")]
-use super::radix::{double_radix, float_radix};
+use super::radix::{double_radix, double_radix_layout, float_radix, float_radix_layout, half_radix, half_radix_layout};
 
 // Select the back-end
 cfg_if! {
@@ -68,13 +77,155 @@ if #[cfg(feature = "grisu3")] {
 // TRAITS
 
 /// Trait to define serialization of a float to string.
-pub(crate) trait FloatToString: Float {
+pub(crate) trait FloatToString: Float + ToLexical {
     /// Export float to decimal string with optimized algorithm.
     fn decimal<'a>(self, bytes: &'a mut [u8]) -> usize;
 
+    /// Export float to decimal string with a fixed number of fraction digits.
+    fn fixed<'a>(self, precision: usize, kind: RoundingKind, bytes: &'a mut [u8]) -> usize;
+
+    /// Get the number of integer digits written by `fixed`.
+    fn fixed_digits(self, precision: usize, kind: RoundingKind) -> usize;
+
+    /// Export the significant digits and decimal exponent of a float.
+    fn significant<'a>(self, max_digits: usize, kind: RoundingKind, digits: &'a mut [u8]) -> (usize, i32);
+
+    /// Export float to its exact decimal value.
+    fn exact<'a>(self, bytes: &'a mut [u8]) -> usize;
+
+    /// Get the number of bytes of the exact decimal value.
+    fn exact_len(self) -> usize;
+
     /// Export float to radix string with slow algorithm.
     #[cfg(feature = "radix")]
-    fn radix<'a>(self, radix: u32, bytes: &'a mut [u8]) -> usize;
+    fn radix<'a>(self, options: &WriteOptions, bytes: &'a mut [u8]) -> usize;
+
+    /// Get the layout of the radix string written by `radix`.
+    #[cfg(feature = "radix")]
+    fn radix_layout(self, options: &WriteOptions) -> Layout;
+
+    /// Export float to C99 hexadecimal float string.
+    #[cfg(feature = "radix")]
+    fn hex<'a>(self, bytes: &'a mut [u8]) -> usize;
+
+    /// Get the number of bytes written by `hex`.
+    #[cfg(feature = "radix")]
+    fn hex_len(self) -> usize;
+}
+
+// The 16-bit floats are exactly representable as an f32, so the
+// fixed-precision, significant-digit, exact and hexadecimal digits
+// are those of the f32. Only the shortest digits differ.
+impl FloatToString for f16 {
+    perftools_inline!{
+    fn decimal<'a>(self, bytes: &'a mut [u8]) -> usize {
+        half_decimal(self, bytes)
+    }}
+
+    perftools_inline!{
+    fn fixed<'a>(self, precision: usize, kind: RoundingKind, bytes: &'a mut [u8]) -> usize {
+        float_fixed(self.to_f32(), precision, kind, bytes)
+    }}
+
+    perftools_inline!{
+    fn fixed_digits(self, precision: usize, kind: RoundingKind) -> usize {
+        float_fixed_digits(self.to_f32(), precision, kind)
+    }}
+
+    perftools_inline!{
+    fn significant<'a>(self, max_digits: usize, kind: RoundingKind, digits: &'a mut [u8]) -> (usize, i32) {
+        float_significant(self.to_f32(), max_digits, kind, digits)
+    }}
+
+    perftools_inline!{
+    fn exact<'a>(self, bytes: &'a mut [u8]) -> usize {
+        float_exact(self.to_f32(), bytes)
+    }}
+
+    perftools_inline!{
+    fn exact_len(self) -> usize {
+        float_exact_len(self.to_f32())
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn radix<'a>(self, options: &WriteOptions, bytes: &'a mut [u8]) -> usize {
+        half_radix(self, options, bytes)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn radix_layout(self, options: &WriteOptions) -> Layout {
+        half_radix_layout(self, options)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn hex<'a>(self, bytes: &'a mut [u8]) -> usize {
+        float_hex(self.to_f32(), bytes)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn hex_len(self) -> usize {
+        float_hex_len(self.to_f32())
+    }}
+}
+
+impl FloatToString for bf16 {
+    perftools_inline!{
+    fn decimal<'a>(self, bytes: &'a mut [u8]) -> usize {
+        half_decimal(self, bytes)
+    }}
+
+    perftools_inline!{
+    fn fixed<'a>(self, precision: usize, kind: RoundingKind, bytes: &'a mut [u8]) -> usize {
+        float_fixed(self.to_f32(), precision, kind, bytes)
+    }}
+
+    perftools_inline!{
+    fn fixed_digits(self, precision: usize, kind: RoundingKind) -> usize {
+        float_fixed_digits(self.to_f32(), precision, kind)
+    }}
+
+    perftools_inline!{
+    fn significant<'a>(self, max_digits: usize, kind: RoundingKind, digits: &'a mut [u8]) -> (usize, i32) {
+        float_significant(self.to_f32(), max_digits, kind, digits)
+    }}
+
+    perftools_inline!{
+    fn exact<'a>(self, bytes: &'a mut [u8]) -> usize {
+        float_exact(self.to_f32(), bytes)
+    }}
+
+    perftools_inline!{
+    fn exact_len(self) -> usize {
+        float_exact_len(self.to_f32())
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn radix<'a>(self, options: &WriteOptions, bytes: &'a mut [u8]) -> usize {
+        half_radix(self, options, bytes)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn radix_layout(self, options: &WriteOptions) -> Layout {
+        half_radix_layout(self, options)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn hex<'a>(self, bytes: &'a mut [u8]) -> usize {
+        float_hex(self.to_f32(), bytes)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn hex_len(self) -> usize {
+        float_hex_len(self.to_f32())
+    }}
 }
 
 impl FloatToString for f32 {
@@ -83,10 +234,53 @@ impl FloatToString for f32 {
         float_decimal(self, bytes)
     }}
 
+    perftools_inline!{
+    fn fixed<'a>(self, precision: usize, kind: RoundingKind, bytes: &'a mut [u8]) -> usize {
+        float_fixed(self, precision, kind, bytes)
+    }}
+
+    perftools_inline!{
+    fn fixed_digits(self, precision: usize, kind: RoundingKind) -> usize {
+        float_fixed_digits(self, precision, kind)
+    }}
+
+    perftools_inline!{
+    fn significant<'a>(self, max_digits: usize, kind: RoundingKind, digits: &'a mut [u8]) -> (usize, i32) {
+        float_significant(self, max_digits, kind, digits)
+    }}
+
+    perftools_inline!{
+    fn exact<'a>(self, bytes: &'a mut [u8]) -> usize {
+        float_exact(self, bytes)
+    }}
+
+    perftools_inline!{
+    fn exact_len(self) -> usize {
+        float_exact_len(self)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn radix<'a>(self, options: &WriteOptions, bytes: &'a mut [u8]) -> usize {
+        float_radix(self, options, bytes)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn radix_layout(self, options: &WriteOptions) -> Layout {
+        float_radix_layout(self, options)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn hex<'a>(self, bytes: &'a mut [u8]) -> usize {
+        float_hex(self, bytes)
+    }}
+
     perftools_inline!{
     #[cfg(feature = "radix")]
-    fn radix<'a>(self, radix: u32, bytes: &'a mut [u8]) -> usize {
-        float_radix(self, radix, bytes)
+    fn hex_len(self) -> usize {
+        float_hex_len(self)
     }}
 }
 
@@ -96,135 +290,654 @@ impl FloatToString for f64 {
         double_decimal(self, bytes)
     }}
 
+    perftools_inline!{
+    fn fixed<'a>(self, precision: usize, kind: RoundingKind, bytes: &'a mut [u8]) -> usize {
+        double_fixed(self, precision, kind, bytes)
+    }}
+
+    perftools_inline!{
+    fn fixed_digits(self, precision: usize, kind: RoundingKind) -> usize {
+        double_fixed_digits(self, precision, kind)
+    }}
+
+    perftools_inline!{
+    fn significant<'a>(self, max_digits: usize, kind: RoundingKind, digits: &'a mut [u8]) -> (usize, i32) {
+        double_significant(self, max_digits, kind, digits)
+    }}
+
+    perftools_inline!{
+    fn exact<'a>(self, bytes: &'a mut [u8]) -> usize {
+        double_exact(self, bytes)
+    }}
+
+    perftools_inline!{
+    fn exact_len(self) -> usize {
+        double_exact_len(self)
+    }}
+
     perftools_inline!{
     #[cfg(feature = "radix")]
-    fn radix<'a>(self, radix: u32, bytes: &'a mut [u8]) -> usize {
-        double_radix(self, radix, bytes)
+    fn radix<'a>(self, options: &WriteOptions, bytes: &'a mut [u8]) -> usize {
+        double_radix(self, options, bytes)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn radix_layout(self, options: &WriteOptions) -> Layout {
+        double_radix_layout(self, options)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn hex<'a>(self, bytes: &'a mut [u8]) -> usize {
+        double_hex(self, bytes)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn hex_len(self) -> usize {
+        double_hex_len(self)
     }}
 }
 
 // FTOA
 
+// Replace the exponent character written by the decimal encoders.
+perftools_inline!{
+fn replace_exponent_char<'a>(bytes: &'a mut [u8], exponent_char: u8)
+{
+    if exponent_char != b'e' {
+        if let Some(c) = bytes.iter_mut().find(|c| **c == b'e') {
+            *c = exponent_char;
+        }
+    }
+}}
+
+// Replace the decimal point written by the encoders.
+perftools_inline!{
+fn replace_decimal_point<'a>(bytes: &'a mut [u8], decimal_point: u8)
+{
+    if decimal_point != b'.' {
+        if let Some(c) = bytes.iter_mut().find(|c| **c == b'.') {
+            *c = decimal_point;
+        }
+    }
+}}
+
+// Export float to decimal string and apply the exponent character.
+perftools_inline!{
+fn decimal<'a, F: FloatToString>(value: F, options: &WriteOptions, sign: Sign, bytes: &'a mut [u8])
+    -> usize
+{
+    let (min_digits, max_digits) = significant_digits(options);
+    if let Some(max_digits) = max_digits {
+        // Round the exact value to the maximum significant digits.
+        let notation = notation(options).unwrap_or(Notation::Auto);
+        let kind = internal_rounding(rounding(options), sign);
+        let mut digits: [u8; MAX_SIGNIFICANT_DIGITS + 10] = [b'0'; MAX_SIGNIFICANT_DIGITS + 10];
+        let (count, exponent) = value.significant(max_digits, kind, &mut digits);
+        let digits = &index!(digits[..count]);
+        return write_digits(digits, exponent, min_digits, notation, options, bytes);
+    }
+
+    if notation(options).is_some() || min_digits.is_some() {
+        // Rewrite the backend output in the requested notation.
+        let notation = notation(options).unwrap_or(Notation::Auto);
+        let mut buffer: [u8; 64] = [b'\0'; 64];
+        let len = match value.is_zero() {
+            true  => copy_to_dst(&mut buffer, b"0"),
+            false => value.decimal(&mut buffer),
+        };
+        return write_notation(&index!(buffer[..len]), notation, min_digits, options, bytes);
+    }
+
+    let len = value.decimal(bytes);
+    replace_exponent_char(&mut index_mut!(bytes[..len]), options.exponent_char());
+    len
+}}
+
 // Forward the correct arguments the ideal encoder.
 perftools_inline!{
-fn forward<'a, F: FloatToString>(value: F, radix: u32, bytes: &'a mut [u8])
+fn forward<'a, F: FloatToString>(value: F, options: &WriteOptions, sign: Sign, bytes: &'a mut [u8])
     -> usize
 {
-    debug_assert_radix!(radix);
+    debug_assert_radix!(options.radix);
+
+    if let Some(precision) = fixed_precision(options) {
+        let kind = internal_rounding(rounding(options), sign);
+        return value.fixed(precision, kind, bytes);
+    }
 
     #[cfg(not(feature = "radix"))] {
-        value.decimal(bytes)
+        decimal(value, options, sign, bytes)
+    }
+
+    #[cfg(feature = "radix")] {
+        if options.hex_float {
+            return value.hex(bytes);
+        }
+
+        match options.radix {
+            10 => decimal(value, options, sign, bytes),
+            _  => value.radix(options, bytes),
+        }
     }
+}}
 
+// Determine if the float is written as a C99 hexadecimal float.
+perftools_inline!{
+fn is_hex_float(options: &WriteOptions) -> bool {
     #[cfg(feature = "radix")] {
-        match radix {
-            10 => value.decimal(bytes),
-            _  => value.radix(radix, bytes),
+        options.hex_float
+    }
+
+    #[cfg(not(feature = "radix"))] {
+        let _ = options;
+        false
+    }
+}}
+
+// Get the number of fraction digits for fixed-precision decimal floats.
+//
+// Fixed precision is ignored for hexadecimal floats and non-decimal radixes.
+perftools_inline!{
+fn fixed_precision(options: &WriteOptions) -> Option<usize> {
+    if options.radix == 10 && !is_hex_float(options) {
+        options.fixed_precision
+    } else {
+        None
+    }
+}}
+
+// Get the notation for decimal floats.
+//
+// The notation is ignored for hexadecimal floats and non-decimal radixes.
+perftools_inline!{
+fn notation(options: &WriteOptions) -> Option<Notation> {
+    if options.radix == 10 && !is_hex_float(options) {
+        options.notation
+    } else {
+        None
+    }
+}}
+
+// Get the minimum and maximum significant digits for decimal floats.
+//
+// Significant digits are ignored for hexadecimal floats, non-decimal
+// radixes, and with a fixed precision.
+perftools_inline!{
+fn significant_digits(options: &WriteOptions) -> (Option<usize>, Option<usize>) {
+    if options.radix == 10 && !is_hex_float(options) && options.fixed_precision.is_none() {
+        (options.min_significant_digits, options.max_significant_digits)
+    } else {
+        (None, None)
+    }
+}}
+
+// Get the rounding scheme for fixed precision and significant digits.
+perftools_inline!{
+fn rounding(options: &WriteOptions) -> RoundingKind {
+    #[cfg(feature = "rounding")] {
+        options.rounding
+    }
+
+    #[cfg(not(feature = "rounding"))] {
+        let _ = options;
+        RoundingKind::NearestTieEven
+    }
+}}
+
+// Determine if zero is written as `0.0`, or `0` with trimmed floats.
+//
+// Hexadecimal floats, fixed precision, scientific notation and minimum
+// significant digits all write zero with the same algorithm as any
+// other float.
+perftools_inline!{
+fn is_plain_zero<F: FloatToString>(value: F, options: &WriteOptions) -> bool {
+    value.is_zero()
+        && !is_hex_float(options)
+        && fixed_precision(options).is_none()
+        && notation(options) != Some(Notation::Scientific)
+        && significant_digits(options).0.is_none()
+}}
+
+// Write a NaN or infinity string in the letter case for special values.
+perftools_inline!{
+fn write_special<'a>(bytes: &'a mut [u8], string: &[u8], options: &WriteOptions)
+    -> usize
+{
+    let count = copy_to_dst(bytes, string);
+    if options.special_case != LetterCase::Preserve {
+        for c in index_mut!(bytes[..count]).iter_mut() {
+            *c = options.special_case.convert(*c);
         }
     }
+    count
+}}
+
+// Write a NaN with a signaling prefix and hexadecimal payload, like `sNaN(0x1234)`.
+//
+// The prefix and payload are omitted for the default quiet and
+// signaling NaNs, which are written as `NaN` and `sNaN`.
+perftools_inline!{
+fn write_nan_payload<'a, F: FloatToString>(value: F, options: &WriteOptions, bytes: &'a mut [u8])
+    -> usize
+{
+    let (is_signaling, payload) = nan_to_payload(value);
+    let mut count = 0;
+    if is_signaling {
+        index_mut!(bytes[0] = options.special_case.convert(b's'));
+        count += 1;
+    }
+    count += write_special(&mut index_mut!(bytes[count..]), options.nan_string, options);
+
+    let default = match is_signaling {
+        true  => default_signaling_payload::<F>(),
+        false => 0,
+    };
+    if payload != default {
+        count += copy_to_dst(&mut index_mut!(bytes[count..]), b"(0x");
+        let digits = (67 - payload.leading_zeros() as usize) / 4;
+        for shift in (0..digits).rev() {
+            let digit = (payload >> (4 * shift)) & 0xF;
+            index_mut!(bytes[count] = digit_to_char(digit).to_ascii_lowercase());
+            count += 1;
+        }
+        index_mut!(bytes[count] = b')');
+        count += 1;
+    }
+    count
 }}
 
 // Convert float-to-string and handle special (positive) floats.
 perftools_inline!{
-fn filter_special<'a, F: FloatToString>(value: F, radix: u32, bytes: &'a mut [u8])
+fn filter_special<'a, F: FloatToString>(value: F, options: &WriteOptions, sign: Sign, bytes: &'a mut [u8])
     -> usize
 {
     // Logic errors, disable in release builds.
     debug_assert!(value.is_sign_positive(), "Value cannot be negative.");
-    debug_assert_radix!(radix);
+    debug_assert_radix!(options.radix);
 
-    // We already check for 0 in `filter_sign` if value.is_zero().
-    #[cfg(not(feature = "trim_floats"))] {
-        if value.is_zero() {
-            // This is safe, because we confirmed the buffer is >= 4
-            // in total (since we also handled the sign by here).
-            return copy_to_dst(bytes, b"0.0");
-        }
+    // Zero is trimmed to `0` with the other floats, if required.
+    if is_plain_zero(value, options) {
+        // This is safe, because we confirmed the buffer is >= 4
+        // in total (since we also handled the sign by here).
+        return copy_to_dst(bytes, b"0.0");
     }
 
-    if value.is_nan() {
+    if value.is_nan() && options.nan_payloads {
+        // This is safe, because the NaN string is limited to the
+        // `F::FORMATTED_SIZE` minus the size of the sign and payload.
+        write_nan_payload(value, options, bytes)
+    } else if value.is_nan() {
         // This is safe, because we confirmed the buffer is >= F::FORMATTED_SIZE.
-        // We have up to `F::FORMATTED_SIZE - 1` bytes from `get_nan_string()`,
+        // We have up to `F::FORMATTED_SIZE - 1` bytes from `nan_string`,
         // and up to 1 byte from the sign.
-        copy_to_dst(bytes, get_nan_string())
+        write_special(bytes, options.nan_string, options)
     } else if value.is_special() {
         // This is safe, because we confirmed the buffer is >= F::FORMATTED_SIZE.
-        // We have up to `F::FORMATTED_SIZE - 1` bytes from `get_inf_string()`,
+        // We have up to `F::FORMATTED_SIZE - 1` bytes from `inf_string`,
         // and up to 1 byte from the sign.
-        copy_to_dst(bytes, get_inf_string())
+        write_special(bytes, options.inf_string, options)
     } else {
-        forward(value, radix, bytes)
+        forward(value, options, sign, bytes)
     }
 }}
 
 // Handle +/- values.
 perftools_inline!{
-fn filter_sign<'a, F: FloatToString>(value: F, radix: u32, bytes: &'a mut [u8])
+fn filter_sign<'a, F: FloatToString>(value: F, options: &WriteOptions, bytes: &'a mut [u8])
     -> usize
 {
-    debug_assert_radix!(radix);
+    debug_assert_radix!(options.radix);
 
-    // Export "-0.0" and "0.0" as "0" with trimmed floats.
-    #[cfg(feature = "trim_floats")] {
-        if value.is_zero() {
-            // We know this is safe, because we confirmed the buffer is >= 1.
-            index_mut!(bytes[0] = b'0');
-            return 1;
-        }
-    }
+    // Export "-0.0" as "0.0" without a signed zero.
+    let value = match !options.signed_zero && is_plain_zero(value, options) {
+        true  => value.abs(),
+        false => value,
+    };
 
     // If the sign bit is set, invert it and just set the first
-    // value to "-".
+    // value to "-", or "+" for positive values with a positive sign.
     if value.is_sign_negative() {
         let value = -value;
         // We know this is safe, because we confirmed the buffer is >= 1.
         index_mut!(bytes[0] = b'-');
         let bytes = &mut index_mut!(bytes[1..]);
-        filter_special(value, radix, bytes) + 1
+        filter_special(value, options, Sign::Negative, bytes) + 1
+    } else if options.positive_sign {
+        index_mut!(bytes[0] = b'+');
+        let bytes = &mut index_mut!(bytes[1..]);
+        filter_special(value, options, Sign::Positive, bytes) + 1
     } else {
-        filter_special(value, radix, bytes)
+        filter_special(value, options, Sign::Positive, bytes)
     }
 }}
 
 // Write float to string..
 perftools_inline!{
-fn ftoa<F: FloatToString>(value: F, radix: u32, bytes: &mut [u8])
+fn ftoa<F: FloatToString>(value: F, options: &WriteOptions, bytes: &mut [u8])
     -> usize
 {
-    let len = filter_sign(value, radix, bytes);
-    let bytes = &mut index_mut!(bytes[..len]);
-    trim(bytes)
+    // Fixed-precision, positional, significant-digit and padded-exponent
+    // floats may require more than `F::FORMATTED_SIZE` bytes, and
+    // fixed-precision and minimum significant-digit floats always keep
+    // every digit. Every float is written in place within the larger of
+    // `F::FORMATTED_SIZE` and the formatted length.
+    let (min_digits, max_digits) = significant_digits(options);
+    let is_fixed = fixed_precision(options).is_some() || min_digits.is_some();
+    let is_padded = options.min_exponent_digits != 0;
+    if is_fixed || is_padded || notation(options).is_some() || max_digits.is_some() {
+        assert!(bytes.len() >= options.float_buffer_size::<F>() || bytes.len() >= ftoa_len(value, options));
+    }
+    let mut len = filter_sign(value, options, bytes);
+    if !value.is_special() && !is_hex_float(options) {
+        len = style(options, is_fixed, bytes, len);
+    }
+    if !value.is_special() {
+        replace_decimal_point(&mut index_mut!(bytes[..len]), options.decimal_point);
+        len = group_integer_digits(options, bytes, len);
+    }
+
+    // Pad to the minimum width, with any zeros after the sign and `0x`.
+    let is_padded = !value.is_special();
+    let sign = (index!(bytes[0]) == b'-' || index!(bytes[0]) == b'+') as usize;
+    let prefix = sign + 2 * (is_padded && is_hex_float(options)) as usize;
+    pad_in_place(bytes, len, prefix, is_padded, options)
 }}
 
-// Trim a trailing ".0" from a float.
+// Group the integer digits, after any sign, of a written float.
+//
+// Must be called after replacing the decimal point, since the group
+// separator may be a `.`. Hexadecimal floats are never grouped.
 perftools_inline!{
-fn trim<'a>(bytes: &'a mut [u8])
+fn group_integer_digits(options: &WriteOptions, bytes: &mut [u8], len: usize)
     -> usize
 {
-    // Trim a trailing ".0" from a float.
-    if cfg!(feature = "trim_floats") && ends_with_slice(bytes, b".0") {
-        bytes.len() - 2
-    } else {
-        bytes.len()
+    if options.group_separator == 0 || is_hex_float(options) {
+        return len;
     }
+
+    let start = (index!(bytes[0]) == b'-' || index!(bytes[0]) == b'+') as usize;
+    let exponent_char = options.exponent_char();
+    let digits = index!(bytes[start..len]).iter()
+        .take_while(|&&c| c != options.decimal_point && c != exponent_char)
+        .count();
+    group_digits(&mut index_mut!(bytes[start..]), len - start, digits, options) + start
 }}
 
-// TO LEXICAL
+// Insert bytes at `index` in the first `len` bytes of the buffer.
+perftools_inline!{
+fn insert_bytes(bytes: &mut [u8], len: usize, index: usize, src: &[u8])
+    -> usize
+{
+    let count = src.len();
+    for i in (index..len).rev() {
+        index_mut!(bytes[i + count] = index!(bytes[i]));
+    }
+    copy_to_dst(&mut index_mut!(bytes[index..]), src);
+    len + count
+}}
 
-to_lexical!(ftoa, f32);
-to_lexical!(ftoa, f64);
+// Remove `count` bytes at `index` from the first `len` bytes of the buffer.
+perftools_inline!{
+fn remove_bytes(bytes: &mut [u8], len: usize, index: usize, count: usize)
+    -> usize
+{
+    for i in index..len - count {
+        index_mut!(bytes[i] = index!(bytes[i + count]));
+    }
+    len - count
+}}
 
-// TESTS
-// -----
+// Style the fraction and exponent of a written float.
+//
+// Trims or adds the `.0` fraction of floats without fraction digits,
+// unless every digit is kept, and signs and pads the exponent. Must be
+// called before replacing the decimal point and grouping digits.
+perftools_inline!{
+fn style(options: &WriteOptions, is_fixed: bool, bytes: &mut [u8], len: usize)
+    -> usize
+{
+    let mut len = len;
+    let exponent_char = options.exponent_char();
+    let mut exponent = index!(bytes[..len]).iter()
+        .position(|&c| c == exponent_char)
+        .unwrap_or(len);
 
-#[cfg(test)]
-mod tests {
-    use util::*;
-    use util::test::*;
+    // Trim or add the `.0` fraction before the exponent.
+    if !is_fixed {
+        let mantissa = &index!(bytes[..exponent]);
+        if options.is_trimmed() && ends_with_slice(mantissa, b".0") {
+            len = remove_bytes(bytes, len, exponent - 2, 2);
+            exponent -= 2;
+        } else if options.trailing_zero == TrailingZero::Force && !mantissa.contains(&b'.') {
+            len = insert_bytes(bytes, len, exponent, b".0");
+            exponent += 2;
+        }
+    }
+    if exponent == len {
+        return len;
+    }
 
-    // Test data for roundtrips.
-    const F32_DATA : [f32; 31] = [0., 0.1, 1., 1.1, 12., 12.1, 123., 123.1, 1234., 1234.1, 12345., 12345.1, 123456., 123456.1, 1234567., 1234567.1, 12345678., 12345678.1, 123456789., 123456789.1, 123456789.12, 123456789.123, 123456789.1234, 123456789.12345, 1.2345678912345e8, 1.2345e+8, 1.2345e+11, 1.2345e+38, 1.2345e-8, 1.2345e-11, 1.2345e-38];
-    const F64_DATA: [f64; 33] = [0., 0.1, 1., 1.1, 12., 12.1, 123., 123.1, 1234., 1234.1, 12345., 12345.1, 123456., 123456.1, 1234567., 1234567.1, 12345678., 12345678.1, 123456789., 123456789.1, 123456789.12, 123456789.123, 123456789.1234, 123456789.12345, 1.2345678912345e8, 1.2345e+8, 1.2345e+11, 1.2345e+38, 1.2345e+308, 1.2345e-8, 1.2345e-11, 1.2345e-38, 1.2345e-299];
+    // Sign and pad the exponent digits, removing any `+` sign written
+    // by the backend from unsigned exponents.
+    let mut start = exponent + 1;
+    match (index!(bytes[start]), options.exponent_sign) {
+        (b'-', _) | (b'+', true)    => start += 1,
+        (b'+', false)               => len = remove_bytes(bytes, len, start, 1),
+        (_, true)                   => {
+            len = insert_bytes(bytes, len, start, b"+");
+            start += 1;
+        },
+        (_, false)                  => (),
+    }
+    let digits = len - start;
+    if digits < options.min_exponent_digits {
+        let zeros = options.min_exponent_digits - digits;
+        for _ in 0..zeros {
+            len = insert_bytes(bytes, len, start, b"0");
+        }
+    }
+    len
+}}
+
+// FORMATTED LENGTH
+
+// Get the layout of the float written by `decimal`.
+perftools_inline!{
+fn decimal_layout<F: FloatToString>(value: F, options: &WriteOptions, sign: Sign)
+    -> Layout
+{
+    let (min_digits, max_digits) = significant_digits(options);
+    let notation = notation(options);
+    if let Some(max_digits) = max_digits {
+        let kind = internal_rounding(rounding(options), sign);
+        let mut digits: [u8; MAX_SIGNIFICANT_DIGITS + 10] = [b'0'; MAX_SIGNIFICANT_DIGITS + 10];
+        let (count, exponent) = value.significant(max_digits, kind, &mut digits);
+        return digits_layout(count, exponent, min_digits, notation.unwrap_or(Notation::Auto), options);
+    }
+
+    // The shortest digits are generated by the backend, but the
+    // styled float is never written.
+    let mut buffer: [u8; 64] = [b'\0'; 64];
+    let len = match value.is_zero() {
+        true  => copy_to_dst(&mut buffer, b"0"),
+        false => value.decimal(&mut buffer),
+    };
+    let src = &index!(buffer[..len]);
+    match notation.is_some() || min_digits.is_some() {
+        true  => notation_layout(src, notation.unwrap_or(Notation::Auto), min_digits, options),
+        false => parse_layout(src),
+    }
+}}
+
+// Get the layout of the float written by `forward`, except for hexadecimal floats.
+perftools_inline!{
+fn forward_layout<F: FloatToString>(value: F, options: &WriteOptions, sign: Sign)
+    -> Layout
+{
+    if let Some(precision) = fixed_precision(options) {
+        let kind = internal_rounding(rounding(options), sign);
+        let fraction = match precision {
+            0 => Fraction::None,
+            _ => Fraction::Digits(precision),
+        };
+        return Layout { integer: value.fixed_digits(precision, kind), fraction, exponent: None };
+    }
+
+    #[cfg(not(feature = "radix"))] {
+        decimal_layout(value, options, sign)
+    }
+
+    #[cfg(feature = "radix")] {
+        match options.radix {
+            10 => decimal_layout(value, options, sign),
+            _  => value.radix_layout(options),
+        }
+    }
+}}
+
+// Get the number of bytes of a styled and grouped float from its layout.
+perftools_inline!{
+fn layout_len(layout: Layout, is_fixed: bool, options: &WriteOptions)
+    -> usize
+{
+    let fraction = match layout.fraction {
+        Fraction::None if !is_fixed && options.trailing_zero == TrailingZero::Force => 2,
+        Fraction::Zero if !is_fixed && options.is_trimmed()                         => 0,
+        Fraction::None                                                              => 0,
+        Fraction::Zero                                                              => 2,
+        Fraction::Digits(count)                                                     => count + 1,
+    };
+    let exponent = match layout.exponent {
+        None                        => 0,
+        Some((is_negative, count))  => {
+            let sign = (is_negative || options.exponent_sign) as usize;
+            1 + sign + cmp::max(count, options.min_exponent_digits)
+        },
+    };
+    options.grouped_size(layout.integer) + fraction + exponent
+}}
+
+// Get the number of bytes written by `write_nan_payload`.
+perftools_inline!{
+fn nan_payload_len<F: FloatToString>(value: F, options: &WriteOptions)
+    -> usize
+{
+    let (is_signaling, payload) = nan_to_payload(value);
+    let default = match is_signaling {
+        true  => default_signaling_payload::<F>(),
+        false => 0,
+    };
+    let len = is_signaling as usize + options.nan_string.len();
+    match payload != default {
+        true  => len + 4 + (67 - payload.leading_zeros() as usize) / 4,
+        false => len,
+    }
+}}
+
+// Get the number of bytes written by `filter_special`.
+perftools_inline!{
+fn filter_special_len<F: FloatToString>(value: F, options: &WriteOptions, sign: Sign, is_fixed: bool)
+    -> usize
+{
+    if is_plain_zero(value, options) {
+        let layout = Layout { integer: 1, fraction: Fraction::Zero, exponent: None };
+        return layout_len(layout, false, options);
+    }
+
+    if value.is_nan() && options.nan_payloads {
+        nan_payload_len(value, options)
+    } else if value.is_nan() {
+        options.nan_string.len()
+    } else if value.is_special() {
+        options.inf_string.len()
+    } else {
+        #[cfg(feature = "radix")] {
+            if options.hex_float {
+                return value.hex_len();
+            }
+        }
+
+        layout_len(forward_layout(value, options, sign), is_fixed, options)
+    }
+}}
+
+// Get the number of bytes written by `ftoa`, without writing them.
+//
+// Only the significant digits are generated, to a temporary buffer,
+// while the styling, group separators and padding are calculated.
+perftools_inline!{
+fn ftoa_len<F: FloatToString>(value: F, options: &WriteOptions)
+    -> usize
+{
+    let is_fixed = fixed_precision(options).is_some() || significant_digits(options).0.is_some();
+    let value = match !options.signed_zero && is_plain_zero(value, options) {
+        true  => value.abs(),
+        false => value,
+    };
+    let len = if value.is_sign_negative() {
+        filter_special_len(-value, options, Sign::Negative, is_fixed) + 1
+    } else {
+        let len = filter_special_len(value, options, Sign::Positive, is_fixed);
+        len + options.positive_sign as usize
+    };
+    cmp::max(len, options.width)
+}}
+
+// Write the exact decimal value of a float.
+//
+// Special values and zero are written like with the default options.
+perftools_inline!{
+fn ftoa_exact<F: FloatToString>(value: F, bytes: &mut [u8])
+    -> usize
+{
+    if value.is_special() || value.is_zero() {
+        ftoa(value, &WriteOptions::new(), bytes)
+    } else if value.is_sign_negative() {
+        index_mut!(bytes[0] = b'-');
+        value.abs().exact(&mut index_mut!(bytes[1..])) + 1
+    } else {
+        value.exact(bytes)
+    }
+}}
+
+// Get the number of bytes written by `ftoa_exact`.
+perftools_inline!{
+fn ftoa_exact_len<F: FloatToString>(value: F)
+    -> usize
+{
+    if value.is_special() || value.is_zero() {
+        let mut buffer: [u8; F64_FORMATTED_SIZE] = [b'\0'; F64_FORMATTED_SIZE];
+        ftoa(value, &WriteOptions::new(), &mut buffer)
+    } else {
+        value.is_sign_negative() as usize + value.abs().exact_len()
+    }
+}}
+
+// TO LEXICAL
+
+to_lexical!(ftoa, ftoa_len, float_buffer_size, f16, F16_FORMATTED_SIZE, F16_FORMATTED_SIZE_DECIMAL);
+to_lexical!(ftoa, ftoa_len, float_buffer_size, bf16, BF16_FORMATTED_SIZE, BF16_FORMATTED_SIZE_DECIMAL);
+to_lexical!(ftoa, ftoa_len, float_buffer_size, f32, F32_FORMATTED_SIZE, F32_FORMATTED_SIZE_DECIMAL);
+to_lexical!(ftoa, ftoa_len, float_buffer_size, f64, F64_FORMATTED_SIZE, F64_FORMATTED_SIZE_DECIMAL);
+to_lexical_exact!(ftoa_exact, ftoa_exact_len, f16, F16_FORMATTED_SIZE_EXACT);
+to_lexical_exact!(ftoa_exact, ftoa_exact_len, bf16, BF16_FORMATTED_SIZE_EXACT);
+to_lexical_exact!(ftoa_exact, ftoa_exact_len, f32, F32_FORMATTED_SIZE_EXACT);
+to_lexical_exact!(ftoa_exact, ftoa_exact_len, f64, F64_FORMATTED_SIZE_EXACT);
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use util::*;
+    use util::test::*;
+
+    // Test data for roundtrips.
+    const F32_DATA : [f32; 31] = [0., 0.1, 1., 1.1, 12., 12.1, 123., 123.1, 1234., 1234.1, 12345., 12345.1, 123456., 123456.1, 1234567., 1234567.1, 12345678., 12345678.1, 123456789., 123456789.1, 123456789.12, 123456789.123, 123456789.1234, 123456789.12345, 1.2345678912345e8, 1.2345e+8, 1.2345e+11, 1.2345e+38, 1.2345e-8, 1.2345e-11, 1.2345e-38];
+    const F64_DATA: [f64; 33] = [0., 0.1, 1., 1.1, 12., 12.1, 123., 123.1, 1234., 1234.1, 12345., 12345.1, 123456., 123456.1, 1234567., 1234567.1, 12345678., 12345678.1, 123456789., 123456789.1, 123456789.12, 123456789.123, 123456789.1234, 123456789.12345, 1.2345678912345e8, 1.2345e+8, 1.2345e+11, 1.2345e+38, 1.2345e+308, 1.2345e-8, 1.2345e-11, 1.2345e-38, 1.2345e-299];
 
     #[cfg(feature = "radix")]
     #[test]
@@ -315,9 +1028,12 @@ mod tests {
         let mut buffer = new_buffer();
         for &f in F32_DATA.iter() {
             for radix in 2..37 {
-                // The lower accuracy is due to slight rounding errors of
-                // ftoa for the Grisu method with non-10 bases.
+                // The shortest digits only round-trip exactly with
+                // the correct parser.
                 let s = f.to_lexical_radix(radix, &mut buffer);
+                #[cfg(feature = "correct")]
+                assert_eq!(f32::from_lexical_radix(s, radix).unwrap(), f);
+                #[cfg(not(feature = "correct"))]
                 assert_relative_eq!(f32::from_lexical_radix(s, radix).unwrap(), f, max_relative=2e-5);
             }
         }
@@ -401,20 +1117,809 @@ mod tests {
         }
     }
 
+    #[test]
+    fn f16_decimal_test() {
+        let mut buffer = new_buffer();
+        #[cfg(feature = "trim_floats")] {
+            assert_eq!(as_slice(b"0"), f16::from_bits(0x0000).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"1"), f16::from_bits(0x3C00).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"65500"), f16::MAX.to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"6e-8"), f16::from_bits(0x0001).to_lexical(&mut buffer));
+        }
+
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(as_slice(b"0.0"), f16::from_bits(0x0000).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"-0.0"), f16::from_bits(0x8000).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"1.0"), f16::from_bits(0x3C00).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"65500.0"), f16::MAX.to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"6.0e-8"), f16::from_bits(0x0001).to_lexical(&mut buffer));
+        }
+
+        assert_eq!(as_slice(b"0.1"), f16::from_bits(0x2E66).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"-1.5"), f16::from_bits(0xBE00).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"246.8"), f16::from_bits(0x5BB6).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"0.0000611"), f16::from_bits(0x0401).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"NaN"), f16::NAN.to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"-inf"), f16::NEG_INFINITY.to_lexical(&mut buffer));
+    }
+
+    #[test]
+    fn bf16_decimal_test() {
+        let mut buffer = new_buffer();
+        #[cfg(feature = "trim_floats")] {
+            assert_eq!(as_slice(b"1"), bf16::from_bits(0x3F80).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"256"), bf16::from_bits(0x4380).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"-1e-40"), bf16::from_bits(0x8001).to_lexical(&mut buffer));
+        }
+
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(as_slice(b"1.0"), bf16::from_bits(0x3F80).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"256.0"), bf16::from_bits(0x4380).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"-1.0e-40"), bf16::from_bits(0x8001).to_lexical(&mut buffer));
+        }
+
+        assert_eq!(as_slice(b"3.14"), bf16::from_bits(0x4049).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"0.1"), bf16::from_bits(0x3DCD).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"3.39e38"), bf16::MAX.to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"NaN"), bf16::NAN.to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"inf"), bf16::INFINITY.to_lexical(&mut buffer));
+    }
+
+    #[test]
+    fn half_options_test() {
+        let mut buffer = vec![b'\0'; bf16::FORMATTED_SIZE_EXACT];
+        assert_eq!(b"0.0999755859375", f16::from_bits(0x2E66).to_lexical_exact(&mut buffer));
+        assert_eq!(b"65504", &f16::MAX.to_lexical_exact(&mut buffer)[..5]);
+        assert_eq!(b"0.000000059604644775390625", f16::from_bits(0x0001).to_lexical_exact(&mut buffer));
+        assert_eq!(b"3.140625", bf16::from_bits(0x4049).to_lexical_exact(&mut buffer));
+        let bytes = bf16::from_bits(0x8001).to_lexical_exact(&mut buffer);
+        assert_eq!(bytes.len(), bf16::FORMATTED_SIZE_EXACT);
+        let bytes = f16::from_bits(0x8001).to_lexical_exact(&mut buffer);
+        assert_eq!(bytes.len(), f16::FORMATTED_SIZE_EXACT);
+
+        let mut options = WriteOptions::new();
+        options.set_fixed_precision(Some(3));
+        assert_eq!(b"0.100", f16::from_bits(0x2E66).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"3.141", bf16::from_bits(0x4049).to_lexical_with_options(&options, &mut buffer));
+
+        let mut options = WriteOptions::new();
+        options.set_max_significant_digits(Some(2));
+        assert_eq!(b"3.1", bf16::from_bits(0x4049).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0.000061", f16::from_bits(0x0401).to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn half_radix_test() {
+        let mut buffer = [b'\0'; <f16 as ToLexical>::FORMATTED_SIZE];
+        #[cfg(feature = "trim_floats")] {
+            assert_eq!(b"YA", f16::from_bits(0x64D2).to_lexical_radix(36, &mut buffer));
+            assert_eq!(b"1111111111100000", f16::MAX.to_lexical_radix(2, &mut buffer));
+            assert_eq!(b"-1e-11000", f16::from_bits(0x8001).to_lexical_radix(2, &mut buffer));
+            assert_eq!(b"1e-10000101", bf16::from_bits(0x0001).to_lexical_radix(2, &mut buffer));
+        }
+
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(b"YA.0", f16::from_bits(0x64D2).to_lexical_radix(36, &mut buffer));
+            assert_eq!(b"1111111111100000.0", f16::MAX.to_lexical_radix(2, &mut buffer));
+            assert_eq!(b"-1.0e-11000", f16::from_bits(0x8001).to_lexical_radix(2, &mut buffer));
+            assert_eq!(b"1.0e-10000101", bf16::from_bits(0x0001).to_lexical_radix(2, &mut buffer));
+        }
+
+        assert_eq!(b"1.1", f16::from_bits(0x3E00).to_lexical_radix(2, &mut buffer));
+
+        let options = WriteOptions::hex_float();
+        assert_eq!(b"0x1p-24", f16::from_bits(0x0001).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0x1.ffcp+15", f16::MAX.to_lexical_with_options(&options, &mut buffer));
+
+        // Every float round-trips, including the longest radix 2 floats.
+        // Negative zero is unsigned with the `trim_floats` feature.
+        for bits in 1..0x7C00u16 {
+            let value = -f16::from_bits(bits);
+            for &radix in [2, 3, 7, 16, 36].iter() {
+                let bytes = value.to_lexical_radix(radix, &mut buffer);
+                assert_eq!(Ok(value.to_bits()), f16::from_lexical_radix(bytes, radix).map(f16::to_bits));
+            }
+        }
+    }
+
     #[cfg(feature = "radix")]
     #[test]
     fn f64_radix_roundtrip_test() {
         let mut buffer = new_buffer();
         for &f in F64_DATA.iter() {
             for radix in 2..37 {
-                // The lower accuracy is due to slight rounding errors of
-                // ftoa for the Grisu method with non-10 bases.
+                // The shortest digits only round-trip exactly with
+                // the correct parser.
                 let s = f.to_lexical_radix(radix, &mut buffer);
+                #[cfg(feature = "correct")]
+                assert_eq!(f64::from_lexical_radix(s, radix).unwrap(), f);
+                #[cfg(not(feature = "correct"))]
                 assert_relative_eq!(f64::from_lexical_radix(s, radix).unwrap(), f, max_relative=3e-5);
             }
         }
     }
 
+    #[cfg(feature = "radix")]
+    #[test]
+    fn hex_float_test() {
+        let mut buffer = new_buffer();
+        let options = WriteOptions::hex_float();
+        assert_eq!(as_slice(b"0x1.8p+3"), 12.0f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(as_slice(b"-0x1.8p+3"), (-12.0f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(as_slice(b"0x1.99999ap-4"), 0.1f32.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(as_slice(b"0x0p+0"), 0.0f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(as_slice(b"-0x0p+0"), (-0.0f32).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(as_slice(b"NaN"), f64::NAN.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(as_slice(b"-inf"), f32::NEG_INFINITY.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn hex_float_roundtrip_test() {
+        let mut buffer = new_buffer();
+        let write_options = WriteOptions::hex_float();
+        let parse_options = ParseOptions::hex_float();
+        for &f in F32_DATA.iter() {
+            let s = f.to_lexical_with_options(&write_options, &mut buffer);
+            assert_eq!(f32::from_lexical_with_options(s, &parse_options), Ok(f));
+        }
+        for &f in F64_DATA.iter() {
+            let s = f.to_lexical_with_options(&write_options, &mut buffer);
+            assert_eq!(f64::from_lexical_with_options(s, &parse_options), Ok(f));
+            assert_eq!(f64::from_lexical_lossy_with_options(s, &parse_options), Ok(f));
+        }
+    }
+
+    #[test]
+    fn nan_payload_test() {
+        let mut buffer = new_buffer();
+        let mut options = WriteOptions::new();
+        let value = f64::from_bits(0x7FF8000000001234);
+        assert_eq!(value.to_lexical_with_options(&options, &mut buffer), b"NaN");
+
+        options.set_nan_payloads(true);
+        assert_eq!(f64::NAN.to_lexical_with_options(&options, &mut buffer), b"NaN");
+        assert_eq!((-f64::NAN).to_lexical_with_options(&options, &mut buffer), b"-NaN");
+        assert_eq!(value.to_lexical_with_options(&options, &mut buffer), b"NaN(0x1234)");
+        assert_eq!((-value).to_lexical_with_options(&options, &mut buffer), b"-NaN(0x1234)");
+        assert_eq!(f64::from_bits(0x7FF4000000000000).to_lexical_with_options(&options, &mut buffer), b"sNaN");
+        assert_eq!(f64::from_bits(0xFFF0000000000001).to_lexical_with_options(&options, &mut buffer), b"-sNaN(0x1)");
+        assert_eq!(f64::from_bits(0x7FFFFFFFFFFFFFFF).to_lexical_with_options(&options, &mut buffer), b"NaN(0x7ffffffffffff)");
+        assert_eq!(f32::from_bits(0x7FC0ABCD).to_lexical_with_options(&options, &mut buffer), b"NaN(0xabcd)");
+        assert_eq!(f32::from_bits(0x7FA00000).to_lexical_with_options(&options, &mut buffer), b"sNaN");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&options, &mut buffer), b"inf");
+
+        options.set_nan_string(b"nan");
+        assert_eq!(f64::from_bits(0x7FF0000000000ABC).to_lexical_with_options(&options, &mut buffer), b"snan(0xabc)");
+    }
+
+    #[test]
+    fn nan_payload_roundtrip_test() {
+        let mut buffer = new_buffer();
+        let mut write_options = WriteOptions::new();
+        write_options.set_nan_payloads(true);
+        let mut parse_options = ParseOptions::new();
+        parse_options.set_nan_payloads(true);
+        let f32_data = [0x7FC00000u32, 0xFFC00000, 0x7FC00001, 0x7FFFFFFF, 0x7F800001, 0xFFA00000, 0x7FBFFFFF];
+        for &bits in f32_data.iter() {
+            let s = f32::from_bits(bits).to_lexical_with_options(&write_options, &mut buffer);
+            assert_eq!(f32::from_lexical_with_options(s, &parse_options).map(f32::to_bits), Ok(bits));
+        }
+        let f64_data = [0x7FF8000000000000u64, 0xFFF8000000000000, 0x7FF8000000001234, 0xFFFFFFFFFFFFFFFF, 0x7FF0000000000001, 0x7FF4000000000000, 0xFFF7FFFFFFFFFFFF];
+        for &bits in f64_data.iter() {
+            let s = f64::from_bits(bits).to_lexical_with_options(&write_options, &mut buffer);
+            assert_eq!(f64::from_lexical_with_options(s, &parse_options).map(f64::to_bits), Ok(bits));
+            assert_eq!(f64::from_lexical_lossy_with_options(s, &parse_options).map(f64::to_bits), Ok(bits));
+        }
+    }
+
+    #[should_panic]
+    #[test]
+    fn nan_payload_long_nan_string_test() {
+        let mut options = WriteOptions::new();
+        options.set_nan_string(b"nan_nan_nan_nan_nan_nan_nan_nan_nan_nan_nan_nan");
+        options.set_nan_payloads(true);
+    }
+
+    #[test]
+    fn special_string_test() {
+        let mut options = WriteOptions::new();
+        options.set_nan_string(b"1.#QNAN");
+        options.set_inf_string(b"1.#INF");
+        let mut buffer = new_buffer();
+        assert_eq!(b"1.#QNAN", f64::NAN.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-1.#INF", f64::NEG_INFINITY.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.5", 1.5f64.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    fn positive_sign_test() {
+        let mut options = WriteOptions::new();
+        options.set_positive_sign(true);
+        options.set_trailing_zero(TrailingZero::Force);
+        let mut buffer = new_buffer();
+        assert_eq!(b"+1.5", 1.5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-1.5", (-1.5f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"+0.0", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"+inf", f64::INFINITY.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"+NaN", f32::NAN.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_group_separator(b',');
+        assert_eq!(b"+1,234.5", 1234.5f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_fixed_precision(Some(2));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"+1,234.50", 1234.5f64.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    fn exponent_style_test() {
+        let mut options = WriteOptions::new();
+        options.set_notation(Some(Notation::Scientific));
+        options.set_trailing_zero(TrailingZero::Force);
+        options.set_exponent_sign(true);
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"1.5e+5", 1.5e5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.5e-5", 1.5e-5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.0e+0", 1.0f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_min_exponent_digits(2);
+        options.set_exponent_case(LetterCase::Upper);
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"1.5E+05", 1.5e5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-1.5E-05", (-1.5e-5f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.5E+300", 1.5e300f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_exponent_sign(false);
+        options.set_min_exponent_digits(5);
+        options.set_max_significant_digits(Some(3));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"1.23E00002", 123.456f64.to_lexical_with_options(&options, &mut buffer));
+
+        // Positional floats have no exponent to style.
+        options.set_notation(Some(Notation::Positional));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"123.0", 123.456f64.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    #[should_panic]
+    fn exponent_digits_buffer_test() {
+        let mut options = WriteOptions::new();
+        options.set_min_exponent_digits(1000);
+        let mut buffer = new_buffer();
+        1e100f64.to_lexical_with_options(&options, &mut buffer);
+    }
+
+    #[test]
+    fn special_case_test() {
+        let mut options = WriteOptions::new();
+        options.set_special_case(LetterCase::Upper);
+        let mut buffer = new_buffer();
+        assert_eq!(b"NAN", f64::NAN.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-INF", f64::NEG_INFINITY.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.5", 1.5f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_special_case(LetterCase::Lower);
+        options.set_inf_string(b"Infinity");
+        assert_eq!(b"nan", f64::NAN.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"infinity", f64::INFINITY.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_special_case(LetterCase::Upper);
+        options.set_nan_payloads(true);
+        let nan = f64::from_bits(0x7FF4000000001234);
+        assert_eq!(b"SNAN(0x4000000001234)", nan.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    fn default_trailing_zero_test() {
+        // The fraction written by the float writer is kept by default.
+        let mut buffer = new_buffer();
+        assert_eq!(b"1e21", 1e21f64.to_lexical(&mut buffer));
+        assert_eq!(b"1e-7", 1e-7f64.to_lexical(&mut buffer));
+        assert_eq!(b"5e-324", 5e-324f64.to_lexical(&mut buffer));
+        assert_eq!(b"-1e21", (-1e21f64).to_lexical_with_options(&WriteOptions::new(), &mut buffer));
+
+        #[cfg(feature = "trim_floats")] {
+            assert_eq!(b"12", 12.0f64.to_lexical(&mut buffer));
+        }
+
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(b"12.0", 12.0f64.to_lexical(&mut buffer));
+        }
+    }
+
+    #[test]
+    fn trailing_zero_test() {
+        let mut options = WriteOptions::new();
+        options.set_trailing_zero(TrailingZero::Trim);
+        let mut buffer = new_buffer();
+        assert_eq!(b"12", 12.0f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"12.5", 12.5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1e100", 1e100f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_trailing_zero(TrailingZero::Force);
+        assert_eq!(b"12.0", 12.0f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"12.5", 12.5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.0e100", 1e100f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.0e-7", 1e-7f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0.0", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+
+        // Minimum significant digits keep every digit.
+        options.set_trailing_zero(TrailingZero::Trim);
+        options.set_min_significant_digits(Some(2));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"12.0", 12.0f64.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    fn signed_zero_test() {
+        let mut options = WriteOptions::new();
+        let mut buffer = new_buffer();
+        let mut write = |trailing_zero, signed_zero| {
+            options.set_trailing_zero(trailing_zero);
+            options.set_signed_zero(signed_zero);
+            (-0.0f64).to_lexical_with_options(&options, &mut buffer).to_vec()
+        };
+        assert_eq!(write(TrailingZero::Force, true), b"-0.0");
+        assert_eq!(write(TrailingZero::Force, false), b"0.0");
+        assert_eq!(write(TrailingZero::Trim, true), b"-0");
+        assert_eq!(write(TrailingZero::Trim, false), b"0");
+    }
+
+    #[test]
+    fn width_test() {
+        let mut options = WriteOptions::new();
+        options.set_width(8);
+        options.set_trailing_zero(TrailingZero::Force);
+        let mut buffer = new_buffer();
+        assert_eq!(b"    -1.5", (-1.5f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"     inf", f32::INFINITY.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"12345.678", 12345.678f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_fill(b'_');
+        options.set_alignment(Alignment::Left);
+        assert_eq!(b"-1.5____", (-1.5f32).to_lexical_with_options(&options, &mut buffer));
+
+        options.set_alignment(Alignment::Center);
+        assert_eq!(b"__NaN___", f64::NAN.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_zero_pad(true);
+        assert_eq!(b"-00001.5", (-1.5f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"__-inf__", f64::NEG_INFINITY.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_positive_sign(true);
+        options.set_decimal_point(b',');
+        options.set_group_separator(b'.');
+        options.set_width(10);
+        assert_eq!(b"+001.234,5", 1234.5f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_positive_sign(false);
+        options.set_group_separator(0);
+        options.set_decimal_point(b'.');
+        options.set_notation(Some(Notation::Scientific));
+        options.set_exponent_sign(true);
+        options.set_width(8);
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"001.5e+5", 1.5e5f64.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    fn width_fixed_precision_test() {
+        let mut options = WriteOptions::new();
+        options.set_fixed_precision(Some(2));
+        options.set_width(12);
+        options.set_zero_pad(true);
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"-00000042.50", (-42.5f64).to_lexical_with_options(&options, &mut buffer));
+
+        options.set_width(400);
+        options.set_zero_pad(false);
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        let bytes = (-42.5f64).to_lexical_with_options(&options, &mut buffer);
+        assert_eq!(bytes.len(), 400);
+        assert_eq!(&bytes[394..], b"-42.50");
+    }
+
+    #[test]
+    #[should_panic]
+    fn width_buffer_test() {
+        let mut options = WriteOptions::new();
+        options.set_width(1000);
+        let mut buffer = new_buffer();
+        1.0f64.to_lexical_with_options(&options, &mut buffer);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn width_hex_float_test() {
+        let mut options = WriteOptions::new();
+        options.set_hex_float(true);
+        options.set_width(12);
+        options.set_zero_pad(true);
+        let mut buffer = new_buffer();
+        assert_eq!(b"-0x0001.8p+3", (-12.0f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"         inf", f64::INFINITY.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn radix_exponent_style_test() {
+        let mut options = WriteOptions::new();
+        options.set_radix(2);
+        options.set_trailing_zero(TrailingZero::Force);
+        options.set_exponent_sign(true);
+        options.set_exponent_case(LetterCase::Upper);
+        options.set_min_exponent_digits(8);
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"1.0E+00101000", 1099511627776.0f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_radix(16);
+        options.set_trailing_zero(TrailingZero::Trim);
+        assert_eq!(b"1.7E43C8800759C^+000000F9", 1e300f64.to_lexical_with_options(&options, &mut buffer));
+
+        // Hexadecimal floats are never styled.
+        options.set_hex_float(true);
+        assert_eq!(b"0x1.8p+3", 12.0f64.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    fn fixed_precision_test() {
+        let mut options = WriteOptions::new();
+        options.set_fixed_precision(Some(2));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"0.00", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-0.00", (-0.0f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-0.00", (-0.001f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.00", 1.0f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"2.67", 2.675f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0.10", 0.1f32.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"NaN", f64::NAN.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-inf", f64::NEG_INFINITY.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_fixed_precision(Some(0));
+        assert_eq!(b"2", 2.5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"4", 3.5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-1000", (-999.5f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"340282346638528859811704183484516925440", f32::MAX.to_lexical_with_options(&options, &mut buffer));
+
+        // Only the formatted length is required.
+        let mut small = vec![b'\0'; <f64 as ToLexical>::FORMATTED_SIZE];
+        assert_eq!(b"1", 1.0f64.to_lexical_with_options(&options, &mut small));
+        options.set_fixed_precision(None);
+        options.set_min_exponent_digits(3);
+        options.set_notation(Some(Notation::Scientific));
+        assert_eq!(b"1.5e002", 150.0f64.to_lexical_with_options(&options, &mut small));
+        options.set_min_exponent_digits(0);
+        options.set_notation(None);
+
+        options.set_fixed_precision(Some(3));
+        options.set_decimal_point(b',');
+        options.set_group_separator(b'.');
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"-1.234.567,891", (-1234567.8906f64).to_lexical_with_options(&options, &mut buffer));
+
+        options.set_fixed_precision(None);
+        assert_eq!(b"1,5", 1.5f64.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    fn fixed_precision_large_test() {
+        let mut options = WriteOptions::new();
+        options.set_fixed_precision(Some(1100));
+        options.set_group_separator(b',');
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        let len = f64::MIN.to_lexical_with_options(&options, &mut buffer).len();
+        assert_eq!(len, options.float_buffer_size::<f64>());
+        let bytes = 5e-324f64.to_lexical_with_options(&options, &mut buffer);
+        assert_eq!(bytes.len(), 1102);
+        assert_eq!(&bytes[1067..1076], b"447265625");
+        assert!(bytes[1076..].iter().all(|&c| c == b'0'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fixed_precision_buffer_test() {
+        let mut options = WriteOptions::new();
+        options.set_fixed_precision(Some(60));
+        let mut buffer = new_buffer();
+        1e300f64.to_lexical_with_options(&options, &mut buffer);
+    }
+
+    #[test]
+    fn notation_test() {
+        let mut options = WriteOptions::new();
+        options.set_notation(Some(Notation::Scientific));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"1.2345e3", 1234.5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-1.5e-7", (-1.5e-7f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.7976931348623157e308", f64::MAX.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"NaN", f64::NAN.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_notation(Some(Notation::Positional));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"0.00000015", 1.5e-7f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0.0625", 0.0625f32.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1234.5", 1234.5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-inf", f64::NEG_INFINITY.to_lexical_with_options(&options, &mut buffer));
+        let bytes = 5e-324f64.to_lexical_with_options(&options, &mut buffer);
+        assert_eq!(bytes.len(), 326);
+        assert!(bytes.ends_with(b"0005"));
+
+        options.set_notation(Some(Notation::Auto));
+        assert_eq!(b"0.00001", 1e-5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.5e-6", 1.5e-6f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"123456789012.5", 123456789012.5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.5e21", 1.5e21f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_exponent_limits(-4, 16);
+        options.set_exponent_default_char(b'E');
+        assert_eq!(b"1.5E-5", 1.5e-5f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.5E16", 1.5e16f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_notation(Some(Notation::Positional));
+        options.set_group_separator(b',');
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"-12,345,600,000.5", (-12345600000.5f64).to_lexical_with_options(&options, &mut buffer));
+        let len = f64::MIN.to_lexical_with_options(&options, &mut buffer).len();
+        assert!(len <= options.float_buffer_size::<f64>());
+
+        #[cfg(feature = "trim_floats")] {
+            let mut options = WriteOptions::new();
+            options.set_notation(Some(Notation::Scientific));
+            assert_eq!(b"1e21", 1e21f64.to_lexical_with_options(&options, &mut buffer));
+            assert_eq!(b"0e0", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+            options.set_notation(Some(Notation::Positional));
+            assert_eq!(b"1500", 1.5e3f64.to_lexical_with_options(&options, &mut buffer));
+            assert_eq!(b"0", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+        }
+
+        #[cfg(not(feature = "trim_floats"))] {
+            let mut options = WriteOptions::new();
+            options.set_notation(Some(Notation::Scientific));
+            assert_eq!(b"1.0e21", 1e21f64.to_lexical_with_options(&options, &mut buffer));
+            assert_eq!(b"0.0e0", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+            options.set_notation(Some(Notation::Positional));
+            assert_eq!(b"1500.0", 1.5e3f64.to_lexical_with_options(&options, &mut buffer));
+            assert_eq!(b"0.0", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn notation_buffer_test() {
+        let mut options = WriteOptions::new();
+        options.set_notation(Some(Notation::Positional));
+        let mut buffer = new_buffer();
+        1e300f64.to_lexical_with_options(&options, &mut buffer);
+    }
+
+    #[test]
+    fn notation_formatted_len_test() {
+        let mut options = WriteOptions::new();
+        options.set_notation(Some(Notation::Positional));
+        options.set_trailing_zero(TrailingZero::Trim);
+        let mut buffer = vec![b'\0'; 1e300f64.formatted_len(&options)];
+        assert_eq!(buffer.len(), 301);
+        assert_eq!(301, 1e300f64.to_lexical_with_options(&options, &mut buffer).len());
+    }
+
+    #[test]
+    fn significant_digits_test() {
+        let mut options = WriteOptions::new();
+        options.set_max_significant_digits(Some(6));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"3.14159", 3.141592653589793f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-0.333333", (-1.0f64 / 3.0).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0.1", 0.1f32.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.23457e25", 1.2345678e25f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"4.94066e-324", 5e-324f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"NaN", f64::NAN.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_max_significant_digits(Some(2));
+        assert_eq!(b"0.12", 0.125f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0.14", 0.135f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-1.2e-7", (-1.249e-7f64).to_lexical_with_options(&options, &mut buffer));
+
+        options.set_notation(Some(Notation::Scientific));
+        assert_eq!(b"1.2e2", 123.0f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_notation(None);
+        options.set_min_significant_digits(Some(2));
+        assert_eq!(b"5.0", 5.0f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0.0", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-0.0", (-0.0f64).to_lexical_with_options(&options, &mut buffer));
+
+        options.set_max_significant_digits(None);
+        options.set_min_significant_digits(Some(20));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"0.10000000000000000000", 0.1f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1.2500000000000000000e-7", 1.25e-7f64.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_max_significant_digits(Some(800));
+        options.set_notation(Some(Notation::Positional));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        let len = 2.2250738585072009e-308f64.to_lexical_with_options(&options, &mut buffer).len();
+        assert_eq!(len, 309 + 767);
+        let bytes = f64::MAX.to_lexical_with_options(&options, &mut buffer);
+        assert_eq!(&bytes[..20], b"17976931348623157081");
+        let len = (-5e-324f64).to_lexical_with_options(&options, &mut buffer).len();
+        assert!(len <= options.float_buffer_size::<f64>());
+
+        // Significant digits are ignored with a fixed precision.
+        options.set_fixed_precision(Some(1));
+        assert_eq!(b"0.1", 0.1f64.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[cfg(feature = "rounding")]
+    #[test]
+    fn significant_digits_rounding_test() {
+        let mut options = WriteOptions::new();
+        options.set_max_significant_digits(Some(3));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        options.set_rounding(RoundingKind::NearestTieAwayZero);
+        assert_eq!(b"0.125", 0.125f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-1.25e-6", (-1.25e-6f64).to_lexical_with_options(&options, &mut buffer));
+        options.set_rounding(RoundingKind::TowardPositiveInfinity);
+        assert_eq!(b"1.24", 1.231f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-1.23", (-1.239f64).to_lexical_with_options(&options, &mut buffer));
+        options.set_rounding(RoundingKind::TowardNegativeInfinity);
+        assert_eq!(b"1.23", 1.239f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-1.24", (-1.231f64).to_lexical_with_options(&options, &mut buffer));
+        options.set_rounding(RoundingKind::TowardZero);
+        assert_eq!(b"9.99", 9.999f64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-9.99", (-9.999f64).to_lexical_with_options(&options, &mut buffer));
+
+        options.set_max_significant_digits(None);
+        options.set_fixed_precision(Some(1));
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+        assert_eq!(b"-0.1", (-0.19f64).to_lexical_with_options(&options, &mut buffer));
+        options.set_rounding(RoundingKind::TowardNegativeInfinity);
+        assert_eq!(b"-0.2", (-0.11f64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"0.0", 0.0f64.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    fn exact_test() {
+        let mut buffer = vec![b'\0'; f64::FORMATTED_SIZE_EXACT];
+        assert_eq!(b"0.1000000000000000055511151231257827021181583404541015625", 0.1f64.to_lexical_exact(&mut buffer));
+        assert_eq!(b"-0.100000001490116119384765625", (-0.1f32).to_lexical_exact(&mut buffer));
+        assert_eq!(b"-2.5", (-2.5f64).to_lexical_exact(&mut buffer));
+        assert_eq!(b"NaN", f64::NAN.to_lexical_exact(&mut buffer));
+        assert_eq!(b"-inf", f32::NEG_INFINITY.to_lexical_exact(&mut buffer));
+        assert_eq!(30, (-0.1f32).exact_len());
+        assert_eq!(3, f64::NAN.exact_len());
+        assert_eq!(4, f32::NEG_INFINITY.exact_len());
+
+        let bytes = (-f64::MIN_POSITIVE).to_lexical_exact(&mut buffer);
+        assert_eq!(bytes.len(), (-f64::MIN_POSITIVE).exact_len());
+        assert_eq!(&bytes[..12], b"-0.000000000");
+        let bytes = (-5e-324f64).to_lexical_exact(&mut buffer);
+        assert_eq!(bytes.len(), f64::FORMATTED_SIZE_EXACT);
+        let bytes = (-1e-45f32).to_lexical_exact(&mut buffer);
+        assert!(bytes.len() <= f32::FORMATTED_SIZE_EXACT);
+
+        #[cfg(feature = "trim_floats")] {
+            assert_eq!(b"0", 0.0f64.to_lexical_exact(&mut buffer));
+            assert_eq!(b"-100", (-100.0f32).to_lexical_exact(&mut buffer));
+            assert_eq!(1, 0.0f64.exact_len());
+        }
+
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(b"0.0", 0.0f64.to_lexical_exact(&mut buffer));
+            assert_eq!(b"-0.0", (-0.0f64).to_lexical_exact(&mut buffer));
+            assert_eq!(b"-100.0", (-100.0f32).to_lexical_exact(&mut buffer));
+            assert_eq!(4, (-0.0f64).exact_len());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn exact_buffer_test() {
+        let mut buffer = vec![b'\0'; 0.1f64.exact_len() - 1];
+        0.1f64.to_lexical_exact(&mut buffer);
+    }
+
+    // Options for every float layout, to check the formatted length.
+    fn formatted_len_options() -> Vec<WriteOptions> {
+        let mut data = Vec::new();
+        let mut options = WriteOptions::new();
+        for &trailing_zero in [TrailingZero::Default, TrailingZero::Trim, TrailingZero::Force].iter() {
+            options.set_trailing_zero(trailing_zero);
+            data.push(options);
+        }
+
+        options.set_signed_zero(false);
+        options.set_positive_sign(true);
+        options.set_exponent_sign(true);
+        options.set_min_exponent_digits(3);
+        options.set_group_separator(b',');
+        options.set_group_sizes(3, 2);
+        data.push(options);
+        options.set_width(40);
+        options.set_zero_pad(true);
+        data.push(options);
+        options.set_width(0);
+        options.set_trailing_zero(TrailingZero::Force);
+        data.push(options);
+
+        for &notation in [Notation::Scientific, Notation::Positional, Notation::Auto].iter() {
+            options.set_notation(Some(notation));
+            data.push(options);
+            options.set_min_significant_digits(Some(5));
+            data.push(options);
+            options.set_min_significant_digits(None);
+            options.set_max_significant_digits(Some(3));
+            data.push(options);
+            options.set_max_significant_digits(None);
+        }
+        options.set_notation(None);
+        options.set_min_significant_digits(Some(20));
+        data.push(options);
+        options.set_min_significant_digits(None);
+        for &precision in [0, 1, 2, 20].iter() {
+            options.set_fixed_precision(Some(precision));
+            data.push(options);
+        }
+        options.set_fixed_precision(None);
+
+        #[cfg(feature = "rounding")] {
+            options.set_rounding(RoundingKind::Upward);
+            options.set_fixed_precision(Some(1));
+            data.push(options);
+            options.set_fixed_precision(None);
+            options.set_max_significant_digits(Some(2));
+            data.push(options);
+            options.set_max_significant_digits(None);
+            options.set_rounding(RoundingKind::NearestTieEven);
+        }
+
+        options.set_nan_payloads(true);
+        data.push(options);
+
+        #[cfg(feature = "radix")] {
+            options.set_hex_float(true);
+            data.push(options);
+            options.set_hex_float(false);
+            for &radix in [2, 7, 16, 36].iter() {
+                options.set_radix(radix);
+                data.push(options);
+            }
+        }
+        data
+    }
+
+    fn check_formatted_len<F: ToLexical + Float>(value: F, options: &WriteOptions) {
+        let mut buffer = vec![b'\0'; options.float_buffer_size::<F>()];
+        let len = value.to_lexical_with_options(options, &mut buffer).len();
+        assert_eq!(value.formatted_len(options), len);
+    }
+
+    #[test]
+    fn formatted_len_test() {
+        let options = WriteOptions::new();
+        assert_eq!(1.5f64.formatted_len(&options), 3);
+        assert_eq!((-1.5f32).formatted_len(&options), 4);
+        assert_eq!(f64::NAN.formatted_len(&options), 3);
+
+        let f64_data = [0.0, 0.5, 0.99, 9.5, 9.9951, 999.96, 1e7, 1e15, 1e16, 1e21, 1e22, 1e100, 1e-5, 1e-6, 1e-7, 5e-324, f64::MAX, f64::MIN_POSITIVE, f64::NAN, f64::INFINITY, f64::from_bits(0x7FF0000000000ABC)];
+        for options in formatted_len_options().iter() {
+            for &value in f64_data.iter().chain(F64_DATA.iter()) {
+                check_formatted_len(value, options);
+                check_formatted_len(-value, options);
+            }
+            for &value in F32_DATA.iter() {
+                check_formatted_len(value, options);
+                check_formatted_len(-value, options);
+            }
+            check_formatted_len(f32::MAX, options);
+            check_formatted_len(1e-45f32, options);
+            check_formatted_len(f32::from_bits(0x7FC0ABCD), options);
+        }
+    }
+
     #[cfg(feature = "correct")]
     quickcheck! {
         fn f32_quickcheck(f: f32) -> bool {
@@ -428,6 +1933,21 @@ mod tests {
         }
     }
 
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    quickcheck! {
+        fn f32_radix_quickcheck(f: f32, radix: u8) -> bool {
+            let mut buffer = new_buffer();
+            let radix = radix % 35 + 2;
+            f == f32::from_lexical_radix(f.to_lexical_radix(radix, &mut buffer), radix).unwrap()
+        }
+
+        fn f64_radix_quickcheck(f: f64, radix: u8) -> bool {
+            let mut buffer = new_buffer();
+            let radix = radix % 35 + 2;
+            f == f64::from_lexical_radix(f.to_lexical_radix(radix, &mut buffer), radix).unwrap()
+        }
+    }
+
     #[cfg(all(feature = "correct", feature = "std"))]
     proptest! {
         #[test]
@@ -443,17 +1963,72 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "std")]
+    proptest! {
+        #[test]
+        fn f32_formatted_len_proptest(bits in 0u32..0xFFFFFFFF) {
+            let value = f32::from_bits(bits);
+            for options in formatted_len_options().iter() {
+                let mut buffer = vec![b'\0'; options.float_buffer_size::<f32>()];
+                let len = value.to_lexical_with_options(options, &mut buffer).len();
+                prop_assert_eq!(value.formatted_len(options), len);
+            }
+        }
+
+        #[test]
+        fn f64_formatted_len_proptest(bits in 0u64..0xFFFFFFFFFFFFFFFF) {
+            let value = f64::from_bits(bits);
+            for options in formatted_len_options().iter() {
+                let mut buffer = vec![b'\0'; options.float_buffer_size::<f64>()];
+                let len = value.to_lexical_with_options(options, &mut buffer).len();
+                prop_assert_eq!(value.formatted_len(options), len);
+            }
+        }
+    }
+
+    #[cfg(all(feature = "correct", feature = "radix", feature = "std"))]
+    proptest! {
+        #[test]
+        fn f32_radix_proptest(i in f32::MIN..f32::MAX, radix in 2u8..37) {
+            let mut buffer = new_buffer();
+            prop_assert_eq!(i, f32::from_lexical_radix(i.to_lexical_radix(radix, &mut buffer), radix).unwrap());
+        }
+
+        #[test]
+        fn f64_radix_proptest(i in f64::MIN..f64::MAX, radix in 2u8..37) {
+            let mut buffer = new_buffer();
+            prop_assert_eq!(i, f64::from_lexical_radix(i.to_lexical_radix(radix, &mut buffer), radix).unwrap());
+        }
+
+        #[test]
+        fn f64_radix_bits_proptest(bits in 1u64..0x7FF0000000000000, radix in 2u8..37) {
+            let mut buffer = new_buffer();
+            let i = f64::from_bits(bits);
+            prop_assert_eq!(i, f64::from_lexical_radix(i.to_lexical_radix(radix, &mut buffer), radix).unwrap());
+        }
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn radix_buffer_test() {
+        // The longest shortest round-trip floats.
+        let mut buffer = [b'\0'; <f32 as ToLexical>::FORMATTED_SIZE];
+        assert_eq!((-1.0000000656873453e-5f32).to_lexical_radix(2, &mut buffer).len(), 43);
+        let mut buffer = [b'\0'; <f64 as ToLexical>::FORMATTED_SIZE];
+        assert_eq!((-1.0000000000000004e-5f64).to_lexical_radix(2, &mut buffer).len(), 72);
+    }
+
     #[test]
     #[should_panic]
     fn f32_buffer_test() {
-        let mut buffer = [b'0'; f32::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <f32 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         1.2345f32.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn f64_buffer_test() {
-        let mut buffer = [b'0'; f64::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <f64 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         1.2345f64.to_lexical(&mut buffer);
     }
 }
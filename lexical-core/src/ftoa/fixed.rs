@@ -0,0 +1,747 @@
+//! Fixed-precision decimal float writer.
+//!
+//! Writes floats in the format of `printf("%.*f")`, with exactly
+//! `precision` digits after the decimal point, the significant digits
+//! of `printf("%.*g")`, or the exact decimal value of the float, which
+//! may have hundreds of digits for denormal floats. The digits are
+//! computed from the exact binary value using big-integer arithmetic,
+//! and rounded with the requested rounding scheme, so with ties to even,
+//! the output matches glibc for any precision.
+
+use atof::{Bigint, Limb, SharedOps, SmallOps};
+use itoa;
+use lib::cmp;
+use util::*;
+
+// CONSTANTS
+// ---------
+
+// Maximum number of integer digits of an f64, 309, rounded up to
+// a multiple of the 9 digits extracted per division.
+const INTEGER_DIGITS: usize = 315;
+
+/// Maximum number of significant digits in the exact value of an f64.
+///
+/// Any further significant digits are zeros.
+pub(crate) const MAX_SIGNIFICANT_DIGITS: usize = 767;
+
+// Powers of 10 that fit in a limb.
+const POW10: [u32; 10] = [1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000];
+
+// BIGINT
+// ------
+
+// Remove and return the bits above the low `bits` bits of a big integer.
+//
+// The removed bits must fit in 32 bits.
+fn split_high(x: &mut Bigint, bits: usize)
+    -> u32
+{
+    let limb_bits = <Limb as Integer>::BITS;
+    let index = bits / limb_bits;
+    let shift = bits % limb_bits;
+    let value = {
+        let data = x.data_mut();
+        if index >= data.len() {
+            return 0;
+        }
+
+        // The high bits span at most 2 limbs.
+        let mut value = (index!(data[index]) >> shift) as u64;
+        if shift != 0 && index + 1 < data.len() {
+            value |= (index!(data[index + 1]) as u64) << (limb_bits - shift);
+        }
+        debug_assert!(value <= u32::max_value() as u64);
+
+        let mask: Limb = (1 << shift) - 1;
+        let low = index!(data[index]) & mask;
+        index_mut!(data[index] = low);
+        data.truncate(index + 1);
+        value as u32
+    };
+    x.normalize();
+    value
+}
+
+// Compare a big integer, which must be less than `2^bits`, to `2^(bits-1)`.
+fn compare_half(x: &Bigint, bits: usize)
+    -> cmp::Ordering
+{
+    let limb_bits = <Limb as Integer>::BITS;
+    let bit = bits - 1;
+    let index = bit / limb_bits;
+    let mask: Limb = 1 << (bit % limb_bits);
+    let data = x.data();
+    if index >= data.len() || index!(data[index]) & mask == 0 {
+        cmp::Ordering::Less
+    } else if data.len() == index + 1 && index!(data[index]) == mask && index!(data[..index]).iter().all(|&x| x == 0) {
+        cmp::Ordering::Equal
+    } else {
+        cmp::Ordering::Greater
+    }
+}
+
+// FTOA FIXED
+// ----------
+
+// Write the digits of a big integer, which must be non-zero.
+fn write_bignum<'a>(mut integer: Bigint, bytes: &'a mut [u8])
+    -> usize
+{
+    let mut digits = [b'0'; INTEGER_DIGITS];
+    let mut index = INTEGER_DIGITS;
+    while !integer.is_zero() {
+        let mut chunk = integer.idiv_small(POW10[9] as Limb);
+        for _ in 0..9 {
+            index -= 1;
+            index_mut!(digits[index] = b'0' + (chunk % 10) as u8);
+            chunk /= 10;
+        }
+    }
+    while index!(digits[index]) == b'0' {
+        index += 1;
+    }
+    copy_to_dst(bytes, &index!(digits[index..]))
+}
+
+// Write `count` digits of a value, padded with leading zeros.
+perftools_inline!{
+fn write_padded<'a>(mut value: u32, count: usize, bytes: &'a mut [u8])
+{
+    for c in index_mut!(bytes[..count]).iter_mut().rev() {
+        *c = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}}
+
+// Round up the written digits, skipping the decimal point.
+//
+// Returns the new length, which is one greater if every digit was a 9.
+fn round_up<'a>(bytes: &'a mut [u8], count: usize)
+    -> usize
+{
+    for c in index_mut!(bytes[..count]).iter_mut().rev() {
+        match *c {
+            b'.' => continue,
+            b'9' => *c = b'0',
+            _    => {
+                *c += 1;
+                return count;
+            },
+        }
+    }
+
+    // Every digit was a 9, shift the digits and write a leading 1.
+    // Avoid `copy_within`, which requires Rustc 1.37.
+    for i in (0..count).rev() {
+        index_mut!(bytes[i + 1] = index!(bytes[i]));
+    }
+    index_mut!(bytes[0] = b'1');
+    count + 1
+}
+
+// Determine if the written digits are rounded up.
+//
+// `half` compares the truncated value to half of the last digit,
+// and `kind` must be a rounding scheme for positive values.
+perftools_inline!{
+fn is_round_up(kind: RoundingKind, half: cmp::Ordering, is_truncated: bool, is_odd: bool)
+    -> bool
+{
+    match kind {
+        RoundingKind::NearestTieEven     => half == cmp::Ordering::Greater || (half == cmp::Ordering::Equal && is_odd),
+        RoundingKind::NearestTieAwayZero => half != cmp::Ordering::Less,
+        RoundingKind::Upward             => is_truncated,
+        RoundingKind::Downward           => false,
+        _                                => unreachable!(),
+    }
+}}
+
+// Write the integer digits of a float, and get the fraction and its bit count.
+//
+// No digits are written if the integer is 0.
+fn split_float<'a>(value: f64, bytes: &'a mut [u8])
+    -> (usize, Bigint, usize)
+{
+    // The value is `mantissa * 2^exponent`.
+    let mantissa = value.mantissa();
+    let exponent = value.exponent();
+
+    if exponent >= 0 {
+        let count = if exponent as u32 <= mantissa.leading_zeros() {
+            itoa::itoa_positive(mantissa << exponent, 10, bytes)
+        } else {
+            let mut integer = Bigint::from_u64(mantissa);
+            integer.ishl(exponent as usize);
+            write_bignum(integer, bytes)
+        };
+        (count, Bigint::from_u64(0), 0)
+    } else {
+        let bits = -exponent as usize;
+        let (integer, fraction) = match bits < 64 {
+            true  => (mantissa >> bits, mantissa & ((1 << bits) - 1)),
+            false => (0, mantissa),
+        };
+        let count = match integer {
+            0 => 0,
+            _ => itoa::itoa_positive(integer, 10, bytes),
+        };
+        (count, Bigint::from_u64(fraction), bits)
+    }
+}
+
+/// Write a float with a fixed number of digits after the decimal point.
+///
+/// `value` must be non-special (NaN or infinite) and non-negative.
+/// The fraction is written with exactly `precision` digits, and no
+/// decimal point is written if `precision` is 0, like `printf("%.0f")`.
+/// `kind` must be a rounding scheme for positive values.
+fn ftoa_fixed<'a>(value: f64, precision: usize, kind: RoundingKind, bytes: &'a mut [u8])
+    -> usize
+{
+    debug_assert!(!value.is_special());
+    debug_assert!(value >= 0.0);
+
+    let (mut count, mut fraction, bits) = split_float(value, bytes);
+    if count == 0 {
+        index_mut!(bytes[0] = b'0');
+        count = 1;
+    }
+
+    // Write the fraction digits, up to 9 at a time.
+    if precision != 0 {
+        index_mut!(bytes[count] = b'.');
+        count += 1;
+    }
+    let mut remaining = precision;
+    while remaining != 0 && !fraction.is_zero() {
+        let digits = cmp::min(remaining, 9);
+        fraction.imul_small(POW10[digits] as Limb);
+        let chunk = split_high(&mut fraction, bits);
+        write_padded(chunk, digits, &mut index_mut!(bytes[count..]));
+        count += digits;
+        remaining -= digits;
+    }
+
+    // Round the last digit, if any digits were truncated. Any digits
+    // not written are zeros, since the fraction is exact after `bits` digits.
+    if !fraction.is_zero() {
+        let half = compare_half(&fraction, bits);
+        let is_odd = (index!(bytes[count - 1]) - b'0') % 2 == 1;
+        if is_round_up(kind, half, true, is_odd) {
+            count = round_up(bytes, count);
+        }
+    }
+    for c in index_mut!(bytes[count..count + remaining]).iter_mut() {
+        *c = b'0';
+    }
+    count + remaining
+}
+
+/// Write the significant digits of a float, rounded to at most `max_digits` digits.
+///
+/// `value` must be non-special (NaN or infinite) and non-negative,
+/// and `kind` must be a rounding scheme for positive values. `digits`
+/// must have room for `MAX_SIGNIFICANT_DIGITS + 10` digits. Returns
+/// the number of digits, without trailing zeros, and the decimal
+/// exponent of the first digit. Zero is written as a single `0` digit.
+fn ftoa_significant<'a>(value: f64, max_digits: usize, kind: RoundingKind, digits: &'a mut [u8])
+    -> (usize, i32)
+{
+    debug_assert!(!value.is_special());
+    debug_assert!(value >= 0.0);
+    debug_assert!(max_digits != 0);
+
+    if value == 0.0 {
+        index_mut!(digits[0] = b'0');
+        return (1, 0);
+    }
+
+    // Write the integer digits, or skip the leading zeros of the fraction.
+    let max_digits = cmp::min(max_digits, MAX_SIGNIFICANT_DIGITS);
+    let (mut count, mut fraction, bits) = split_float(value, digits);
+    let mut exponent = count as i32 - 1;
+    while count == 0 {
+        fraction.imul_small(POW10[9] as Limb);
+        let chunk = split_high(&mut fraction, bits);
+        if chunk != 0 {
+            write_padded(chunk, 9, digits);
+            let zeros = index!(digits[..9]).iter().take_while(|&&c| c == b'0').count();
+            for i in zeros..9 {
+                index_mut!(digits[i - zeros] = index!(digits[i]));
+            }
+            count = 9 - zeros;
+            exponent -= zeros as i32;
+        } else {
+            exponent -= 9;
+        }
+    }
+
+    // Write the fraction digits, up to 9 at a time, until a digit is truncated.
+    while count <= max_digits && !fraction.is_zero() {
+        fraction.imul_small(POW10[9] as Limb);
+        let chunk = split_high(&mut fraction, bits);
+        write_padded(chunk, 9, &mut index_mut!(digits[count..]));
+        count += 9;
+    }
+
+    // Round the last digit, from the truncated digits and the fraction.
+    if count > max_digits {
+        let first = index!(digits[max_digits]);
+        let rest = &index!(digits[max_digits + 1..count]);
+        let is_truncated = rest.iter().any(|&c| c != b'0') || !fraction.is_zero();
+        let half = match first {
+            b'5' if is_truncated => cmp::Ordering::Greater,
+            b'5'                 => cmp::Ordering::Equal,
+            b'6' ... b'9'        => cmp::Ordering::Greater,
+            _                    => cmp::Ordering::Less,
+        };
+        let is_odd = (index!(digits[max_digits - 1]) - b'0') % 2 == 1;
+        count = max_digits;
+        if is_round_up(kind, half, is_truncated || first != b'0', is_odd) && round_up(digits, count) != count {
+            // Every digit was a 9, and was rounded to a power of 10.
+            exponent += 1;
+        }
+    }
+
+    // Remove trailing zeros.
+    while count > 1 && index!(digits[count - 1]) == b'0' {
+        count -= 1;
+    }
+    (count, exponent)
+}
+
+// Get the number of significant fraction bits of a float.
+perftools_inline!{
+fn fraction_bits(value: f64)
+    -> usize
+{
+    let mantissa = value.mantissa();
+    let exponent = value.exponent() + mantissa.trailing_zeros() as i32;
+    match exponent < 0 && mantissa != 0 {
+        true  => -exponent as usize,
+        false => 0,
+    }
+}}
+
+// Get the number of digits of a 64-bit integer.
+perftools_inline!{
+fn digit_count(mut value: u64)
+    -> usize
+{
+    let mut count = 1;
+    while value >= 10 {
+        value /= 10;
+        count += 1;
+    }
+    count
+}}
+
+/// Write the exact decimal value of a float.
+///
+/// `value` must be non-special (NaN or infinite) and non-negative.
+/// Every fraction digit of the binary value is written, since every
+/// fraction bit adds exactly one decimal digit, and integers are
+/// written with a `.0` fraction unless floats are trimmed.
+fn ftoa_exact<'a>(value: f64, bytes: &'a mut [u8])
+    -> usize
+{
+    let bits = fraction_bits(value);
+    let count = ftoa_fixed(value, bits, RoundingKind::NearestTieEven, bytes);
+    match bits {
+        0 if !cfg!(feature = "trim_floats") => count + copy_to_dst(&mut index_mut!(bytes[count..]), b".0"),
+        _ => count,
+    }
+}
+
+// Get the number of integer digits of a float, or 1 if the integer is 0.
+fn integer_digit_count(value: f64)
+    -> usize
+{
+    let mantissa = value.mantissa();
+    match value.exponent() {
+        e if e <= -64 => 1,
+        e if e <= 0 => digit_count(mantissa >> -e),
+        e if e as u32 <= mantissa.leading_zeros() => digit_count(mantissa << e),
+        e => {
+            // The integer has at least `estimate` digits, since `78913 / 2^18`
+            // is slightly less than `log10(2)`, and at most `estimate + 1`.
+            let mut integer = Bigint::from_u64(mantissa);
+            integer.ishl(e as usize);
+            let bit_length = (64 - mantissa.leading_zeros() as usize) + e as usize;
+            let estimate = (((bit_length - 1) * 78913) >> 18) + 1;
+            let mut power = Bigint::from_u64(1);
+            let mut remaining = estimate;
+            while remaining != 0 {
+                let step = cmp::min(remaining, 9);
+                power.imul_small(POW10[step] as Limb);
+                remaining -= step;
+            }
+            match integer.compare(&power) {
+                cmp::Ordering::Less => estimate,
+                _                   => estimate + 1,
+            }
+        },
+    }
+}
+
+/// Get the number of bytes written by `ftoa_exact`, without writing them.
+fn exact_len(value: f64)
+    -> usize
+{
+    debug_assert!(!value.is_special());
+    debug_assert!(value >= 0.0);
+
+    // Write the integer digits, the decimal point, and every fraction digit.
+    let digits = integer_digit_count(value);
+    let bits = fraction_bits(value);
+    if bits != 0 {
+        return digits + 1 + bits;
+    }
+    match cfg!(feature = "trim_floats") {
+        true  => digits,
+        false => digits + 2,
+    }
+}
+
+// Determine if rounding the fraction adds an integer digit.
+//
+// The integer, and every fraction digit written by `ftoa_fixed`,
+// must be a 9, and the digits must be rounded up, like `9.96` to `10.0`.
+fn is_carried(value: f64, precision: usize, kind: RoundingKind)
+    -> bool
+{
+    let mantissa = value.mantissa();
+    let exponent = value.exponent();
+    if exponent >= 0 || exponent <= -64 {
+        // No fraction, or no integer.
+        return false;
+    }
+
+    let bits = -exponent as usize;
+    let integer = mantissa >> bits;
+    if digit_count(integer + 1) == digit_count(integer) {
+        return false;
+    }
+
+    // Check the fraction digits, up to 9 at a time, like `ftoa_fixed`.
+    let mut fraction = Bigint::from_u64(mantissa & ((1 << bits) - 1));
+    let mut remaining = precision;
+    while remaining != 0 && !fraction.is_zero() {
+        let digits = cmp::min(remaining, 9);
+        fraction.imul_small(POW10[digits] as Limb);
+        if split_high(&mut fraction, bits) != POW10[digits] - 1 {
+            return false;
+        }
+        remaining -= digits;
+    }
+
+    // Any digits not written are zeros, and the last digit is odd.
+    remaining == 0 && !fraction.is_zero() && is_round_up(kind, compare_half(&fraction, bits), true, true)
+}
+
+/// Get the number of integer digits written by `ftoa_fixed`, without writing them.
+fn fixed_digits(value: f64, precision: usize, kind: RoundingKind)
+    -> usize
+{
+    debug_assert!(!value.is_special());
+    debug_assert!(value >= 0.0);
+
+    integer_digit_count(value) + is_carried(value, precision, kind) as usize
+}
+
+// F32
+
+// Forward to double_fixed.
+//
+// Every f32 is exactly representable as an f64, so this writes the
+// same digits as `printf("%.*f")`, where floats are promoted to doubles.
+// `f` must be non-special (NaN or infinite) and non-negative.
+perftools_inline!{
+pub(crate) fn float_fixed<'a>(f: f32, precision: usize, kind: RoundingKind, bytes: &'a mut [u8])
+    -> usize
+{
+    double_fixed(f as f64, precision, kind, bytes)
+}}
+
+// Forward to double_significant.
+//
+// `f` must be non-special (NaN or infinite) and non-negative.
+perftools_inline!{
+pub(crate) fn float_significant<'a>(f: f32, max_digits: usize, kind: RoundingKind, digits: &'a mut [u8])
+    -> (usize, i32)
+{
+    double_significant(f as f64, max_digits, kind, digits)
+}}
+
+// Forward to double_exact.
+//
+// Every f32 is exactly representable as an f64, with the same digits.
+// `f` must be non-special (NaN or infinite) and non-negative.
+perftools_inline!{
+pub(crate) fn float_exact<'a>(f: f32, bytes: &'a mut [u8])
+    -> usize
+{
+    double_exact(f as f64, bytes)
+}}
+
+// Forward to double_exact_len.
+perftools_inline!{
+pub(crate) fn float_exact_len(f: f32)
+    -> usize
+{
+    double_exact_len(f as f64)
+}}
+
+// Forward to double_fixed_digits.
+perftools_inline!{
+pub(crate) fn float_fixed_digits(f: f32, precision: usize, kind: RoundingKind)
+    -> usize
+{
+    double_fixed_digits(f as f64, precision, kind)
+}}
+
+// F64
+
+// Forward to ftoa_fixed.
+//
+// `d` must be non-special (NaN or infinite) and non-negative.
+perftools_inline!{
+pub(crate) fn double_fixed<'a>(d: f64, precision: usize, kind: RoundingKind, bytes: &'a mut [u8])
+    -> usize
+{
+    ftoa_fixed(d, precision, kind, bytes)
+}}
+
+// Forward to ftoa_exact.
+//
+// `d` must be non-special (NaN or infinite) and non-negative.
+perftools_inline!{
+pub(crate) fn double_exact<'a>(d: f64, bytes: &'a mut [u8])
+    -> usize
+{
+    ftoa_exact(d, bytes)
+}}
+
+// Forward to exact_len.
+perftools_inline!{
+pub(crate) fn double_exact_len(d: f64)
+    -> usize
+{
+    exact_len(d)
+}}
+
+// Forward to fixed_digits.
+//
+// `d` must be non-special (NaN or infinite) and non-negative.
+perftools_inline!{
+pub(crate) fn double_fixed_digits(d: f64, precision: usize, kind: RoundingKind)
+    -> usize
+{
+    fixed_digits(d, precision, kind)
+}}
+
+// Forward to ftoa_significant.
+//
+// `d` must be non-special (NaN or infinite) and non-negative.
+perftools_inline!{
+pub(crate) fn double_significant<'a>(d: f64, max_digits: usize, kind: RoundingKind, digits: &'a mut [u8])
+    -> (usize, i32)
+{
+    ftoa_significant(d, max_digits, kind, digits)
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(value: f64, precision: usize, expected: &str) {
+        let mut buffer = [b'\0'; 1500];
+        let len = double_fixed(value, precision, RoundingKind::NearestTieEven, &mut buffer);
+        assert_eq!(&buffer[..len], expected.as_bytes());
+    }
+
+    fn check_significant(value: f64, max_digits: usize, kind: RoundingKind, expected: &str, exponent: i32) {
+        let mut digits = [b'\0'; MAX_SIGNIFICANT_DIGITS + 10];
+        let (count, exp) = double_significant(value, max_digits, kind, &mut digits);
+        assert_eq!(&digits[..count], expected.as_bytes());
+        assert_eq!(exp, exponent);
+    }
+
+    #[test]
+    fn split_high_test() {
+        let mut x = Bigint::from_u64(0xFFFFFFFFFFFFFFFF);
+        x.ishl(36);
+        assert_eq!(split_high(&mut x, 68), 0xFFFFFFFF);
+        let mut y = Bigint::from_u64(0xFFFFFFFF);
+        y.ishl(36);
+        assert_eq!(x, y);
+        assert_eq!(split_high(&mut x, 68), 0);
+
+        let mut x = Bigint::from_u64(5);
+        x.ishl(64);
+        x.iadd_small(3);
+        assert_eq!(split_high(&mut x, 64), 5);
+        assert_eq!(x, Bigint::from_u64(3));
+        assert_eq!(split_high(&mut x, 1), 1);
+        assert_eq!(x, Bigint::from_u64(1));
+    }
+
+    #[test]
+    fn compare_half_test() {
+        let x = Bigint::from_u64(0);
+        assert_eq!(compare_half(&x, 40), cmp::Ordering::Less);
+        let x = Bigint::from_u64(0x8000000000);
+        assert_eq!(compare_half(&x, 40), cmp::Ordering::Equal);
+        assert_eq!(compare_half(&x, 41), cmp::Ordering::Less);
+        let x = Bigint::from_u64(0x8000000001);
+        assert_eq!(compare_half(&x, 40), cmp::Ordering::Greater);
+        let x = Bigint::from_u64(0x8000000000000001);
+        assert_eq!(compare_half(&x, 64), cmp::Ordering::Greater);
+        let mut x = Bigint::from_u64(1);
+        x.ishl(99);
+        assert_eq!(compare_half(&x, 100), cmp::Ordering::Equal);
+        x.iadd_small(1);
+        assert_eq!(compare_half(&x, 100), cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn fixed_test() {
+        check(0.0, 0, "0");
+        check(0.0, 3, "0.000");
+        check(1.0, 2, "1.00");
+        check(0.5, 0, "0");
+        check(1.5, 0, "2");
+        check(2.5, 0, "2");
+        check(0.125, 2, "0.12");
+        check(0.375, 2, "0.38");
+        check(9.995, 2, "9.99");
+        check(9.9951, 2, "10.00");
+        check(999.96, 1, "1000.0");
+        check(0.1, 20, "0.10000000000000000555");
+        check(123.456, 0, "123");
+        check(1e23, 0, "99999999999999991611392");
+        check(1e23, 2, "99999999999999991611392.00");
+        check(1.7976931348623157e308, 0, "179769313486231570814527423731704356798070567525844996598917476803157260780028538760589558632766878171540458953514382464234321326889464182768467546703537516986049910576551282076245490090389328944075868508455133942304583236903222948165808559332123348274797826204144723168738177180919299881250404026184124858368");
+        let mut buffer = [b'\0'; 1500];
+        let len = double_fixed(5e-324, 1074, RoundingKind::NearestTieEven, &mut buffer);
+        assert_eq!(len, 1076);
+        assert_eq!(&buffer[325..340], b"494065645841246");
+        assert_eq!(&buffer[1056..1076], b"19718265533447265625");
+        assert!(buffer[2..325].iter().all(|&c| c == b'0'));
+        check(5e-324, 2, "0.00");
+        check(0.6, 0, "1");
+        check(0.05, 1, "0.1");
+        check(0.25, 1, "0.2");
+        check(0.35, 1, "0.3");
+    }
+    #[test]
+    fn fixed_rounding_test() {
+        let mut buffer = [b'\0'; 64];
+        let mut fixed = |value: f64, precision: usize, kind: RoundingKind| {
+            let len = double_fixed(value, precision, kind, &mut buffer);
+            buffer[..len].to_vec()
+        };
+        assert_eq!(fixed(2.5, 0, RoundingKind::NearestTieAwayZero), b"3");
+        assert_eq!(fixed(0.125, 2, RoundingKind::NearestTieAwayZero), b"0.13");
+        assert_eq!(fixed(1.01, 1, RoundingKind::Upward), b"1.1");
+        assert_eq!(fixed(1.0, 1, RoundingKind::Upward), b"1.0");
+        assert_eq!(fixed(9.99, 1, RoundingKind::Upward), b"10.0");
+        assert_eq!(fixed(1.99, 1, RoundingKind::Downward), b"1.9");
+        assert_eq!(fixed(5e-324, 3, RoundingKind::Upward), b"0.001");
+    }
+
+    #[test]
+    fn fixed_digits_test() {
+        let kinds = [RoundingKind::NearestTieEven, RoundingKind::NearestTieAwayZero, RoundingKind::Upward, RoundingKind::Downward];
+        let values = [0.0, 0.5, 0.96, 1.5, 9.5, 9.95, 9.9951, 99.5, 999.96, 9999.999, 1e23, 5e-324, 1.7976931348623157e308];
+        let mut buffer = [b'\0'; 1500];
+        for &kind in kinds.iter() {
+            for &value in values.iter() {
+                for &precision in [0, 1, 2, 3, 20].iter() {
+                    let len = double_fixed(value, precision, kind, &mut buffer);
+                    let digits = buffer[..len].iter().take_while(|&&c| c != b'.').count();
+                    assert_eq!(double_fixed_digits(value, precision, kind), digits);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn significant_test() {
+        let even = RoundingKind::NearestTieEven;
+        check_significant(0.0, 6, even, "0", 0);
+        check_significant(1.0, 6, even, "1", 0);
+        check_significant(0.1, 6, even, "1", -1);
+        check_significant(0.1, 17, even, "10000000000000001", -1);
+        check_significant(0.00015, 1, even, "1", -4);
+        check_significant(123456.0, 3, even, "123", 5);
+        check_significant(123456.0, 4, even, "1235", 5);
+        check_significant(999.96, 4, even, "1", 3);
+        check_significant(2.5, 1, even, "2", 0);
+        check_significant(3.5, 1, even, "4", 0);
+        check_significant(1e-10, 3, even, "1", -10);
+        check_significant(1e23, 30, even, "99999999999999991611392", 22);
+        check_significant(1.7976931348623157e308, 3, even, "18", 308);
+        check_significant(5e-324, 6, even, "494066", -324);
+        check_significant(2.2250738585072014e-308, 17, even, "22250738585072014", -308);
+
+        let mut digits = [b'\0'; MAX_SIGNIFICANT_DIGITS + 10];
+        let (count, exponent) = double_significant(2.2250738585072009e-308, 1000, even, &mut digits);
+        assert_eq!(count, 767);
+        assert_eq!(exponent, -308);
+        assert_eq!(&digits[760..767], b"2734375");
+
+        // Truncating the shortest digits would round 0.15 up.
+        check_significant(0.15, 1, even, "1", -1);
+        check_significant(0.15, 1, RoundingKind::NearestTieAwayZero, "1", -1);
+        check_significant(0.125, 2, RoundingKind::NearestTieAwayZero, "13", -1);
+        check_significant(0.125, 2, even, "12", -1);
+        check_significant(0.15, 1, RoundingKind::Upward, "2", -1);
+        check_significant(0.25, 1, RoundingKind::Upward, "3", -1);
+        check_significant(0.2, 1, RoundingKind::Upward, "3", -1);
+        check_significant(0.5, 1, RoundingKind::Upward, "5", -1);
+        check_significant(0.99, 1, RoundingKind::Upward, "1", 0);
+        check_significant(0.99, 1, RoundingKind::Downward, "9", -1);
+        check_significant(1.99999, 3, RoundingKind::Downward, "199", 0);
+    }
+    fn check_exact(value: f64, expected: &str) {
+        let mut buffer = [b'\0'; 1100];
+        let len = double_exact(value, &mut buffer);
+        assert_eq!(&buffer[..len], expected.as_bytes());
+        assert_eq!(double_exact_len(value), len);
+    }
+
+    #[test]
+    fn exact_test() {
+        check_exact(0.1, "0.1000000000000000055511151231257827021181583404541015625");
+        check_exact(0.5, "0.5");
+        check_exact(1.25, "1.25");
+        check_exact(3.0e-5, "0.00003000000000000000076002572291233860823922441340982913970947265625");
+        check_exact(9007199254740993.0, if cfg!(feature = "trim_floats") { "9007199254740992" } else { "9007199254740992.0" });
+        check_exact(1e23, if cfg!(feature = "trim_floats") { "99999999999999991611392" } else { "99999999999999991611392.0" });
+        check_exact(0.3f32 as f64, "0.300000011920928955078125");
+
+        let mut buffer = [b'\0'; 1100];
+        let len = double_exact(5e-324, &mut buffer);
+        assert_eq!(len, 1076);
+        assert_eq!(&buffer[1056..1076], b"19718265533447265625");
+        assert_eq!(double_exact_len(5e-324), 1076);
+        let len = double_exact(1.7976931348623157e308, &mut buffer);
+        assert_eq!(double_exact_len(1.7976931348623157e308), len);
+        assert_eq!(&buffer[..20], b"17976931348623157081");
+
+        // The length must match the written digits around powers of 10.
+        let mut value = 1.0f64;
+        for _ in 0..308 {
+            for &x in [value, value * 0.9999999, value * 1.0000001, 1.0 / value].iter() {
+                let len = double_exact(x, &mut buffer);
+                assert_eq!(double_exact_len(x), len);
+            }
+            value *= 10.0;
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! C99 hexadecimal float writer.
+//!
+//! Writes floats in the format of `printf("%a")`, with a `0x` prefix,
+//! lowercase hexadecimal digits, and a binary exponent in decimal.
+//! Since every bit of the mantissa is written, the value round-trips
+//! exactly.
+
+use itoa;
+use util::*;
+
+// FTOA HEX
+// --------
+
+// Number of hexadecimal digits in the fraction of an f64.
+const FRACTION_DIGITS: u32 = 13;
+
+// Split a float into the leading digit, the fraction digits without
+// trailing zeros and their count, and the binary exponent.
+//
+// Denormal values have a `0` leading digit, and the exponent of the
+// smallest normal value.
+perftools_inline!{
+fn split_hex(value: f64)
+    -> (u8, u64, u32, i32)
+{
+    let bits = value.to_bits();
+    let biased_exp = ((bits & f64::EXPONENT_MASK) >> f64::MANTISSA_SIZE) as i32;
+    let mut fraction = bits & f64::MANTISSA_MASK;
+    let (digit, exponent) = if biased_exp != 0 {
+        (b'1', biased_exp + f64::MANTISSA_SIZE - f64::EXPONENT_BIAS)
+    } else if fraction != 0 {
+        (b'0', f64::DENORMAL_EXPONENT + f64::MANTISSA_SIZE)
+    } else {
+        (b'0', 0)
+    };
+
+    let mut digits = 0;
+    if fraction != 0 {
+        digits = FRACTION_DIGITS;
+        while fraction & 0xF == 0 {
+            fraction >>= 4;
+            digits -= 1;
+        }
+    }
+    (digit, fraction, digits, exponent)
+}}
+
+/// Write a C99 hexadecimal float.
+///
+/// `value` must be non-special (NaN or infinite) and non-negative.
+fn ftoa_hex<'a>(value: f64, bytes: &'a mut [u8])
+    -> usize
+{
+    debug_assert!(!value.is_special());
+    debug_assert!(value >= 0.0);
+
+    // Write the prefix and leading digit.
+    let (digit, fraction, digits, exponent) = split_hex(value);
+    copy_to_dst(bytes, b"0x");
+    index_mut!(bytes[2] = digit);
+    let mut count = 3;
+
+    // Write the fraction digits, without any trailing zeros.
+    if digits != 0 {
+        index_mut!(bytes[count] = b'.');
+        count += 1;
+        for shift in (0..digits).rev() {
+            let digit = (fraction >> (4 * shift)) & 0xF;
+            index_mut!(bytes[count] = digit_to_char(digit).to_ascii_lowercase());
+            count += 1;
+        }
+    }
+
+    // Write the exponent, which always has a sign.
+    index_mut!(bytes[count] = b'p');
+    index_mut!(bytes[count+1] = if exponent < 0 { b'-' } else { b'+' });
+    count += 2;
+    let exp = exponent.wrapping_abs() as u32;
+    count + itoa::itoa_positive(exp, 10, &mut index_mut!(bytes[count..]))
+}
+
+/// Get the number of bytes written by `ftoa_hex`, without writing them.
+fn hex_len(value: f64)
+    -> usize
+{
+    debug_assert!(!value.is_special());
+    debug_assert!(value >= 0.0);
+
+    let (_, _, digits, exponent) = split_hex(value);
+    let fraction = match digits {
+        0 => 0,
+        _ => 1 + digits as usize,
+    };
+    let exp = exponent.wrapping_abs() as u32;
+    5 + fraction + itoa::digit_count(exp, 10)
+}
+
+// F32
+
+// Forward to double_hex.
+//
+// Every f32 is exactly representable as an f64, so this writes the
+// same value as `printf("%a")`, where floats are promoted to doubles.
+// `f` must be non-special (NaN or infinite) and non-negative.
+perftools_inline!{
+pub(crate) fn float_hex<'a>(f: f32, bytes: &'a mut [u8])
+    -> usize
+{
+    double_hex(f as f64, bytes)
+}}
+
+// Forward to double_hex_len.
+perftools_inline!{
+pub(crate) fn float_hex_len(f: f32)
+    -> usize
+{
+    double_hex_len(f as f64)
+}}
+
+// F64
+
+// Algorithm for C99 hexadecimal float strings.
+//
+// `d` must be non-special (NaN or infinite) and non-negative.
+perftools_inline!{
+pub(crate) fn double_hex<'a>(d: f64, bytes: &'a mut [u8])
+    -> usize
+{
+    ftoa_hex(d, bytes)
+}}
+
+// Forward to hex_len.
+perftools_inline!{
+pub(crate) fn double_hex_len(d: f64)
+    -> usize
+{
+    hex_len(d)
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(value: f64, expected: &[u8]) {
+        let mut buffer = [b'\0'; 32];
+        let count = double_hex(value, &mut buffer);
+        assert_eq!(&buffer[..count], expected);
+        assert_eq!(double_hex_len(value), count);
+    }
+
+    #[test]
+    fn double_hex_test() {
+        check(0.0, b"0x0p+0");
+        check(1.0, b"0x1p+0");
+        check(12.0, b"0x1.8p+3");
+        check(0.1, b"0x1.999999999999ap-4");
+        check(0.5, b"0x1p-1");
+        check(f64::MAX, b"0x1.fffffffffffffp+1023");
+        check(f64::MIN_POSITIVE, b"0x1p-1022");
+        check(5e-324, b"0x0.0000000000001p-1022");
+        check(f64::MIN_POSITIVE - 5e-324, b"0x0.fffffffffffffp-1022");
+    }
+
+    #[test]
+    fn float_hex_test() {
+        let mut buffer = [b'\0'; 32];
+        let count = float_hex(0.1f32, &mut buffer);
+        assert_eq!(&buffer[..count], b"0x1.99999ap-4");
+        let count = float_hex(1e-45f32, &mut buffer);
+        assert_eq!(&buffer[..count], b"0x1p-149");
+        let count = float_hex(f32::MAX, &mut buffer);
+        assert_eq!(&buffer[..count], b"0x1.fffffep+127");
+    }
+}
@@ -1,6 +1,13 @@
 //! Fast lexical float-to-string conversion routines.
 
 // Hide implementation details.
+mod fixed;
+mod notation;
+mod shortest;
+
+#[cfg(feature = "radix")]
+mod hex;
+
 #[cfg(feature = "radix")]
 mod radix;
 
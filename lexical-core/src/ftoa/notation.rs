@@ -0,0 +1,387 @@
+//! Backend-independent notation for decimal floats.
+//!
+//! The decimal backends each choose when to write an exponent, and
+//! how to write it, so the significant digits and the exponent are
+//! extracted from the backend output, and written in the notation
+//! from the options. The digits of non-decimal floats are also written
+//! here, with the exponent in the radix.
+
+use itoa;
+use lib::cmp;
+use util::*;
+
+// Maximum number of significant digits written by the decimal backends.
+const MAX_DIGITS: usize = 20;
+
+/// Significant digits and decimal exponent of a written float.
+///
+/// The value is `d.ddd * 10^exponent`, without trailing zeros.
+struct Decimal {
+    /// Significant digits, as characters.
+    digits: [u8; MAX_DIGITS],
+    /// Number of significant digits.
+    count: usize,
+    /// Decimal exponent of the first significant digit.
+    exponent: i32,
+}
+
+// Extract the significant digits and exponent from a written decimal float.
+//
+// Accepts the output of any decimal backend, such as `1.5e-7`, `1e+16`,
+// `0.00015` or `123.0`. Zero is returned as a single `0` digit.
+fn parse_decimal(bytes: &[u8])
+    -> Decimal
+{
+    let mut decimal = Decimal { digits: [b'0'; MAX_DIGITS], count: 0, exponent: 0 };
+    let mut integer_digits: i32 = 0;
+    let mut leading_zeros: i32 = 0;
+    let mut is_fraction = false;
+    let mut iter = bytes.iter();
+    while let Some(&c) = iter.next() {
+        match c {
+            b'.' => is_fraction = true,
+            b'0' ... b'9' => {
+                if !is_fraction {
+                    integer_digits += 1;
+                }
+                if c == b'0' && decimal.count == 0 {
+                    leading_zeros += 1;
+                } else if decimal.count < MAX_DIGITS {
+                    decimal.digits[decimal.count] = c;
+                    decimal.count += 1;
+                }
+            },
+            _ => break,
+        }
+    }
+
+    // Parse the exponent after the exponent character, if present.
+    let mut exponent: i32 = 0;
+    let mut is_negative = false;
+    for &c in iter {
+        match c {
+            b'-' => is_negative = true,
+            b'+' => (),
+            _    => exponent = exponent * 10 + (c - b'0') as i32,
+        }
+    }
+    if is_negative {
+        exponent = -exponent;
+    }
+
+    // Remove trailing zeros, keeping a single digit for zero.
+    while decimal.count > 1 && decimal.digits[decimal.count - 1] == b'0' {
+        decimal.count -= 1;
+    }
+    if decimal.count == 0 {
+        decimal.count = 1;
+        return decimal;
+    }
+    decimal.exponent = integer_digits - leading_zeros - 1 + exponent;
+    decimal
+}
+
+/// Fraction of a written float, after the decimal point.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Fraction {
+    /// No decimal point, such as `1e16`.
+    None,
+    /// A single zero digit, such as `1.0`.
+    Zero,
+    /// Fraction digits, such as `1.25`.
+    Digits(usize),
+}
+
+/// Shape of a float written by a backend, before styling and grouping.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Layout {
+    /// Number of integer digits.
+    pub(crate) integer: usize,
+    /// Fraction digits after the decimal point.
+    pub(crate) fraction: Fraction,
+    /// Whether the exponent is negative and its number of digits, if written.
+    pub(crate) exponent: Option<(bool, usize)>,
+}
+
+/// Get the layout of a float written by a decimal backend.
+///
+/// Accepts the same output as `parse_decimal`, with the exponent
+/// character `e`, and counts the exponent digits as written.
+pub(crate) fn parse_layout(bytes: &[u8])
+    -> Layout
+{
+    let integer = bytes.iter().take_while(|&&c| c != b'.' && c != b'e').count();
+    let rest = &index!(bytes[integer..]);
+    let fraction = match rest.first() {
+        Some(&b'.') => index!(rest[1..]).iter().take_while(|&&c| c != b'e').count(),
+        _           => 0,
+    };
+    let fraction_end = match fraction {
+        0 => integer,
+        _ => integer + 1 + fraction,
+    };
+    let exponent = &index!(bytes[fraction_end..]);
+    let exponent = match exponent.len() {
+        0 => None,
+        _ => {
+            let is_negative = index!(exponent[1]) == b'-';
+            let sign = (is_negative || index!(exponent[1]) == b'+') as usize;
+            Some((is_negative, exponent.len() - 1 - sign))
+        },
+    };
+    let fraction = match fraction {
+        0 => Fraction::None,
+        1 if index!(bytes[integer + 1]) == b'0' => Fraction::Zero,
+        n => Fraction::Digits(n),
+    };
+    Layout { integer, fraction, exponent }
+}
+
+// Copy the significant digits in `[start, end)`, followed by trailing zeros.
+perftools_inline!{
+fn copy_digits(digits: &[u8], start: usize, end: usize, bytes: &mut [u8])
+    -> usize
+{
+    for (i, c) in index_mut!(bytes[..end - start]).iter_mut().enumerate() {
+        *c = match digits.get(start + i) {
+            Some(&digit) => digit,
+            None         => b'0',
+        };
+    }
+    end - start
+}}
+
+// Write the digits in scientific notation, such as `1.5e-7`.
+perftools_inline!{
+fn write_scientific(digits: &[u8], count: usize, exponent: i32, options: &WriteOptions, bytes: &mut [u8])
+    -> usize
+{
+    index_mut!(bytes[0] = index!(digits[0]));
+    let mut count = match count {
+        1 if options.is_trimmed() => 1,
+        1 => copy_to_dst(&mut index_mut!(bytes[1..]), b".0") + 1,
+        _ => {
+            index_mut!(bytes[1] = b'.');
+            2 + copy_digits(digits, 1, count, &mut index_mut!(bytes[2..]))
+        },
+    };
+
+    index_mut!(bytes[count] = options.exponent_char());
+    count += 1;
+    if exponent < 0 {
+        index_mut!(bytes[count] = b'-');
+        count += 1;
+    }
+    let exponent = exponent.wrapping_abs() as u32;
+    count + itoa::itoa_positive(exponent, options.radix, &mut index_mut!(bytes[count..]))
+}}
+
+// Write the digits in positional notation, such as `0.00000015`.
+perftools_inline!{
+fn write_positional(digits: &[u8], count: usize, exponent: i32, is_trimmed: bool, bytes: &mut [u8])
+    -> usize
+{
+    if exponent < 0 {
+        // Write leading zeros before the digits.
+        let zeros = (-exponent) as usize - 1;
+        copy_to_dst(bytes, b"0.");
+        write_bytes(&mut index_mut!(bytes[2..zeros + 2]), b'0');
+        zeros + 2 + copy_digits(digits, 0, count, &mut index_mut!(bytes[zeros + 2..]))
+    } else {
+        let integer_digits = exponent as usize + 1;
+        if count <= integer_digits && is_trimmed {
+            // Write trailing zeros after the digits.
+            copy_digits(digits, 0, integer_digits, bytes)
+        } else if count <= integer_digits {
+            // Write trailing zeros after the digits, and an empty fraction.
+            copy_digits(digits, 0, integer_digits, bytes);
+            integer_digits + copy_to_dst(&mut index_mut!(bytes[integer_digits..]), b".0")
+        } else {
+            // Write the decimal point between the digits.
+            copy_digits(digits, 0, integer_digits, bytes);
+            index_mut!(bytes[integer_digits] = b'.');
+            let fraction = &mut index_mut!(bytes[integer_digits + 1..]);
+            integer_digits + 1 + copy_digits(digits, integer_digits, count, fraction)
+        }
+    }
+}}
+
+/// Write significant digits and an exponent in the given notation.
+///
+/// `digits` must not have trailing zeros, except for zero, which is a
+/// single `0` digit. Trailing zeros are added to write at least
+/// `min_digits` significant digits, and the empty fraction of integers
+/// is kept with `min_digits`.
+pub(crate) fn write_digits(digits: &[u8], exponent: i32, min_digits: Option<usize>, notation: Notation, options: &WriteOptions, bytes: &mut [u8])
+    -> usize
+{
+    let count = cmp::max(digits.len(), min_digits.unwrap_or(0));
+    let is_trimmed = min_digits.is_none() && options.is_trimmed();
+    let (min, max) = options.get_exponent_limits();
+    let is_scientific = match notation {
+        Notation::Scientific    => true,
+        Notation::Positional    => false,
+        Notation::Auto          => exponent < min || exponent >= max,
+    };
+    match is_scientific {
+        true  => write_scientific(digits, count, exponent, options, bytes),
+        false => write_positional(digits, count, exponent, is_trimmed, bytes),
+    }
+}
+
+/// Get the layout of the significant digits written by `write_digits`.
+///
+/// `count` is the number of significant digits, and `exponent` the
+/// exponent of the first digit, with the same requirements.
+pub(crate) fn digits_layout(count: usize, exponent: i32, min_digits: Option<usize>, notation: Notation, options: &WriteOptions)
+    -> Layout
+{
+    let is_trimmed = min_digits.is_none() && options.is_trimmed();
+    let count = cmp::max(count, min_digits.unwrap_or(0));
+    let (min, max) = options.get_exponent_limits();
+    let is_scientific = match notation {
+        Notation::Scientific    => true,
+        Notation::Positional    => false,
+        Notation::Auto          => exponent < min || exponent >= max,
+    };
+    if is_scientific {
+        let fraction = match count {
+            1 if options.is_trimmed() => Fraction::None,
+            1 => Fraction::Zero,
+            _ => Fraction::Digits(count - 1),
+        };
+        let digits = itoa::digit_count(exponent.wrapping_abs() as u32, options.radix);
+        Layout { integer: 1, fraction, exponent: Some((exponent < 0, digits)) }
+    } else if exponent < 0 {
+        let zeros = (-exponent) as usize - 1;
+        Layout { integer: 1, fraction: Fraction::Digits(zeros + count), exponent: None }
+    } else {
+        let integer = exponent as usize + 1;
+        let fraction = match count <= integer {
+            true if is_trimmed  => Fraction::None,
+            true                => Fraction::Zero,
+            false               => Fraction::Digits(count - integer),
+        };
+        Layout { integer, fraction, exponent: None }
+    }
+}
+
+/// Get the layout of a float rewritten by `write_notation`.
+pub(crate) fn notation_layout(src: &[u8], notation: Notation, min_digits: Option<usize>, options: &WriteOptions)
+    -> Layout
+{
+    let decimal = parse_decimal(src);
+    digits_layout(decimal.count, decimal.exponent, min_digits, notation, options)
+}
+
+/// Rewrite a float written by a decimal backend in the given notation.
+///
+/// `src` must contain a non-negative, non-special float written by a
+/// decimal backend, or `0`.
+pub(crate) fn write_notation(src: &[u8], notation: Notation, min_digits: Option<usize>, options: &WriteOptions, bytes: &mut [u8])
+    -> usize
+{
+    let decimal = parse_decimal(src);
+    let digits = &decimal.digits[..decimal.count];
+    write_digits(digits, decimal.exponent, min_digits, notation, options, bytes)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(src: &[u8], digits: &[u8], exponent: i32) {
+        let decimal = parse_decimal(src);
+        assert_eq!(&decimal.digits[..decimal.count], digits);
+        assert_eq!(decimal.exponent, exponent);
+    }
+
+    #[test]
+    fn parse_decimal_test() {
+        check(b"0", b"0", 0);
+        check(b"1.0", b"1", 0);
+        check(b"123.0", b"123", 2);
+        check(b"12300000.0", b"123", 7);
+        check(b"1.5e-7", b"15", -7);
+        check(b"1e16", b"1", 16);
+        check(b"1e+16", b"1", 16);
+        check(b"1.0e16", b"1", 16);
+        check(b"0.00015", b"15", -4);
+        check(b"123.456", b"123456", 2);
+        check(b"1.2345e-308", b"12345", -308);
+    }
+
+    #[test]
+    fn parse_layout_test() {
+        let layout = |integer, fraction, exponent| Layout { integer, fraction, exponent };
+        assert_eq!(parse_layout(b"1"), layout(1, Fraction::None, None));
+        assert_eq!(parse_layout(b"123.0"), layout(3, Fraction::Zero, None));
+        assert_eq!(parse_layout(b"0.00015"), layout(1, Fraction::Digits(5), None));
+        assert_eq!(parse_layout(b"1.5e-7"), layout(1, Fraction::Digits(1), Some((true, 1))));
+        assert_eq!(parse_layout(b"1e16"), layout(1, Fraction::None, Some((false, 2))));
+        assert_eq!(parse_layout(b"1.0e+16"), layout(1, Fraction::Zero, Some((false, 2))));
+        assert_eq!(parse_layout(b"1.2345e-308"), layout(1, Fraction::Digits(4), Some((true, 3))));
+    }
+
+    #[test]
+    fn digits_layout_test() {
+        let options = WriteOptions::new();
+        let mut buffer = [b'\0'; 64];
+        let data: [(&[u8], i32, Option<usize>); 8] = [(b"1", 0, Some(3)), (b"1", 2, Some(3)), (b"15", -3, Some(4)), (b"15", 3, None), (b"0", 0, None), (b"12345", 1, Some(2)), (b"1", 21, None), (b"125", -7, None)];
+        for &(digits, exponent, min_digits) in data.iter() {
+            for &notation in [Notation::Scientific, Notation::Positional, Notation::Auto].iter() {
+                let len = write_digits(digits, exponent, min_digits, notation, &options, &mut buffer);
+                let expected = parse_layout(&buffer[..len]);
+                assert_eq!(digits_layout(digits.len(), exponent, min_digits, notation, &options), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn write_notation_test() {
+        let options = WriteOptions::new();
+        let mut buffer = [b'\0'; 64];
+        let mut write = |src: &[u8], notation| {
+            let len = write_notation(src, notation, None, &options, &mut buffer);
+            buffer[..len].to_vec()
+        };
+        assert_eq!(write(b"1.5e-7", Notation::Positional), b"0.00000015");
+        assert_eq!(write(b"15.25", Notation::Positional), b"15.25");
+        assert_eq!(write(b"0.00015", Notation::Scientific), b"1.5e-4");
+        assert_eq!(write(b"123.456", Notation::Scientific), b"1.23456e2");
+        assert_eq!(write(b"0.00001", Notation::Auto), b"0.00001");
+        assert_eq!(write(b"1.25e-6", Notation::Auto), b"1.25e-6");
+
+        #[cfg(feature = "trim_floats")] {
+            assert_eq!(write(b"1.5e+3", Notation::Positional), b"1500");
+            assert_eq!(write(b"1e16", Notation::Auto), b"10000000000000000");
+            assert_eq!(write(b"1e21", Notation::Auto), b"1e21");
+            assert_eq!(write(b"0", Notation::Scientific), b"0e0");
+        }
+
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(write(b"1.5e+3", Notation::Positional), b"1500.0");
+            assert_eq!(write(b"1e16", Notation::Auto), b"10000000000000000.0");
+            assert_eq!(write(b"1e21", Notation::Auto), b"1.0e21");
+            assert_eq!(write(b"0", Notation::Scientific), b"0.0e0");
+        }
+    }
+    #[test]
+    fn write_digits_test() {
+        let options = WriteOptions::new();
+        let mut buffer = [b'\0'; 64];
+        let mut write = |digits: &[u8], exponent, min_digits, notation| {
+            let len = write_digits(digits, exponent, min_digits, notation, &options, &mut buffer);
+            buffer[..len].to_vec()
+        };
+        assert_eq!(write(b"1", 0, Some(3), Notation::Positional), b"1.00");
+        assert_eq!(write(b"1", 2, Some(3), Notation::Positional), b"100.0");
+        assert_eq!(write(b"15", -3, Some(4), Notation::Positional), b"0.001500");
+        assert_eq!(write(b"15", 3, Some(4), Notation::Scientific), b"1.500e3");
+        assert_eq!(write(b"0", 0, Some(3), Notation::Auto), b"0.00");
+        assert_eq!(write(b"12345", 1, Some(2), Notation::Auto), b"12.345");
+    }
+}
@@ -0,0 +1,314 @@
+//! Shortest round-trip float writer.
+//!
+//! Writes the shortest digits in the radix that parse back to the same
+//! float, using the free-format algorithm of Steele and White, with the
+//! exact boundaries halfway to the neighboring floats, and big-integer
+//! arithmetic. Since the correct parser rounds halfway values to even,
+//! the boundaries are only included for floats with an even mantissa.
+//!
+//! Every float is written with the shortest digits for its own type,
+//! rather than the digits of the promoted f64. This writes floats with
+//! a custom radix, and decimal 16-bit floats, which the decimal
+//! backends do not support.
+
+use atof::{Bigint, LargeOps, Limb, SharedOps, SmallOps};
+use lib::cmp;
+use util::*;
+use super::notation::{digits_layout, write_digits, Layout};
+
+// Maximum number of significant digits, which is the number of
+// mantissa bits of an f64, for radix 2.
+const MAX_DIGITS: usize = 53;
+
+// SHORTEST
+// --------
+
+// Determine if a scaled value is above the upper boundary.
+//
+// The boundary is included for even mantissas.
+perftools_inline!{
+fn is_above(value: &Bigint, boundary: &Bigint, is_even: bool)
+    -> bool
+{
+    match value.compare(boundary) {
+        cmp::Ordering::Greater  => true,
+        cmp::Ordering::Equal    => is_even,
+        cmp::Ordering::Less     => false,
+    }
+}}
+
+// Generate the shortest digits of `mantissa * 2^exponent` in the radix.
+//
+// Returns the number of digits and the exponent of the first digit,
+// such that the value is `d.ddd * radix^exponent`. The digits never
+// have trailing zeros.
+fn shortest_digits(mantissa: u64, exponent: i32, is_lower_closer: bool, radix: u32, digits: &mut [u8])
+    -> (usize, i32)
+{
+    debug_assert!(mantissa != 0);
+
+    // The value is `r / s`, and the distances to the boundaries halfway
+    // to the neighboring floats are `plus / s` and `minus / s`. Scale
+    // everything by 2, or by 4 if the lower boundary is closer, so the
+    // boundaries are integers.
+    let is_even = mantissa % 2 == 0;
+    let shift = is_lower_closer as usize;
+    let mut r = Bigint::from_u64(mantissa);
+    let mut s = Bigint::from_u64(2);
+    let mut plus = Bigint::from_u64(1);
+    let mut minus = Bigint::from_u64(1);
+    r.ishl(1 + shift);
+    s.ishl(shift);
+    plus.ishl(shift);
+    if exponent >= 0 {
+        r.ishl(exponent as usize);
+        plus.ishl(exponent as usize);
+        minus.ishl(exponent as usize);
+    } else {
+        s.ishl(-exponent as usize);
+    }
+
+    // Estimate `k`, the smallest power of the radix above the upper
+    // boundary, from the bit length of the value, which may be 1 too low.
+    let bits = 64 - mantissa.leading_zeros() as i32;
+    let log2 = 2f64.ln() / (radix as f64).ln();
+    let mut k = ((exponent + bits - 1) as f64 * log2).floor() as i32;
+    match k >= 0 {
+        true  => s.imul_power(radix, k as u32),
+        false => {
+            r.imul_power(radix, -k as u32);
+            plus.imul_power(radix, -k as u32);
+            minus.imul_power(radix, -k as u32);
+        },
+    }
+
+    // Fix the estimate, so `r + plus < s <= (r + plus) * radix`.
+    loop {
+        let mut high = r.clone();
+        high.iadd_large(&plus);
+        if !is_above(&high, &s, is_even) {
+            break;
+        }
+        s.imul_small(radix as Limb);
+        k += 1;
+    }
+
+    // Generate digits until the remainder is within the boundaries.
+    let mut count = 0;
+    loop {
+        r.imul_small(radix as Limb);
+        plus.imul_small(radix as Limb);
+        minus.imul_small(radix as Limb);
+        let mut digit: u32 = 0;
+        while r.compare(&s) != cmp::Ordering::Less {
+            r.isub_large(&s);
+            digit += 1;
+        }
+
+        let is_low = is_above(&minus, &r, is_even);
+        let mut high = r.clone();
+        high.iadd_large(&plus);
+        let is_high = is_above(&high, &s, is_even);
+        let is_round_up = match (is_low, is_high) {
+            (false, false)  => {
+                index_mut!(digits[count] = digit_to_char(digit));
+                count += 1;
+                continue;
+            },
+            (true, false)   => false,
+            (false, true)   => true,
+            (true, true)    => {
+                // Both digits are within the boundaries, use the closest.
+                let mut twice = r;
+                twice.ishl(1);
+                twice.compare(&s) != cmp::Ordering::Less
+            },
+        };
+        index_mut!(digits[count] = digit_to_char(digit + is_round_up as u32));
+        count += 1;
+        break;
+    }
+
+    (count, k - 1)
+}
+
+// FTOA SHORTEST
+// -------------
+
+// Generate the shortest digits of a float in the radix.
+//
+// Returns the number of digits, the exponent of the first digit, and
+// the notation, which is scientific for values `<= 1e-5` or `>= 1e9`.
+//
+// `value` must be non-special (NaN or infinite), non-negative,
+// and non-zero.
+pub(super) fn shortest<F: Float>(value: F, radix: u32, digits: &mut [u8])
+    -> (usize, i32, Notation)
+{
+    debug_assert_radix!(radix);
+
+    // Assert no special cases remain, no zero values,
+    // and no negative numbers.
+    debug_assert!(!value.is_special());
+    debug_assert!(!value.is_zero());
+    debug_assert!(value.is_sign_positive());
+
+    let mantissa = value.mantissa().as_u64();
+    let exponent = value.exponent();
+    let is_lower_closer = mantissa == F::HIDDEN_BIT_MASK.as_u64() && exponent != F::DENORMAL_EXPONENT;
+    let (count, exponent) = shortest_digits(mantissa, exponent, is_lower_closer, radix, digits);
+
+    let float = value.as_f64();
+    let notation = match float <= 1e-5 || float >= 1e9 {
+        true  => Notation::Scientific,
+        false => Notation::Positional,
+    };
+    (count, exponent, notation)
+}
+
+// Write the shortest digits of a float in a custom radix, with the
+// exponent written in the radix.
+pub(super) fn ftoa_shortest<'a, F: Float>(value: F, options: &WriteOptions, bytes: &'a mut [u8])
+    -> usize
+{
+    let mut digits: [u8; MAX_DIGITS] = [b'0'; MAX_DIGITS];
+    let (count, exponent, notation) = shortest(value, options.radix, &mut digits);
+    write_digits(&index!(digits[..count]), exponent, None, notation, options, bytes)
+}
+
+// Get the layout of the float written by `ftoa_shortest`.
+pub(super) fn shortest_layout<F: Float>(value: F, options: &WriteOptions)
+    -> Layout
+{
+    let mut digits: [u8; MAX_DIGITS] = [b'0'; MAX_DIGITS];
+    let (count, exponent, notation) = shortest(value, options.radix, &mut digits);
+    digits_layout(count, exponent, None, notation, options)
+}
+
+// F16 AND BF16
+
+// Algorithm for decimal 16-bit floats.
+//
+// Writes the digits like a decimal backend, with the exponent character
+// `e` and a `.` decimal point, which are replaced when styling the float.
+//
+// `value` must be non-special (NaN or infinite), non-negative,
+// and non-zero.
+perftools_inline!{
+pub(crate) fn half_decimal<'a, F: Float>(value: F, bytes: &'a mut [u8])
+    -> usize
+{
+    let mut options = WriteOptions::new();
+    options.set_exponent_default_char(b'e');
+    ftoa_shortest(value, &options, bytes)
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(value: f64, radix: u32, expected: &str, exponent: i32) {
+        let mut digits = [b'\0'; MAX_DIGITS];
+        let mantissa = value.mantissa();
+        let is_lower_closer = mantissa == f64::HIDDEN_BIT_MASK && value.exponent() != f64::DENORMAL_EXPONENT;
+        let (count, exp) = shortest_digits(mantissa, value.exponent(), is_lower_closer, radix, &mut digits);
+        assert_eq!(&digits[..count], expected.as_bytes());
+        assert_eq!(exp, exponent);
+    }
+
+    fn check_f32(value: f32, radix: u32, expected: &str, exponent: i32) {
+        let mut digits = [b'\0'; MAX_DIGITS];
+        let mantissa = value.mantissa() as u64;
+        let is_lower_closer = value.mantissa() == f32::HIDDEN_BIT_MASK && value.exponent() != f32::DENORMAL_EXPONENT;
+        let (count, exp) = shortest_digits(mantissa, value.exponent(), is_lower_closer, radix, &mut digits);
+        assert_eq!(&digits[..count], expected.as_bytes());
+        assert_eq!(exp, exponent);
+    }
+
+    #[test]
+    fn shortest_digits_decimal_test() {
+        check(0.1, 10, "1", -1);
+        check(12345.6789, 10, "123456789", 4);
+        check(1e300, 10, "1", 300);
+        check(5e-324, 10, "5", -324);
+        check(2.2250738585072014e-308, 10, "22250738585072014", -308);
+        check(1.7976931348623157e308, 10, "17976931348623157", 308);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn shortest_digits_test() {
+        check(1.0, 36, "1", 0);
+        check(0.1, 2, "1100110011001100110011001100110011001100110011001101", -4);
+        check(0.1, 3, "22002200220022002200220022002201", -3);
+        check(0.1, 36, "3LLLLLLLLLM", -1);
+        check(0.3, 5, "122222222222222222222222", -1);
+        check(12345.6789, 36, "9IXOFURAVWU", 2);
+        check(1e300, 16, "17E43C8800759C", 249);
+        check(1e-5, 7, "11143516050460505612", -6);
+
+        // Boundaries.
+        check(5e-324, 2, "1", -1074);
+        check(5e-324, 36, "3", -208);
+        check(2.2250738585072014e-308, 2, "1", -1022);
+        check(1.7976931348623157e308, 36, "1A1E4VNGAIL", 198);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn shortest_digits_f32_test() {
+        check_f32(0.1, 2, "110011001100110011001101", -4);
+        check_f32(0.1, 36, "3LLLLP", -1);
+        check_f32(1.5, 3, "1111111111111112", 0);
+        check_f32(1e-45, 36, "2", -29);
+        check_f32(3.4028235e38, 2, "111111111111111111111111", 127);
+        check_f32(3.4028235e38, 32, "7VVVVG", 25);
+    }
+
+    #[test]
+    fn shortest_test() {
+        let mut digits = [b'\0'; MAX_DIGITS];
+        let mut check = |value: f16, expected: &str, exponent: i32, notation| {
+            let (count, exp, note) = shortest(value, 10, &mut digits);
+            assert_eq!(&digits[..count], expected.as_bytes());
+            assert_eq!(exp, exponent);
+            assert_eq!(note, notation);
+        };
+        check(f16::from_f32(0.1), "1", -1, Notation::Positional);
+        check(f16::from_f32(1.0), "1", 0, Notation::Positional);
+        check(f16::MAX, "655", 4, Notation::Positional);
+        check(f16::from_bits(0x0001), "6", -8, Notation::Scientific);
+        check(f16::from_bits(0x03FF), "61", -5, Notation::Positional);
+        check(f16::from_bits(0x0400), "6104", -5, Notation::Positional);
+        check(f16::from_bits(0x0401), "611", -5, Notation::Positional);
+        check(f16::from_f32(2049.0), "2048", 3, Notation::Positional);
+
+        let (count, exp, _) = shortest(bf16::from_f32(3.140625), 10, &mut digits);
+        assert_eq!(&digits[..count], b"314");
+        assert_eq!(exp, 0);
+        let (count, exp, _) = shortest(bf16::MAX, 10, &mut digits);
+        assert_eq!(&digits[..count], b"339");
+        assert_eq!(exp, 38);
+        let (count, exp, _) = shortest(bf16::from_bits(0x0001), 10, &mut digits);
+        assert_eq!(&digits[..count], b"1");
+        assert_eq!(exp, -40);
+    }
+
+    #[test]
+    fn half_decimal_test() {
+        let mut buffer = [b'\0'; 64];
+        let mut write = |value: f16| {
+            let len = half_decimal(value, &mut buffer);
+            buffer[..len].to_vec()
+        };
+        assert_eq!(write(f16::from_f32(0.1)), b"0.1");
+        assert_eq!(write(f16::from_f32(1.5)), b"1.5");
+        assert_eq!(write(f16::MAX)[..5], b"65500"[..]);
+        assert!(write(f16::from_bits(0x0001)).starts_with(b"6"));
+        assert!(write(f16::from_bits(0x0001)).ends_with(b"e-8"));
+        assert_eq!(write(f16::from_bits(0x0401)), b"0.0000611");
+    }
+}
@@ -3,6 +3,7 @@
 //! Uses either the optimized decimal algorithm, the optimized generic
 //! algorithm, or the naive algorithm.
 
+use lib::cmp;
 use util::*;
 
 /// Select the back-end.
@@ -30,9 +31,9 @@ macro_rules! write_backwards {
         let mut buffer: [u8; BUFFER_SIZE] = [b'0'; BUFFER_SIZE];
         let digits;
         if cfg!(not(feature = "radix")) || $radix == 10 {
-            digits = &mut buffer[..$t::FORMATTED_SIZE_DECIMAL];
+            digits = &mut buffer[..<$t as ToLexical>::FORMATTED_SIZE_DECIMAL];
         } else {
-            digits = &mut buffer[..$t::FORMATTED_SIZE];
+            digits = &mut buffer[..<$t as ToLexical>::FORMATTED_SIZE];
         }
 
         // Write backwards to buffer and copy output to slice.
@@ -43,15 +44,15 @@ macro_rules! write_backwards {
 }
 
 #[cfg(all(feature = "table", not(feature = "radix")))]
-pub(crate) trait Atoi: Decimal + UnsignedInteger
+pub(crate) trait Atoi: Decimal + UnsignedInteger + ToLexical
 {}
 
 #[cfg(all(feature = "table", feature = "radix"))]
-pub(crate) trait Atoi: Decimal + Generic + UnsignedInteger
+pub(crate) trait Atoi: Decimal + Generic + UnsignedInteger + ToLexical
 {}
 
 #[cfg(not(feature = "table"))]
-pub(crate) trait Atoi: Naive + UnsignedInteger
+pub(crate) trait Atoi: Naive + UnsignedInteger + ToLexical
 {}
 
 macro_rules! atoi_impl {
@@ -102,77 +103,182 @@ pub(crate) fn itoa_positive<T>(value: T, radix: u32, buffer: &mut [u8])
     write_backwards!(value, radix, buffer, T, naive)
 }}
 
+// PADDING
+
+// Get the number of digits of an unsigned integer in the radix.
+//
+// Decimal digits are counted without division with the optimized
+// decimal backend.
+perftools_inline!{
+pub(crate) fn digit_count<T>(value: T, radix: u32)
+    -> usize
+    where T: Atoi
+{
+    #[cfg(feature = "table")] {
+        if radix == 10 {
+            return value.decimal_count();
+        }
+    }
+
+    let radix: T = as_cast(radix);
+    let mut value = value;
+    let mut count = 1;
+    while value >= radix {
+        value /= radix;
+        count += 1;
+    }
+    count
+}}
+
+// Write the sign and digits of an integer, padded to the minimum width.
+//
+// The length of the grouped digits is calculated before writing, so the
+// fill characters, sign and zeros are written directly around the digits,
+// in a single pass.
+perftools_inline!{
+fn write_integer<T>(value: T, is_negative: bool, options: &WriteOptions, buffer: &mut [u8])
+    -> usize
+    where T: Atoi
+{
+    let radix = options.radix;
+    let sign = is_negative as usize;
+    if options.width <= sign + 1 {
+        // No number is shorter than the width, write the digits directly.
+        if is_negative {
+            index_mut!(buffer[0] = b'-');
+        }
+        let digits = &mut index_mut!(buffer[sign..]);
+        let len = itoa_positive(value, radix, digits);
+        return group_digits(digits, len, len, options) + sign;
+    }
+
+    let len = sign + options.grouped_size(digit_count(value, radix));
+    let padding = padding(len, true, options);
+    let mut index = padding.before;
+    write_bytes(&mut index_mut!(buffer[..index]), options.fill);
+    if is_negative {
+        index_mut!(buffer[index] = b'-');
+        index += 1;
+    }
+    write_bytes(&mut index_mut!(buffer[index..index + padding.zeros]), b'0');
+    index += padding.zeros;
+
+    let digits = &mut index_mut!(buffer[index..]);
+    let count = itoa_positive(value, radix, digits);
+    index += group_digits(digits, count, count, options);
+    write_bytes(&mut index_mut!(buffer[index..index + padding.after]), options.fill);
+    index + padding.after
+}}
+
+// Get the number of bytes written by `write_integer`, without writing them.
+perftools_inline!{
+fn integer_len<T>(value: T, is_negative: bool, options: &WriteOptions)
+    -> usize
+    where T: Atoi
+{
+    let len = is_negative as usize + options.grouped_size(digit_count(value, options.radix));
+    cmp::max(len, options.width)
+}}
+
 // TO LEXICAL
 
 // Callback for unsigned integer formatter.
 perftools_inline!{
-fn unsigned<Narrow, Wide>(value: Narrow, radix: u32, buffer: &mut [u8])
+fn unsigned<Narrow, Wide>(value: Narrow, options: &WriteOptions, buffer: &mut [u8])
     -> usize
     where Narrow: UnsignedInteger,
           Wide: Atoi
 {
     let value: Wide = as_cast(value);
-    itoa_positive(value, radix, buffer)
+    write_integer(value, false, options, buffer)
+}}
+
+// Callback for the unsigned integer formatted length.
+perftools_inline!{
+fn unsigned_len<Narrow, Wide>(value: Narrow, options: &WriteOptions)
+    -> usize
+    where Narrow: UnsignedInteger,
+          Wide: Atoi
+{
+    let value: Wide = as_cast(value);
+    integer_len(value, false, options)
 }}
 
 macro_rules! unsigned_to_lexical {
-    ($narrow:ty, $wide:ty) => (
-        to_lexical!(unsigned::<$narrow, $wide>, $narrow);
+    ($narrow:ty, $wide:ty, $radix_size:ident, $decimal_size:ident) => (
+        to_lexical!(unsigned::<$narrow, $wide>, unsigned_len::<$narrow, $wide>, integer_buffer_size, $narrow, $radix_size, $decimal_size);
     );
 }
 
-unsigned_to_lexical!(u8, u32);
-unsigned_to_lexical!(u16, u32);
-unsigned_to_lexical!(u32, u32);
-unsigned_to_lexical!(u64, u64);
+unsigned_to_lexical!(u8, u32, U8_FORMATTED_SIZE, U8_FORMATTED_SIZE_DECIMAL);
+unsigned_to_lexical!(u16, u32, U16_FORMATTED_SIZE, U16_FORMATTED_SIZE_DECIMAL);
+unsigned_to_lexical!(u32, u32, U32_FORMATTED_SIZE, U32_FORMATTED_SIZE_DECIMAL);
+unsigned_to_lexical!(u64, u64, U64_FORMATTED_SIZE, U64_FORMATTED_SIZE_DECIMAL);
 
 #[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
-unsigned_to_lexical!(usize, u32);
+unsigned_to_lexical!(usize, u32, USIZE_FORMATTED_SIZE, USIZE_FORMATTED_SIZE_DECIMAL);
 
 #[cfg(target_pointer_width = "64")]
-unsigned_to_lexical!(usize, u64);
+unsigned_to_lexical!(usize, u64, USIZE_FORMATTED_SIZE, USIZE_FORMATTED_SIZE_DECIMAL);
 
 #[cfg(has_i128)]
-unsigned_to_lexical!(u128, u128);
+unsigned_to_lexical!(u128, u128, U128_FORMATTED_SIZE, U128_FORMATTED_SIZE_DECIMAL);
 
 // Callback for signed integer formatter.
 perftools_inline!{
-fn signed<Narrow, Wide, Unsigned>(value: Narrow, radix: u32, buffer: &mut [u8])
+fn signed<Narrow, Wide, Unsigned>(value: Narrow, options: &WriteOptions, buffer: &mut [u8])
+    -> usize
+    where Narrow: SignedInteger,
+          Wide: SignedInteger,
+          Unsigned: Atoi
+{
+    if value < Narrow::ZERO {
+        let value: Wide = as_cast(value);
+        let value: Unsigned = as_cast(value.wrapping_neg());
+        write_integer(value, true, options, buffer)
+    } else {
+        let value: Unsigned = as_cast(value);
+        write_integer(value, false, options, buffer)
+    }
+}}
+
+// Callback for the signed integer formatted length.
+perftools_inline!{
+fn signed_len<Narrow, Wide, Unsigned>(value: Narrow, options: &WriteOptions)
     -> usize
     where Narrow: SignedInteger,
           Wide: SignedInteger,
           Unsigned: Atoi
 {
     if value < Narrow::ZERO {
-        unchecked_index_mut!(buffer[0] = b'-');
         let value: Wide = as_cast(value);
         let value: Unsigned = as_cast(value.wrapping_neg());
-        itoa_positive(value, radix, &mut unchecked_index_mut!(buffer[1..])) + 1
+        integer_len(value, true, options)
     } else {
         let value: Unsigned = as_cast(value);
-        itoa_positive(value, radix, buffer)
+        integer_len(value, false, options)
     }
 }}
 
 macro_rules! signed_to_lexical {
-    ($narrow:ty, $wide:ty, $unsigned:ty) => (
-        to_lexical!(signed::<$narrow, $wide, $unsigned>, $narrow);
+    ($narrow:ty, $wide:ty, $unsigned:ty, $radix_size:ident, $decimal_size:ident) => (
+        to_lexical!(signed::<$narrow, $wide, $unsigned>, signed_len::<$narrow, $wide, $unsigned>, integer_buffer_size, $narrow, $radix_size, $decimal_size);
     );
 }
 
-signed_to_lexical!(i8, i32, u32);
-signed_to_lexical!(i16, i32, u32);
-signed_to_lexical!(i32, i32, u32);
-signed_to_lexical!(i64, i64, u64);
+signed_to_lexical!(i8, i32, u32, I8_FORMATTED_SIZE, I8_FORMATTED_SIZE_DECIMAL);
+signed_to_lexical!(i16, i32, u32, I16_FORMATTED_SIZE, I16_FORMATTED_SIZE_DECIMAL);
+signed_to_lexical!(i32, i32, u32, I32_FORMATTED_SIZE, I32_FORMATTED_SIZE_DECIMAL);
+signed_to_lexical!(i64, i64, u64, I64_FORMATTED_SIZE, I64_FORMATTED_SIZE_DECIMAL);
 
 #[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
-signed_to_lexical!(isize, i32, u32);
+signed_to_lexical!(isize, i32, u32, ISIZE_FORMATTED_SIZE, ISIZE_FORMATTED_SIZE_DECIMAL);
 
 #[cfg(target_pointer_width = "64")]
-signed_to_lexical!(isize, i64, u64);
+signed_to_lexical!(isize, i64, u64, ISIZE_FORMATTED_SIZE, ISIZE_FORMATTED_SIZE_DECIMAL);
 
 #[cfg(has_i128)]
-signed_to_lexical!(i128, i128, u128);
+signed_to_lexical!(i128, i128, u128, I128_FORMATTED_SIZE, I128_FORMATTED_SIZE_DECIMAL);
 
 // TESTS
 // -----
@@ -448,6 +554,129 @@ mod tests {
         }
     }
 
+    #[test]
+    fn width_test() {
+        let mut options = WriteOptions::new();
+        options.set_width(6);
+        let mut buffer = new_buffer();
+        assert_eq!(b"    42", 42u8.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"   -42", (-42i8).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"     0", 0u64.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"1234567", 1234567u32.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_fill(b'.');
+        options.set_alignment(Alignment::Left);
+        assert_eq!(b"-42...", (-42i32).to_lexical_with_options(&options, &mut buffer));
+
+        options.set_alignment(Alignment::Center);
+        assert_eq!(b".-42..", (-42i64).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"..7...", 7usize.to_lexical_with_options(&options, &mut buffer));
+
+        options.set_zero_pad(true);
+        assert_eq!(b"-00042", (-42i16).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"000042", 42u16.to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"-00128", i8::min_value().to_lexical_with_options(&options, &mut buffer));
+
+        options.set_group_separator(b',');
+        options.set_width(10);
+        assert_eq!(b"-00001,234", (-1234i32).to_lexical_with_options(&options, &mut buffer));
+
+        options.set_zero_pad(false);
+        assert_eq!(b"..-1,234..", (-1234i32).to_lexical_with_options(&options, &mut buffer));
+    }
+
+    #[test]
+    fn width_large_test() {
+        let mut options = WriteOptions::new();
+        options.set_width(100);
+        options.set_alignment(Alignment::Left);
+        let mut buffer = vec![b'\0'; options.integer_buffer_size::<u64>()];
+        let bytes = u64::max_value().to_lexical_with_options(&options, &mut buffer);
+        assert_eq!(bytes.len(), 100);
+        assert_eq!(&bytes[..20], b"18446744073709551615");
+        assert!(bytes[20..].iter().all(|&c| c == b' '));
+    }
+
+    #[test]
+    #[should_panic]
+    fn width_buffer_test() {
+        let mut options = WriteOptions::new();
+        options.set_width(1000);
+        let mut buffer = new_buffer();
+        1u64.to_lexical_with_options(&options, &mut buffer);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn width_radix_test() {
+        let mut options = WriteOptions::new();
+        options.set_radix(16);
+        options.set_width(8);
+        options.set_zero_pad(true);
+        let mut buffer = new_buffer();
+        assert_eq!(b"-000002A", (-42i32).to_lexical_with_options(&options, &mut buffer));
+        assert_eq!(b"FFFFFFFF", u32::max_value().to_lexical_with_options(&options, &mut buffer));
+
+        options.set_radix(2);
+        options.set_zero_pad(false);
+        assert_eq!(b"  101010", 42u8.to_lexical_with_options(&options, &mut buffer));
+    }
+
+    fn check_formatted_len<T: ToLexical + Integer>(value: T, options: &WriteOptions) {
+        let mut buffer = new_buffer();
+        let len = value.to_lexical_with_options(options, &mut buffer).len();
+        assert_eq!(value.formatted_len(options), len);
+    }
+
+    // Check the formatted length around every power of 10, with grouping and padding.
+    fn check_formatted_lens(options: &WriteOptions) {
+        let mut options = *options;
+        for &(separator, width) in [(0, 0), (b',', 0), (b',', 12)].iter() {
+            options.set_group_separator(separator);
+            options.set_width(width);
+            let mut value = 1u64;
+            loop {
+                for &x in [value - 1, value, value + 1].iter() {
+                    check_formatted_len(x, &options);
+                    check_formatted_len(x as i64, &options);
+                    check_formatted_len(-(x as i64), &options);
+                    check_formatted_len(x as u32, &options);
+                    check_formatted_len(x as i16, &options);
+                    check_formatted_len(x as u8, &options);
+                    #[cfg(has_i128)]
+                    check_formatted_len(x as u128 * 10000000000000000000, &options);
+                }
+                match value.checked_mul(10) {
+                    Some(next) => value = next,
+                    None       => break,
+                }
+            }
+            check_formatted_len(u64::max_value(), &options);
+            check_formatted_len(i64::min_value(), &options);
+            #[cfg(has_i128)]
+            check_formatted_len(u128::max_value(), &options);
+            #[cfg(has_i128)]
+            check_formatted_len(i128::min_value(), &options);
+        }
+    }
+
+    #[test]
+    fn formatted_len_test() {
+        let options = WriteOptions::new();
+        assert_eq!(0u8.formatted_len(&options), 1);
+        assert_eq!((-128i8).formatted_len(&options), 4);
+        assert_eq!(u64::max_value().formatted_len(&options), 20);
+        check_formatted_lens(&options);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn formatted_len_radix_test() {
+        for &radix in [2, 3, 16, 36].iter() {
+            check_formatted_lens(&WriteOptions::radix(radix));
+        }
+    }
+
     // Quickcheck
 
     quickcheck! {
@@ -518,6 +747,42 @@ mod tests {
 
     #[cfg(feature = "std")]
     proptest! {
+        #[test]
+        fn i64_width_proptest(i in i64::min_value()..i64::max_value(), width in 0usize..30) {
+            let mut options = WriteOptions::new();
+            options.set_width(width);
+            let mut buffer = vec![b'\0'; options.integer_buffer_size::<i64>()];
+            let mut write = |options: &WriteOptions| {
+                String::from_utf8(i.to_lexical_with_options(options, &mut buffer).to_vec()).unwrap()
+            };
+            prop_assert_eq!(write(&options), format!("{:>1$}", i, width));
+            options.set_alignment(Alignment::Left);
+            prop_assert_eq!(write(&options), format!("{:<1$}", i, width));
+            options.set_alignment(Alignment::Center);
+            prop_assert_eq!(write(&options), format!("{:^1$}", i, width));
+            options.set_zero_pad(true);
+            prop_assert_eq!(write(&options), format!("{:01$}", i, width));
+        }
+
+        #[test]
+        fn i64_formatted_len_proptest(i in i64::min_value()..i64::max_value(), width in 0usize..30) {
+            let mut options = WriteOptions::new();
+            options.set_width(width);
+            options.set_group_separator(b',');
+            let mut buffer = new_buffer();
+            let len = i.to_lexical_with_options(&options, &mut buffer).len();
+            prop_assert_eq!(i.formatted_len(&options), len);
+        }
+
+        #[cfg(has_i128)]
+        #[test]
+        fn u128_formatted_len_proptest(i in u128::min_value()..u128::max_value()) {
+            let options = WriteOptions::new();
+            let mut buffer = new_buffer();
+            let len = i.to_lexical_with_options(&options, &mut buffer).len();
+            prop_assert_eq!(i.formatted_len(&options), len);
+        }
+
         #[test]
         fn u8_proptest(i in u8::min_value()..u8::max_value()) {
             let mut buffer = new_buffer();
@@ -598,28 +863,28 @@ mod tests {
     #[test]
     #[should_panic]
     fn i8_buffer_test() {
-        let mut buffer = [b'0'; i8::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <i8 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12i8.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn i16_buffer_test() {
-        let mut buffer = [b'0'; i16::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <i16 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12i16.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn i32_buffer_test() {
-        let mut buffer = [b'0'; i32::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <i32 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12i32.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn i64_buffer_test() {
-        let mut buffer = [b'0'; i64::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <i64 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12i64.to_lexical(&mut buffer);
     }
 
@@ -627,57 +892,57 @@ mod tests {
     #[test]
     #[should_panic]
     fn i128_buffer_test() {
-        let mut buffer = [b'0'; i128::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <i128 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12i128.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn isize_buffer_test() {
-        let mut buffer = [b'0'; isize::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <isize as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12isize.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn u8_buffer_test() {
-        let mut buffer = [b'0'; u8::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <u8 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12i8.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn u16_buffer_test() {
-        let mut buffer = [b'0'; u16::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <u16 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12i16.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn u32_buffer_test() {
-        let mut buffer = [b'0'; u32::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <u32 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12i32.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn u64_buffer_test() {
-        let mut buffer = [b'0'; u64::FORMATTED_SIZE_DECIMAL-1];
-        12i64.to_lexical(&mut buffer);
+        let mut buffer = [b'0'; <u64 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
+        12u64.to_lexical(&mut buffer);
     }
 
     #[cfg(has_i128)]
     #[test]
     #[should_panic]
     fn u128_buffer_test() {
-        let mut buffer = [b'0'; u128::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <u128 as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12i128.to_lexical(&mut buffer);
     }
 
     #[test]
     #[should_panic]
     fn usize_buffer_test() {
-        let mut buffer = [b'0'; usize::FORMATTED_SIZE_DECIMAL-1];
+        let mut buffer = [b'0'; <usize as ToLexical>::FORMATTED_SIZE_DECIMAL-1];
         12usize.to_lexical(&mut buffer);
     }
 }
@@ -167,6 +167,39 @@ macro_rules! calculate_offset {
     });
 }
 
+// Powers of 10 that fit in a u64.
+const POW10: [u64; 20] = [1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000, 10000000000, 100000000000, 1000000000000, 10000000000000, 100000000000000, 1000000000000000, 10000000000000000, 100000000000000000, 1000000000000000000, 10000000000000000000];
+
+// Calculate the number of digits of a u64.
+//
+// Estimates log10(value) from log2(value) like `calculate_offset`,
+// which may be 1 too low, and compares the value to the power of 10.
+perftools_inline!{
+#[allow(unused_unsafe)]
+fn u64_count(value: u64) -> usize {
+    // log2(0) is undefined, always ensure 1 bit is set.
+    let value = value | 1;
+    let log2 = 64 - cltz!(value);
+    let digits = (log2 * 1233) >> 12;
+    digits + (value >= unchecked_index!(POW10[digits])) as usize
+}}
+
+// Calculate the number of digits of a u128.
+perftools_inline!{
+#[cfg(has_i128)]
+#[allow(unused_unsafe)]
+fn u128_count(value: u128) -> usize {
+    if value >> 64 == 0 {
+        return u64_count(value as u64);
+    }
+
+    // The estimate is in [19, 38], so the power of 10 is `10^19 * 10^(digits-19)`.
+    let log2 = 128 - cltz!(value);
+    let digits = (log2 * 1233) >> 12;
+    let power = unchecked_index!(POW10[digits - 19]) as u128 * POW10[19] as u128;
+    digits + (value >= power) as usize
+}}
+
 // INDEXING
 // --------
 
@@ -765,25 +798,43 @@ if #[cfg(target_pointer_width = "16")] {
 pub(crate) trait Decimal {
     // Export integer to string.
     fn decimal(self, buffer: &mut [u8]) -> usize;
+
+    // Get the number of digits written by `decimal`.
+    fn decimal_count(self) -> usize;
 }
 
 // Implement decimal for type.
 macro_rules! decimal_impl {
-    ($t:ty, $cb:ident) => (
+    ($t:ty, $cb:ident, $count:ident) => (
         impl Decimal for $t {
             perftools_inline_always!{
             fn decimal(self, buffer: &mut [u8]) -> usize {
                 $cb(self, buffer)
             }}
+
+            perftools_inline_always!{
+            fn decimal_count(self) -> usize {
+                $count(self.as_u64())
+            }}
         }
     );
 }
 
-decimal_impl!(u8, u8toa);
-decimal_impl!(u16, u16toa);
-decimal_impl!(u32, u32toa);
-decimal_impl!(u64, u64toa);
-decimal_impl!(usize, usizetoa);
+decimal_impl!(u8, u8toa, u64_count);
+decimal_impl!(u16, u16toa, u64_count);
+decimal_impl!(u32, u32toa, u64_count);
+decimal_impl!(u64, u64toa, u64_count);
+decimal_impl!(usize, usizetoa, u64_count);
 
 #[cfg(has_i128)]
-decimal_impl!(u128, u128toa);
+impl Decimal for u128 {
+    perftools_inline_always!{
+    fn decimal(self, buffer: &mut [u8]) -> usize {
+        u128toa(self, buffer)
+    }}
+
+    perftools_inline_always!{
+    fn decimal_count(self) -> usize {
+        u128_count(self)
+    }}
+}
@@ -12,5 +12,4 @@ mod naive;
 
 mod api;
 
-#[cfg(feature = "radix")]
-pub(crate) use self::api::itoa_positive;
+pub(crate) use self::api::{digit_count, itoa_positive};
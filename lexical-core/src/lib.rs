@@ -35,8 +35,8 @@
 //!
 //! // In order to guarantee the buffer is long enough, always ensure there
 //! // are at least `T::FORMATTED_SIZE` bytes, which requires the
-//! // `lexical_core::Number` trait to be in scope.
-//! use lexical_core::Number;
+//! // `lexical_core::ToLexical` trait to be in scope.
+//! use lexical_core::ToLexical;
 //! let mut buf = [b'0'; f64::FORMATTED_SIZE];
 //! let slc = lexical_core::write::<f64>(15.1, &mut buf);
 //! assert_eq!(slc, b"15.1");
@@ -49,6 +49,12 @@
 //! let mut buf = [b'0'; f64::FORMATTED_SIZE_DECIMAL];
 //! let slc = lexical_core::write::<f64>(15.1, &mut buf);
 //! assert_eq!(slc, b"15.1");
+//! 
+//! // To format a number without sizing a buffer by hand, use
+//! // `FormattedNumber`, which stores the string inline and
+//! // dereferences to `&str`.
+//! let number = lexical_core::FormattedNumber::new(15.1f64);
+//! assert_eq!(&*number, "15.1");
 //! ```
 //!
 //! # Conversion API
@@ -56,16 +62,68 @@
 //! **To String**
 //! - [`write`]
 #![cfg_attr(feature = "radix", doc = " - [`write_radix`]")]
+//! - [`write_with_options`]
+//! - [`write_str`]
+#![cfg_attr(feature = "radix", doc = " - [`write_str_radix`]")]
+//! - [`write_str_with_options`]
+//! - [`try_write`]
+#![cfg_attr(feature = "radix", doc = " - [`try_write_radix`]")]
+//! - [`try_write_with_options`]
+//! - [`formatted_len`]
+//! - [`write_exact`]
+//! - [`write_to_fmt`]
+#![cfg_attr(feature = "std", doc = " - [`write_to_io`]")]
+//! - [`FormattedNumber`]
 //!
 //! **From String**
 //! - [`parse`]
 #![cfg_attr(feature = "radix", doc = " - [`parse_radix`]")]
+//! - [`parse_with_options`]
 //! - [`parse_partial`]
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_radix`]")]
+//! - [`parse_partial_with_options`]
+//! - [`parse_partial_with_suffix`]
 //! - [`parse_lossy`]
 #![cfg_attr(feature = "radix", doc = " - [`parse_lossy_radix`]")]
+//! - [`parse_lossy_with_options`]
 //! - [`parse_partial_lossy`]
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_lossy_radix`]")]
+//! - [`parse_partial_lossy_with_options`]
+//!
+//! **16-Bit Floats**
+//! - [`f16`]
+//! - [`bf16`]
+//!
+//! Parsing and writing are implemented for the IEEE half-precision
+//! [`f16`] and the bfloat16 [`bf16`], which store the bits of the float.
+//! Parsing is correctly rounded directly to 16 bits, and writing uses
+//! the shortest digits that round-trip.
+//!
+//! # Options
+//!
+//! The `*_with_options` functions take a [`ParseOptions`] or
+//! [`WriteOptions`] argument, which customizes a single conversion.
+//! Options are initialized from the global configuration settings,
+//! and unlike the global configuration, are thread-safe. Parsers may
+//! also skip whitespace surrounding the number, via [`Whitespace`],
+//! and accept alternative spellings of special values, such as `1.#INF`,
+//! via [`SpecialAlias`]. Writers may also always or never write floats
+//! with an exponent, independent of the backend, via [`Notation`], and
+//! style signs, exponents and special values, such as `+1.5E+05`, with
+//! the letter case set via [`LetterCase`], and trim or force the `.0`
+//! fraction of floats via [`TrailingZero`]. Integers and floats may also
+//! be padded to a minimum width, with a fill character in an
+//! [`Alignment`], or with zeros after the sign, such as `-0042`.
+#![cfg_attr(feature = "format", doc = "")]
+#![cfg_attr(feature = "format", doc = " With the `format` feature, [`ParseOptions`] also accepts a [`NumberFormat`],")]
+#![cfg_attr(feature = "format", doc = " which enables digit separators, such as `1_000_000`, and stricter number")]
+#![cfg_attr(feature = "format", doc = " grammars. Presets are provided for common formats, such as")]
+#![cfg_attr(feature = "format", doc = " `NumberFormat::JSON` and `NumberFormat::RUST_LITERAL`.")]
+#![cfg_attr(feature = "radix", doc = "")]
+#![cfg_attr(feature = "radix", doc = " With the `radix` feature, options may also parse and write C99 hexadecimal")]
+#![cfg_attr(feature = "radix", doc = " floats, such as `0x1.8p+3`, like `strtod` and `printf(\"%a\")`. Base prefixes,")]
+#![cfg_attr(feature = "radix", doc = " such as `0x1F`, may be allowed, required, or used to detect the radix, like")]
+#![cfg_attr(feature = "radix", doc = " `strtol` with a base of 0, via [`BasePrefix`].")]
 //!
 //! # Configuration Settings
 //!
@@ -87,14 +145,44 @@
 //!
 //! [`write`]: fn.write.html
 #![cfg_attr(feature = "radix", doc = " [`write_radix`]: fn.write_radix.html")]
+//! [`write_with_options`]: fn.write_with_options.html
+//! [`write_str`]: fn.write_str.html
+#![cfg_attr(feature = "radix", doc = " [`write_str_radix`]: fn.write_str_radix.html")]
+//! [`write_str_with_options`]: fn.write_str_with_options.html
+//! [`try_write`]: fn.try_write.html
+#![cfg_attr(feature = "radix", doc = " [`try_write_radix`]: fn.try_write_radix.html")]
+//! [`try_write_with_options`]: fn.try_write_with_options.html
+//! [`formatted_len`]: fn.formatted_len.html
+//! [`write_exact`]: fn.write_exact.html
+//! [`write_to_fmt`]: fn.write_to_fmt.html
+#![cfg_attr(feature = "std", doc = " [`write_to_io`]: fn.write_to_io.html")]
+//! [`FormattedNumber`]: struct.FormattedNumber.html
+//! [`f16`]: struct.f16.html
+//! [`bf16`]: struct.bf16.html
 //! [`parse`]: fn.parse.html
 #![cfg_attr(feature = "radix", doc = " [`parse_radix`]: fn.parse_radix.html")]
+//! [`parse_with_options`]: fn.parse_with_options.html
 //! [`parse_partial`]: fn.parse_partial.html
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_radix`]: fn.parse_partial_radix.html")]
+//! [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+//! [`parse_partial_with_suffix`]: fn.parse_partial_with_suffix.html
 //! [`parse_lossy`]: fn.parse_lossy.html
 #![cfg_attr(feature = "radix", doc = " [`parse_lossy_radix`]: fn.parse_lossy_radix.html")]
+//! [`parse_lossy_with_options`]: fn.parse_lossy_with_options.html
 //! [`parse_partial_lossy`]: fn.parse_partial_lossy.html
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_lossy_radix`]: fn.parse_partial_lossy_radix.html")]
+//! [`parse_partial_lossy_with_options`]: fn.parse_partial_lossy_with_options.html
+//!
+//! [`ParseOptions`]: struct.ParseOptions.html
+//! [`WriteOptions`]: struct.WriteOptions.html
+//! [`Whitespace`]: enum.Whitespace.html
+//! [`SpecialAlias`]: struct.SpecialAlias.html
+//! [`Notation`]: enum.Notation.html
+//! [`LetterCase`]: enum.LetterCase.html
+//! [`TrailingZero`]: enum.TrailingZero.html
+//! [`Alignment`]: enum.Alignment.html
+#![cfg_attr(feature = "format", doc = " [`NumberFormat`]: struct.NumberFormat.html")]
+#![cfg_attr(feature = "radix", doc = " [`BasePrefix`]: enum.BasePrefix.html")]
 //!
 //! [`get_exponent_default_char`]: fn.get_exponent_default_char.html
 #![cfg_attr(feature = "radix", doc = " [`get_exponent_backup_char`]: fn.get_exponent_backup_char.html")]
@@ -114,7 +202,7 @@
 
 // Require intrinsics in a no_std context.
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(all(not(feature = "std"), feature = "correct", feature = "radix"), feature(alloc))]
+#![cfg_attr(all(not(feature = "std"), feature = "radix"), feature(alloc))]
 #![cfg_attr(not(feature = "std"), feature(core_intrinsics))]
 
 // DEPENDENCIES
@@ -122,11 +210,15 @@
 #[macro_use]
 extern crate cfg_if;
 
-#[cfg(feature = "correct")]
 #[allow(unused_imports)]    // Not used before 1.26.
 #[macro_use]
 extern crate static_assertions;
 
+// Use bitflags for the number format.
+#[cfg(feature = "format")]
+#[macro_use]
+extern crate bitflags;
+
 // Testing assertions for floating-point equality.
 #[cfg(test)]
 #[macro_use]
@@ -143,13 +235,12 @@ extern crate quickcheck;
 extern crate proptest;
 
 // Use vec if there is a system allocator, which we require only if
-// we're using the correct and radix features.
-#[cfg(all(not(feature = "std"), feature = "correct", feature = "radix"))]
+// we're using the radix feature.
+#[cfg(all(not(feature = "std"), feature = "radix"))]
 #[cfg_attr(test, macro_use)]
 extern crate alloc;
 
-// Use arrayvec for atof.
-#[cfg(feature = "correct")]
+// Use arrayvec for the big-integer arithmetic.
 extern crate arrayvec;
 
 // Ensure only one back-end is enabled.
@@ -173,7 +264,7 @@ pub(crate) use std::*;
 pub(crate) use core::*;
 
 cfg_if! {
-if #[cfg(all(feature = "correct", feature = "radix"))] {
+if #[cfg(feature = "radix")] {
     #[cfg(feature = "std")]
     pub(crate) use std::vec::Vec;
 
@@ -212,7 +303,7 @@ pub use util::*;
 /// number. In order to ensure the function will not panic, provide a
 /// buffer with at least [`FORMATTED_SIZE_DECIMAL`] elements.
 ///
-/// [`FORMATTED_SIZE_DECIMAL`]: trait.Number.html#associatedconstant.FORMATTED_SIZE_DECIMAL
+/// [`FORMATTED_SIZE_DECIMAL`]: trait.ToLexical.html#associatedconstant.FORMATTED_SIZE_DECIMAL
 #[inline]
 pub fn write<'a, N: ToLexical>(n: N, bytes: &'a mut [u8])
     -> &'a mut [u8]
@@ -237,7 +328,7 @@ pub fn write<'a, N: ToLexical>(n: N, bytes: &'a mut [u8])
 /// serialized number. In order to ensure the function will not panic,
 /// provide a buffer with at least [`FORMATTED_SIZE`] elements.
 ///
-/// [`FORMATTED_SIZE`]: trait.Number.html#associatedconstant.FORMATTED_SIZE
+/// [`FORMATTED_SIZE`]: trait.ToLexical.html#associatedconstant.FORMATTED_SIZE
 #[inline]
 #[cfg(feature = "radix")]
 pub fn write_radix<'a, N: ToLexical>(n: N, radix: u8, bytes: &'a mut [u8])
@@ -381,3 +472,501 @@ pub fn parse_partial_lossy_radix<N: FromLexicalLossy>(bytes: &[u8], radix: u8)
 {
     N::from_lexical_partial_lossy_radix(bytes, radix)
 }
+
+/// Write number to string with custom options.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number serialization.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the
+/// serialized number. In order to ensure the function will not panic,
+/// provide a buffer with at least [`FORMATTED_SIZE`] elements. A minimum
+/// width may require more bytes, see [`integer_buffer_size`] and
+/// [`float_buffer_size`]. Floats with a fixed precision, significant
+/// digits, a notation or minimum exponent digits require at least
+/// [`float_buffer_size`] elements, or the [`formatted_len`] of the value.
+///
+/// [`FORMATTED_SIZE`]: trait.ToLexical.html#associatedconstant.FORMATTED_SIZE
+/// [`integer_buffer_size`]: struct.WriteOptions.html#method.integer_buffer_size
+/// [`float_buffer_size`]: struct.WriteOptions.html#method.float_buffer_size
+/// [`formatted_len`]: trait.ToLexical.html#tymethod.formatted_len
+#[inline]
+pub fn write_with_options<'a, N: ToLexical>(n: N, options: &WriteOptions, bytes: &'a mut [u8])
+    -> &'a mut [u8]
+{
+    n.to_lexical_with_options(options, bytes)
+}
+
+/// Write number to a string slice.
+///
+/// Returns a string slice of the input buffer containing the written
+/// number, starting from the same address in memory as the input slice.
+/// The written number is always ASCII, since the NaN and infinity strings
+/// and any other configurable characters must be ASCII.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ToLexical;
+/// # pub fn main() {
+/// let mut buffer = [b'0'; i32::FORMATTED_SIZE_DECIMAL];
+/// assert_eq!(lexical_core::write_str(-42i32, &mut buffer), "-42");
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least [`FORMATTED_SIZE_DECIMAL`] elements.
+///
+/// [`FORMATTED_SIZE_DECIMAL`]: trait.ToLexical.html#associatedconstant.FORMATTED_SIZE_DECIMAL
+#[inline]
+pub fn write_str<'a, N: ToLexical>(n: N, bytes: &'a mut [u8])
+    -> &'a str
+{
+    ascii_to_str(n.to_lexical(bytes))
+}
+
+/// Write number to a string slice with a custom radix.
+///
+/// Returns a string slice of the input buffer containing the written
+/// number, starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// Also panics if the buffer may not be large enough to hold the
+/// serialized number. In order to ensure the function will not panic,
+/// provide a buffer with at least [`FORMATTED_SIZE`] elements.
+///
+/// [`FORMATTED_SIZE`]: trait.ToLexical.html#associatedconstant.FORMATTED_SIZE
+#[inline]
+#[cfg(feature = "radix")]
+pub fn write_str_radix<'a, N: ToLexical>(n: N, radix: u8, bytes: &'a mut [u8])
+    -> &'a str
+{
+    ascii_to_str(n.to_lexical_radix(radix, bytes))
+}
+
+/// Write number to a string slice with custom options.
+///
+/// Returns a string slice of the input buffer containing the written
+/// number, starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number serialization.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ToLexical, WriteOptions};
+/// # pub fn main() {
+/// let mut options = WriteOptions::new();
+/// options.set_inf_string(b"Inf");
+/// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+/// assert_eq!(lexical_core::write_str_with_options(-f64::INFINITY, &options, &mut buffer), "-Inf");
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the
+/// serialized number. In order to ensure the function will not panic,
+/// provide a buffer with at least [`FORMATTED_SIZE`] elements. A minimum
+/// width may require more bytes, see [`integer_buffer_size`] and
+/// [`float_buffer_size`].
+///
+/// [`FORMATTED_SIZE`]: trait.ToLexical.html#associatedconstant.FORMATTED_SIZE
+/// [`integer_buffer_size`]: struct.WriteOptions.html#method.integer_buffer_size
+/// [`float_buffer_size`]: struct.WriteOptions.html#method.float_buffer_size
+#[inline]
+pub fn write_str_with_options<'a, N: ToLexical>(n: N, options: &WriteOptions, bytes: &'a mut [u8])
+    -> &'a str
+{
+    ascii_to_str(n.to_lexical_with_options(options, bytes))
+}
+
+/// Write number to string, without panicking if the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// let mut buffer = [b'0'; 4];
+/// assert_eq!(lexical_core::try_write(-123i32, &mut buffer), Ok(&mut b"-123".clone()[..]));
+///
+/// let error = lexical_core::try_write(-12345i32, &mut buffer).err().unwrap();
+/// assert_eq!(error.code, ErrorCode::BufferTooSmall);
+/// assert_eq!(error.index, 6);
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`BufferTooSmall`] error if the buffer is too small to hold
+/// the serialized number, with the number of bytes required as the
+/// error index, so the number may be written to a larger buffer, like
+/// `snprintf`.
+///
+/// [`BufferTooSmall`]: enum.ErrorCode.html#variant.BufferTooSmall
+#[inline]
+pub fn try_write<'a, N: ToLexical>(n: N, bytes: &'a mut [u8])
+    -> Result<&'a mut [u8]>
+{
+    try_write_buffered(n, &WriteOptions::new(), bytes)
+}
+
+/// Write number to string with a custom radix, without panicking if the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Errors
+///
+/// Returns a [`BufferTooSmall`] error if the buffer is too small to hold
+/// the serialized number, with the number of bytes required as the
+/// error index.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// [`BufferTooSmall`]: enum.ErrorCode.html#variant.BufferTooSmall
+#[inline]
+#[cfg(feature = "radix")]
+pub fn try_write_radix<'a, N: ToLexical>(n: N, radix: u8, bytes: &'a mut [u8])
+    -> Result<&'a mut [u8]>
+{
+    try_write_buffered(n, &WriteOptions::radix(radix), bytes)
+}
+
+/// Write number to string with custom options, without panicking if the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number serialization.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ErrorCode, WriteOptions};
+/// # pub fn main() {
+/// let mut options = WriteOptions::new();
+/// options.set_fixed_precision(Some(2));
+/// let mut buffer = [b'0'; 8];
+/// assert_eq!(lexical_core::try_write_with_options(3.14159f64, &options, &mut buffer), Ok(&mut b"3.14".clone()[..]));
+///
+/// let error = lexical_core::try_write_with_options(1e10f64, &options, &mut buffer).err().unwrap();
+/// assert_eq!(error.code, ErrorCode::BufferTooSmall);
+/// assert_eq!(error.index, 14);
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`BufferTooSmall`] error if the buffer is too small to hold
+/// the serialized number, with the number of bytes required as the
+/// error index, from [`formatted_len`].
+///
+/// [`BufferTooSmall`]: enum.ErrorCode.html#variant.BufferTooSmall
+/// [`formatted_len`]: fn.formatted_len.html
+#[inline]
+pub fn try_write_with_options<'a, N: ToLexical>(n: N, options: &WriteOptions, bytes: &'a mut [u8])
+    -> Result<&'a mut [u8]>
+{
+    try_write_buffered(n, options, bytes)
+}
+
+/// Get the number of bytes written by [`write_with_options`], without writing them.
+///
+/// The length includes any sign, group separators and padding to the
+/// minimum width, for the radix and format options. Only the significant
+/// digits of floats are generated, so the length is calculated faster
+/// than writing the number.
+///
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number serialization.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::WriteOptions;
+/// # pub fn main() {
+/// let mut options = WriteOptions::new();
+/// assert_eq!(lexical_core::formatted_len(-12345i32, &options), 6);
+/// assert_eq!(lexical_core::formatted_len(1.5f64, &options), 3);
+///
+/// options.set_group_separator(b',');
+/// options.set_fixed_precision(Some(2));
+/// assert_eq!(lexical_core::formatted_len(1234567.0f64, &options), 12);
+/// # }
+/// ```
+///
+/// [`write_with_options`]: fn.write_with_options.html
+#[inline]
+pub fn formatted_len<N: ToLexical>(n: N, options: &WriteOptions)
+    -> usize
+{
+    n.formatted_len(options)
+}
+
+/// Write the exact decimal value of a float to string.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. Every
+/// digit of the binary value is written, without rounding, which may
+/// require hundreds of digits for denormal floats.
+///
+/// * `value`   - Float to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let mut buffer = vec![b'0'; lexical_core::exact_len(0.1f64)];
+/// let bytes = lexical_core::write_exact(0.1f64, &mut buffer);
+/// assert_eq!(bytes, &b"0.1000000000000000055511151231257827021181583404541015625"[..]);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the serialized
+/// float. In order to ensure the function will not panic, provide a
+/// buffer with at least [`exact_len`] or [`FORMATTED_SIZE_EXACT`] elements.
+///
+/// [`exact_len`]: fn.exact_len.html
+/// [`FORMATTED_SIZE_EXACT`]: trait.ToLexicalExact.html#associatedconstant.FORMATTED_SIZE_EXACT
+#[inline]
+pub fn write_exact<'a, N: ToLexicalExact>(n: N, bytes: &'a mut [u8])
+    -> &'a mut [u8]
+{
+    n.to_lexical_exact(bytes)
+}
+
+/// Get the number of bytes written by [`write_exact`], without writing them.
+///
+/// * `value`   - Float to serialize.
+///
+/// [`write_exact`]: fn.write_exact.html
+#[inline]
+pub fn exact_len<N: ToLexicalExact>(n: N)
+    -> usize
+{
+    n.exact_len()
+}
+
+/// Write number to a formatter or other `fmt::Write` sink with custom options.
+///
+/// Returns the number of bytes written, so many numbers may be written
+/// into a single reused output, without a caller-provided buffer.
+///
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number serialization.
+/// * `writer`  - Sink for the written string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let options = lexical_core::WriteOptions::new();
+/// let mut string = String::from("x=");
+/// assert_eq!(lexical_core::write_to_fmt(-42i32, &options, &mut string), Ok(3));
+/// assert_eq!(string, "x=-42");
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the sink fails. Without the `std` feature, also
+/// returns an error if the serialized number is longer than
+/// [`BUFFER_SIZE`], such as for a large minimum width or fixed precision.
+///
+/// [`BUFFER_SIZE`]: constant.BUFFER_SIZE.html
+#[inline]
+pub fn write_to_fmt<N: ToLexical, W: lib::fmt::Write + ?Sized>(n: N, options: &WriteOptions, writer: &mut W)
+    -> lib::result::Result<usize, lib::fmt::Error>
+{
+    #[cfg(feature = "std")] {
+        write_buffered(n, options, |bytes| {
+            writer.write_str(ascii_to_str(bytes))?;
+            Ok(bytes.len())
+        })
+    }
+
+    #[cfg(not(feature = "std"))] {
+        let mut buffer: [u8; BUFFER_SIZE] = [b'\0'; BUFFER_SIZE];
+        let bytes = try_write_buffered(n, options, &mut buffer).map_err(|_| lib::fmt::Error)?;
+        writer.write_str(ascii_to_str(bytes))?;
+        Ok(bytes.len())
+    }
+}
+
+/// Write number to a file, socket or other `io::Write` sink with custom options.
+///
+/// Returns the number of bytes written, which are written entirely
+/// via `write_all`.
+///
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number serialization.
+/// * `writer`  - Sink for the written bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let options = lexical_core::WriteOptions::new();
+/// let mut bytes: Vec<u8> = Vec::new();
+/// assert_eq!(lexical_core::write_to_io(12345u32, &options, &mut bytes).unwrap(), 5);
+/// assert_eq!(bytes, b"12345");
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns any error from the sink.
+#[cfg(feature = "std")]
+#[inline]
+pub fn write_to_io<N: ToLexical, W: lib::io::Write + ?Sized>(n: N, options: &WriteOptions, writer: &mut W)
+    -> lib::io::Result<usize>
+{
+    write_buffered(n, options, |bytes| {
+        writer.write_all(bytes)?;
+        Ok(bytes.len())
+    })
+}
+
+/// Parse number from string with custom options.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_with_options<N: FromLexical>(bytes: &[u8], options: &ParseOptions)
+    -> Result<N>
+{
+    N::from_lexical_with_options(bytes, options)
+}
+
+/// Parse number from string with custom options.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_partial_with_options<N: FromLexical>(bytes: &[u8], options: &ParseOptions)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_with_options(bytes, options)
+}
+
+/// Parse number and literal type suffix from string with custom options.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), and then matches the longest type suffix from the
+/// options after the digits, returning the parsed value, the number of
+/// processed bytes including the suffix, and the suffix. An error is
+/// returned if the suffix does not fit `N`, or if the value does not
+/// fit the type denoted by the suffix. A finite float literal that
+/// overflows to infinity in the suffix type returns an overflow error.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ErrorCode, ParseOptions, TypeSuffix};
+/// # pub fn main() {
+/// let mut options = ParseOptions::new();
+/// options.set_type_suffixes(TypeSuffix::RUST);
+/// let (value, count, suffix) = lexical_core::parse_partial_with_suffix::<u64>(b"10u32", &options).unwrap();
+/// assert_eq!((value, count), (10, 5));
+/// assert_eq!(suffix.unwrap().suffix, b"u32");
+///
+/// let result = lexical_core::parse_partial_with_suffix::<f32>(b"1.5f64", &options);
+/// assert_eq!(result.map_err(|e| e.code), Err(ErrorCode::InvalidTypeSuffix));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial_with_suffix<N: FromLexical>(bytes: &[u8], options: &ParseOptions)
+    -> Result<(N, usize, Option<TypeSuffix>)>
+{
+    N::from_lexical_partial_with_suffix(bytes, options)
+}
+
+/// Lossily parse number from string with custom options.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. This parser is
+/// lossy, so numerical rounding may occur during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_lossy_with_options<N: FromLexicalLossy>(bytes: &[u8], options: &ParseOptions)
+    -> Result<N>
+{
+    N::from_lexical_lossy_with_options(bytes, options)
+}
+
+/// Lossily parse number from string with custom options.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point. This parser is
+/// lossy, so numerical rounding may occur during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_partial_lossy_with_options<N: FromLexicalLossy>(bytes: &[u8], options: &ParseOptions)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_lossy_with_options(bytes, options)
+}
@@ -75,6 +75,32 @@ pub fn rtrim_char_slice<'a>(slc: &'a [u8], c: u8)
     (slc, count)
 }
 
+/// Trim either of two characters from the left-side of a slice.
+#[inline]
+#[cfg(feature = "format")]
+pub fn ltrim_char2_slice<'a>(slc: &'a [u8], c1: u8, c2: u8)
+    -> (&'a [u8], usize)
+{
+    let count = slc.iter().take_while(|&&si| si == c1 || si == c2).count();
+    debug_assert!(count <= slc.len());
+    let slc = unsafe {slc.get_unchecked(count..)};
+    (slc, count)
+}
+
+/// Trim either of two characters from the right-side of a slice.
+#[inline]
+#[cfg(feature = "format")]
+pub fn rtrim_char2_slice<'a>(slc: &'a [u8], c1: u8, c2: u8)
+    -> (&'a [u8], usize)
+{
+    let count = slc.iter().rev().take_while(|&&si| si == c1 || si == c2).count();
+    let index = slc.len() - count;
+    debug_assert!(count <= slc.len());
+    debug_assert!(index <= slc.len());
+    let slc = unsafe {slc.get_unchecked(..index)};
+    (slc, count)
+}
+
 /// Copy from source-to-dst.
 #[inline]
 pub fn copy_to_dst<'a, Bytes: AsRef<[u8]>>(dst: &'a mut [u8], src: Bytes)
@@ -91,7 +117,6 @@ pub fn copy_to_dst<'a, Bytes: AsRef<[u8]>>(dst: &'a mut [u8], src: Bytes)
 }
 
 /// Length-check variant of ptr::write_bytes for a slice.
-#[cfg(not(any(feature = "grisu3", feature = "ryu")))]
 #[inline]
 pub fn write_bytes(dst: &mut [u8], byte: u8)
 {
@@ -198,4 +223,13 @@ mod tests {
         assert_eq!(rtrim_char_slice(z.as_bytes(), b'0').1, 0);
         assert_eq!(rtrim_char_slice(z.as_bytes(), b'5').1, 1);
     }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn trim_char2_test() {
+        let x = "0_0_1_0_";
+        assert_eq!(ltrim_char2_slice(x.as_bytes(), b'0', b'_'), (&b"1_0_"[..], 4));
+        assert_eq!(rtrim_char2_slice(x.as_bytes(), b'0', b'_'), (&b"0_0_1"[..], 3));
+        assert_eq!(ltrim_char2_slice(x.as_bytes(), b'1', b'_').1, 0);
+    }
 }
@@ -0,0 +1,43 @@
+//! Letter case for float writers.
+
+/// Letter case of the exponent character and special values.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LetterCase {
+    /// Write the characters as configured, such as `e` and `NaN`.
+    Preserve,
+    /// Write lowercase characters, such as `e` and `nan`.
+    Lower,
+    /// Write uppercase characters, such as `E` and `NAN`.
+    Upper,
+}
+
+impl LetterCase {
+    /// Convert an ASCII character to the letter case.
+    ///
+    /// Non-ASCII bytes are never changed.
+    #[inline]
+    pub(crate) fn convert(self, ch: u8) -> u8 {
+        match self {
+            LetterCase::Preserve    => ch,
+            LetterCase::Lower       => ch.to_ascii_lowercase(),
+            LetterCase::Upper       => ch.to_ascii_uppercase(),
+        }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_test() {
+        assert_eq!(LetterCase::Preserve.convert(b'e'), b'e');
+        assert_eq!(LetterCase::Upper.convert(b'e'), b'E');
+        assert_eq!(LetterCase::Lower.convert(b'N'), b'n');
+        assert_eq!(LetterCase::Upper.convert(b'^'), b'^');
+        assert_eq!(LetterCase::Upper.convert(0xE2), 0xE2);
+    }
+}
@@ -10,7 +10,7 @@ use super::rounding::RoundingKind;
 /// Control characters include digits, `.`, `+`, and `-`.
 #[inline]
 #[allow(unknown_lints, ellipsis_inclusive_range_patterns)]
-fn is_control_character(ch: u8, is_default: bool) -> bool {
+pub(crate) fn is_control_character(ch: u8, is_default: bool) -> bool {
     if is_default {
         // Default character handles radixes < 15 (where 'e'/'E' is a
         // a valid exponent character).
@@ -36,7 +36,7 @@ fn is_control_character(ch: u8, is_default: bool) -> bool {
 
 // Check if byte array starts with case-insensitive N.
 #[inline]
-fn starts_with_n(bytes: &[u8]) -> bool {
+pub(crate) fn starts_with_n(bytes: &[u8]) -> bool {
     match bytes.get(0) {
         Some(&b'N') => true,
         Some(&b'n') => true,
@@ -46,7 +46,7 @@ fn starts_with_n(bytes: &[u8]) -> bool {
 
 // Check if byte array starts with case-insensitive I.
 #[inline]
-fn starts_with_i(bytes: &[u8]) -> bool {
+pub(crate) fn starts_with_i(bytes: &[u8]) -> bool {
     match bytes.get(0) {
         Some(&b'I') => true,
         Some(&b'i') => true,
@@ -54,6 +54,22 @@ fn starts_with_i(bytes: &[u8]) -> bool {
     }
 }
 
+/// Determine if the character is a valid decimal point.
+///
+/// Decimal points must be printable ASCII characters, and cannot be
+/// a digit in any radix or a sign.
+#[inline]
+#[allow(unknown_lints, ellipsis_inclusive_range_patterns)]
+pub(crate) fn is_valid_decimal_point(ch: u8) -> bool {
+    match ch {
+        b'0' ... b'9'   => false,
+        b'a' ... b'z'   => false,
+        b'A' ... b'Z'   => false,
+        b'+' | b'-'     => false,
+        _               => ch.is_ascii_graphic(),
+    }
+}
+
 /// Fixed-size string for float configurations.
 ///
 /// These values are guaranteed less than or equal to the maximum
@@ -95,50 +111,26 @@ static mut EXPONENT_BACKUP_CHAR: u8 = b'^';
 #[cfg(feature = "rounding")]
 static mut FLOAT_ROUNDING: RoundingKind = RoundingKind::NearestTieEven;
 
-cfg_if! {
-if #[cfg(feature = "radix")] {
-    /// Not a Number literal.
-    static mut NAN_STRING: FloatConfigString = FloatConfigString {
-        // b"NaN"
-        data: [b'N', b'a', b'N', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0'],
-        length: 3
-    };
-
-    /// Short infinity literal.
-    static mut INF_STRING: FloatConfigString = FloatConfigString {
-        // b"inf"
-        data: [b'i', b'n', b'f', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0'],
-        length: 3
-    };
-
-    /// Long infinity literal.
-    static mut INFINITY_STRING: FloatConfigString = FloatConfigString {
-        // b"infinity"
-        data: [b'i', b'n', b'f', b'i', b'n', b'i', b't', b'y', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0'],
-        length: 8
-    };
-} else {
-    /// Not a Number literal.
-    static mut NAN_STRING: FloatConfigString = FloatConfigString {
-        // b"NaN"
-        data: [b'N', b'a', b'N', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0'],
-        length: 3
-    };
-
-    /// Short infinity literal.
-    static mut INF_STRING: FloatConfigString = FloatConfigString {
-        // b"inf"
-        data: [b'i', b'n', b'f', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0'],
-        length: 3
-    };
-
-    /// Long infinity literal.
-    static mut INFINITY_STRING: FloatConfigString = FloatConfigString {
-        // b"infinity"
-        data: [b'i', b'n', b'f', b'i', b'n', b'i', b't', b'y', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0'],
-        length: 8
-    };
-}}   // cfg_if
+/// Not a Number literal.
+static mut NAN_STRING: FloatConfigString = FloatConfigString {
+    // b"NaN"
+    data: [b'N', b'a', b'N', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0'],
+    length: 3
+};
+
+/// Short infinity literal.
+static mut INF_STRING: FloatConfigString = FloatConfigString {
+    // b"inf"
+    data: [b'i', b'n', b'f', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0'],
+    length: 3
+};
+
+/// Long infinity literal.
+static mut INFINITY_STRING: FloatConfigString = FloatConfigString {
+    // b"infinity"
+    data: [b'i', b'n', b'f', b'i', b'n', b'i', b't', b'y', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0'],
+    length: 8
+};
 
 // GETTERS/SETTERS
 
@@ -165,14 +157,20 @@ pub fn get_exponent_default_char() -> u8
 /// # Safety
 ///
 /// Do not call this function in threaded-code, as it is not thread-safe.
+/// Use [`ParseOptions`] or [`WriteOptions`] to customize a single call
+/// instead.
+///
+/// [`ParseOptions`]: struct.ParseOptions.html
+/// [`WriteOptions`]: struct.WriteOptions.html
 ///
 /// # Panics
 ///
-/// Panics if the character is in the character set `[A-Da-d.+\-]`.
+/// Panics if the character is not ASCII, or is in the character set
+/// `[A-Da-d.+\-]`.
 #[inline]
 pub unsafe fn set_exponent_default_char(ch: u8)
 {
-    assert!(!is_control_character(ch, true));
+    assert!(ch.is_ascii() && !is_control_character(ch, true));
     EXPONENT_DEFAULT_CHAR = ch
 }
 
@@ -202,15 +200,21 @@ pub fn get_exponent_backup_char() -> u8
 /// # Safety
 ///
 /// Do not call this function in threaded-code, as it is not thread-safe.
+/// Use [`ParseOptions`] or [`WriteOptions`] to customize a single call
+/// instead.
+///
+/// [`ParseOptions`]: struct.ParseOptions.html
+/// [`WriteOptions`]: struct.WriteOptions.html
 ///
 /// # Panics
 ///
-/// Panics if the character is in the character set `[A-Za-z.+\-]`.
+/// Panics if the character is not ASCII, or is in the character set
+/// `[A-Za-z.+\-]`.
 #[inline]
 #[cfg(feature ="radix")]
 pub unsafe fn set_exponent_backup_char(ch: u8)
 {
-    assert!(!is_control_character(ch, false));
+    assert!(ch.is_ascii() && !is_control_character(ch, false));
     EXPONENT_BACKUP_CHAR = ch
 }
 
@@ -238,6 +242,9 @@ pub fn get_float_rounding() -> RoundingKind {
 /// # Safety
 ///
 /// Do not modify this value in threaded-code, as it is not thread-safe.
+/// Use [`ParseOptions`] to customize a single call instead.
+///
+/// [`ParseOptions`]: struct.ParseOptions.html
 #[inline]
 #[cfg(feature = "rounding")]
 pub unsafe fn set_float_rounding(rounding: RoundingKind) {
@@ -260,6 +267,11 @@ pub fn get_nan_string() -> &'static [u8]
 /// # Safety
 ///
 /// Do not call this function in threaded-code, as it is not thread-safe.
+/// Use [`ParseOptions`] or [`WriteOptions`] to customize a single call
+/// instead.
+///
+/// [`ParseOptions`]: struct.ParseOptions.html
+/// [`WriteOptions`]: struct.WriteOptions.html
 ///
 /// # Panics
 ///
@@ -267,10 +279,11 @@ pub fn get_nan_string() -> &'static [u8]
 /// - `bytes.len() >= f32::FORMATTED_SIZE`
 /// - `bytes` is empty
 /// - `bytes` does not start with an `'N'` or `'n'`.
+/// - `bytes` is not ASCII.
 #[inline]
 pub unsafe fn set_nan_string(bytes: &[u8])
 {
-    assert!(starts_with_n(bytes));
+    assert!(starts_with_n(bytes) && bytes.is_ascii());
     NAN_STRING.load_bytes(bytes);
 }
 
@@ -290,6 +303,11 @@ pub fn get_inf_string() -> &'static [u8]
 /// # Safety
 ///
 /// Do not call this function in threaded-code, as it is not thread-safe.
+/// Use [`ParseOptions`] or [`WriteOptions`] to customize a single call
+/// instead.
+///
+/// [`ParseOptions`]: struct.ParseOptions.html
+/// [`WriteOptions`]: struct.WriteOptions.html
 ///
 /// # Panics
 ///
@@ -298,10 +316,11 @@ pub fn get_inf_string() -> &'static [u8]
 /// - `bytes.len() >= get_infinity_string().len()`
 /// - `bytes` is empty
 /// - `bytes` does not start with an `'I'` or `'i'`.
+/// - `bytes` is not ASCII.
 #[inline]
 pub unsafe fn set_inf_string(bytes: &[u8])
 {
-    assert!(starts_with_i(bytes) && bytes.len() <= INFINITY_STRING.length);
+    assert!(starts_with_i(bytes) && bytes.len() <= INFINITY_STRING.length && bytes.is_ascii());
     INF_STRING.load_bytes(bytes);
 }
 
@@ -321,6 +340,11 @@ pub fn get_infinity_string() -> &'static [u8]
 /// # Safety
 ///
 /// Do not call this function in threaded-code, as it is not thread-safe.
+/// Use [`ParseOptions`] or [`WriteOptions`] to customize a single call
+/// instead.
+///
+/// [`ParseOptions`]: struct.ParseOptions.html
+/// [`WriteOptions`]: struct.WriteOptions.html
 ///
 /// # Panics
 ///
@@ -329,37 +353,52 @@ pub fn get_infinity_string() -> &'static [u8]
 /// - `bytes.len() < get_inf_string().len()`
 /// - `bytes` is empty
 /// - `bytes` does not start with an `'I'` or `'i'`.
+/// - `bytes` is not ASCII.
 #[inline]
 pub unsafe fn set_infinity_string(bytes: &[u8])
 {
-    assert!(starts_with_i(bytes) && bytes.len() >= INF_STRING.length);
+    assert!(starts_with_i(bytes) && bytes.len() >= INF_STRING.length && bytes.is_ascii());
     INFINITY_STRING.load_bytes(bytes);
 }
 
 // CONSTANTS
 
 // The f64 buffer is actually a size of 60, but use 64 since it's a
-// power of 2.
-pub(crate) const I8_FORMATTED_SIZE_DECIMAL: usize = 4;
-pub(crate) const I16_FORMATTED_SIZE_DECIMAL: usize = 6;
-pub(crate) const I32_FORMATTED_SIZE_DECIMAL: usize = 11;
-pub(crate) const I64_FORMATTED_SIZE_DECIMAL: usize = 20;
-pub(crate) const U8_FORMATTED_SIZE_DECIMAL: usize = 3;
-pub(crate) const U16_FORMATTED_SIZE_DECIMAL: usize = 5;
-pub(crate) const U32_FORMATTED_SIZE_DECIMAL: usize = 10;
-pub(crate) const U64_FORMATTED_SIZE_DECIMAL: usize = 20;
+// power of 2. The integer sizes include room for group separators
+// with the smallest group size of 1 digit, and the float sizes
+// already have room for grouping the integer digits.
+pub(crate) const I8_FORMATTED_SIZE_DECIMAL: usize = 6;
+pub(crate) const I16_FORMATTED_SIZE_DECIMAL: usize = 10;
+pub(crate) const I32_FORMATTED_SIZE_DECIMAL: usize = 20;
+pub(crate) const I64_FORMATTED_SIZE_DECIMAL: usize = 38;
+pub(crate) const U8_FORMATTED_SIZE_DECIMAL: usize = 5;
+pub(crate) const U16_FORMATTED_SIZE_DECIMAL: usize = 9;
+pub(crate) const U32_FORMATTED_SIZE_DECIMAL: usize = 19;
+pub(crate) const U64_FORMATTED_SIZE_DECIMAL: usize = 39;
+pub(crate) const F16_FORMATTED_SIZE_DECIMAL: usize = 64;
+pub(crate) const BF16_FORMATTED_SIZE_DECIMAL: usize = 64;
 pub(crate) const F32_FORMATTED_SIZE_DECIMAL: usize = 64;
 pub(crate) const F64_FORMATTED_SIZE_DECIMAL: usize = 64;
-#[cfg(has_i128)] pub(crate) const I128_FORMATTED_SIZE_DECIMAL: usize = 40;
-#[cfg(has_i128)] pub(crate) const U128_FORMATTED_SIZE_DECIMAL: usize = 39;
+#[cfg(has_i128)] pub(crate) const I128_FORMATTED_SIZE_DECIMAL: usize = 78;
+#[cfg(has_i128)] pub(crate) const U128_FORMATTED_SIZE_DECIMAL: usize = 77;
+
+// The exact value of the smallest denormal has 24 fraction digits
+// for an f16, 133 for a bf16, 149 for an f32, and 1074 for an f64,
+// after the sign and `0.`.
+pub(crate) const F16_FORMATTED_SIZE_EXACT: usize = 27;
+pub(crate) const BF16_FORMATTED_SIZE_EXACT: usize = 136;
+pub(crate) const F32_FORMATTED_SIZE_EXACT: usize = 152;
+pub(crate) const F64_FORMATTED_SIZE_EXACT: usize = 1077;
 
 // Simple, fast optimization.
 // Since we're declaring a variable on the stack, and our power-of-two
 // alignment dramatically improved atoi performance, do it.
 cfg_if! {
 if #[cfg(feature = "radix")] {
-    // Use 256, actually, since we seem to have memory issues with f64.
-    // Clearly not sufficient memory allocated for non-decimal values.
+    // The shortest round-trip floats are longest in radix 2, written
+    // positionally just above `1e-5`, with a sign, `0.`, 16 leading zeros
+    // and up to 24 digits for an f32, or 53 digits for an f64. The 43 and
+    // 72 bytes required are rounded up to a power of 2.
     pub(crate) const I8_FORMATTED_SIZE: usize = 16;
     pub(crate) const I16_FORMATTED_SIZE: usize = 32;
     pub(crate) const I32_FORMATTED_SIZE: usize = 64;
@@ -368,10 +407,14 @@ if #[cfg(feature = "radix")] {
     pub(crate) const U16_FORMATTED_SIZE: usize = 32;
     pub(crate) const U32_FORMATTED_SIZE: usize = 64;
     pub(crate) const U64_FORMATTED_SIZE: usize = 128;
-    pub(crate) const F32_FORMATTED_SIZE: usize = 256;
-    pub(crate) const F64_FORMATTED_SIZE: usize = 256;
+    pub(crate) const F16_FORMATTED_SIZE: usize = 64;
+    pub(crate) const BF16_FORMATTED_SIZE: usize = 64;
+    pub(crate) const F32_FORMATTED_SIZE: usize = 64;
+    pub(crate) const F64_FORMATTED_SIZE: usize = 128;
     #[cfg(has_i128)] pub(crate) const I128_FORMATTED_SIZE: usize = 256;
     #[cfg(has_i128)] pub(crate) const U128_FORMATTED_SIZE: usize = 256;
+    // The largest buffer is for 128-bit integers in radix 2.
+    pub(crate) const MAX_FORMATTED_SIZE: usize = 256;
 } else {
     // The f64 buffer is actually a size of 60, but use 64 since it's a
     // power of 2.
@@ -383,10 +426,15 @@ if #[cfg(feature = "radix")] {
     pub(crate) const U16_FORMATTED_SIZE: usize = U16_FORMATTED_SIZE_DECIMAL;
     pub(crate) const U32_FORMATTED_SIZE: usize = U32_FORMATTED_SIZE_DECIMAL;
     pub(crate) const U64_FORMATTED_SIZE: usize = U64_FORMATTED_SIZE_DECIMAL;
+    pub(crate) const F16_FORMATTED_SIZE: usize = F16_FORMATTED_SIZE_DECIMAL;
+    pub(crate) const BF16_FORMATTED_SIZE: usize = BF16_FORMATTED_SIZE_DECIMAL;
     pub(crate) const F32_FORMATTED_SIZE: usize = F32_FORMATTED_SIZE_DECIMAL;
     pub(crate) const F64_FORMATTED_SIZE: usize = F64_FORMATTED_SIZE_DECIMAL;
     #[cfg(has_i128)] pub(crate) const I128_FORMATTED_SIZE: usize = I128_FORMATTED_SIZE_DECIMAL;
     #[cfg(has_i128)] pub(crate) const U128_FORMATTED_SIZE: usize = U128_FORMATTED_SIZE_DECIMAL;
+    // The largest buffer is for grouped 128-bit integers, rounded up
+    // to a power of 2.
+    pub(crate) const MAX_FORMATTED_SIZE: usize = 128;
 }} // cfg_if
 
 cfg_if! {
@@ -408,23 +456,23 @@ if #[cfg(target_pointer_width = "16")] {
 }}  // cfg_if
 
 /// Maximum number of bytes required to serialize any number to string.
-pub const BUFFER_SIZE: usize = F64_FORMATTED_SIZE;
+pub const BUFFER_SIZE: usize = MAX_FORMATTED_SIZE;
 
 // FUNCTIONS
 
 /// Get the exponent notation character.
 #[inline]
 #[allow(unused_variables)]
-pub(crate) fn exponent_notation_char(radix: u32) -> u8 {
+pub(crate) fn exponent_notation_char(radix: u32, default: u8, backup: u8) -> u8 {
     #[cfg(not(feature ="radix"))] {
-        get_exponent_default_char()
+        default
     }
 
     #[cfg(feature ="radix")] {
         if radix >= 15 {
-            get_exponent_backup_char()
+            backup
         } else {
-            get_exponent_default_char()
+            default
         }
     }
 }
@@ -443,12 +491,12 @@ mod tests {
     fn exponent_notation_char_test() {
         let default = get_exponent_default_char();
         let backup = get_exponent_backup_char();
-        assert_eq!(exponent_notation_char(2), default);
-        assert_eq!(exponent_notation_char(8), default);
-        assert_eq!(exponent_notation_char(10), default);
-        assert_eq!(exponent_notation_char(15), backup);
-        assert_eq!(exponent_notation_char(16), backup);
-        assert_eq!(exponent_notation_char(32), backup);
+        assert_eq!(exponent_notation_char(2, default, backup), default);
+        assert_eq!(exponent_notation_char(8, default, backup), default);
+        assert_eq!(exponent_notation_char(10, default, backup), default);
+        assert_eq!(exponent_notation_char(15, default, backup), backup);
+        assert_eq!(exponent_notation_char(16, default, backup), backup);
+        assert_eq!(exponent_notation_char(32, default, backup), backup);
     }
 
     // Only enable when no other threads touch NAN_STRING or INFINITY_STRING.
@@ -608,6 +656,14 @@ mod tests {
         }
     }
 
+    #[should_panic]
+    #[test]
+    fn set_exponent_default_char_non_ascii_test() {
+        unsafe {
+            set_exponent_default_char(b'\xE2')
+        }
+    }
+
     #[cfg(all(feature = "radix"))]
     #[should_panic]
     #[test]
@@ -660,6 +716,14 @@ mod tests {
         }
     }
 
+    #[should_panic]
+    #[test]
+    fn set_nan_string_non_ascii_test() {
+        unsafe {
+            set_nan_string(b"n\xE2\x88\x9E")
+        }
+    }
+
     #[should_panic]
     #[test]
     fn set_inf_string_empty_test() {
@@ -684,6 +748,14 @@ mod tests {
         }
     }
 
+    #[should_panic]
+    #[test]
+    fn set_inf_string_non_ascii_test() {
+        unsafe {
+            set_inf_string(b"i\xE2\x88\x9E")
+        }
+    }
+
     #[should_panic]
     #[test]
     fn set_infinity_string_empty_test() {
@@ -707,4 +779,12 @@ mod tests {
             set_infinity_string(b"i")
         }
     }
+
+    #[should_panic]
+    #[test]
+    fn set_infinity_string_non_ascii_test() {
+        unsafe {
+            set_infinity_string(b"infinity\xE2\x88\x9E")
+        }
+    }
 }
@@ -16,11 +16,20 @@
 /// const int32_t EMPTY = -4;
 /// const int32_t EMPTY_FRACTION = -5;
 /// const int32_t EMPTY_EXPONENT = -6;
+/// const int32_t EMPTY_INTEGER = -7;
+/// const int32_t INVALID_POSITIVE_MANTISSA_SIGN = -8;
+/// const int32_t INVALID_LEADING_ZEROS = -9;
+/// const int32_t INVALID_SPECIAL = -10;
+/// const int32_t INVALID_POSITIVE_EXPONENT_SIGN = -11;
+/// const int32_t EXPONENT_WITHOUT_FRACTION = -12;
+/// const int32_t INVALID_BASE_PREFIX = -13;
+/// const int32_t INVALID_TYPE_SUFFIX = -14;
+/// const int32_t BUFFER_TOO_SMALL = -15;
 /// ```
 ///
 /// # Safety
 ///
-/// Assigning any value outside the range `[-6, -1]` to value of type
+/// Assigning any value outside the range `[-15, -1]` to value of type
 /// ErrorCode may invoke undefined-behavior.
 #[repr(i32)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -43,14 +52,34 @@ pub enum ErrorCode {
     EmptyFraction = -5,
     /// Empty exponent found.
     EmptyExponent = -6,
+    /// Empty integer found, when integer digits are required.
+    EmptyInteger = -7,
+    /// Invalid positive sign for the mantissa was found.
+    InvalidPositiveMantissaSign = -8,
+    /// Invalid leading zeros were found.
+    InvalidLeadingZeros = -9,
+    /// Invalid special value (NaN or infinity) was found.
+    InvalidSpecial = -10,
+    /// Invalid positive sign for the exponent was found.
+    InvalidPositiveExponentSign = -11,
+    /// Exponent was found without a fraction, when a fraction is required.
+    ExponentWithoutFraction = -12,
+    /// Base prefix was missing or did not match the radix, when a base prefix is required.
+    InvalidBasePrefix = -13,
+    /// Type suffix does not fit the parsed type.
+    InvalidTypeSuffix = -14,
+    /// Buffer is too small to hold the written number.
+    ///
+    /// The error index is the number of bytes required.
+    BufferTooSmall = -15,
 
     // We may add additional variants later, so ensure that client matching
     // does not depend on exhaustive matching.
     #[doc(hidden)]
-    __Nonexhaustive = -7,
+    __Nonexhaustive = -16,
 }
 
-/// Error type for lexical parsing.
+/// Error type for lexical parsing and writing.
 ///
 /// This error is FFI-compatible for interfacing with C code.
 #[repr(C)]
@@ -59,6 +88,8 @@ pub struct Error {
     /// Error code designating the type of error occurred.
     pub code: ErrorCode,
     /// Optional position within the buffer for the error.
+    ///
+    /// For `BufferTooSmall`, the number of bytes required instead.
     pub index: usize,
 }
 
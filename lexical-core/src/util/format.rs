@@ -0,0 +1,441 @@
+//! Number format specifications for number parsing.
+
+use super::case::LetterCase;
+
+// NUMBER FORMAT
+
+// Bit shifts for the digit separator flags of each component.
+// The integer, fraction, and exponent flags are adjacent, so the
+// component flags may be extracted with a single shift.
+pub(crate) const INTEGER_SHIFT: u32 = 0;
+pub(crate) const FRACTION_SHIFT: u32 = 1;
+pub(crate) const EXPONENT_SHIFT: u32 = 2;
+
+// Shift for the digit separator character.
+const DIGIT_SEPARATOR_SHIFT: u64 = 56;
+
+bitflags! {
+    /// Bitflags for a number format specification.
+    ///
+    /// The lowest 56 bits are flags describing the grammar of the number,
+    /// while the highest 8 bits store the digit separator character,
+    /// or `0` if digit separators are disabled. Use [`compile`] to create
+    /// a format with a digit separator.
+    ///
+    /// A digit separator is **internal** if it occurs between two digits,
+    /// **leading** if it occurs before any digits in the component, and
+    /// **trailing** if it occurs after all the digits in the component.
+    /// For example, in `_1_0_`, the first separator is leading, the
+    /// second is internal, and the third is trailing. Multiple adjacent
+    /// digit separators are only valid if **consecutive** separators
+    /// are enabled for the component. A component containing only
+    /// digit separators is always invalid.
+    ///
+    /// The default format is the permissive format used by [`parse`],
+    /// and has no digit separator.
+    ///
+    /// [`compile`]: #method.compile
+    /// [`parse`]: fn.parse.html
+    #[derive(Default)]
+    pub struct NumberFormat: u64 {
+        // GRAMMAR FLAGS
+
+        /// Digits are required before the decimal point.
+        const REQUIRED_INTEGER_DIGITS = 0b0000_0001;
+        /// Digits are required after the decimal point.
+        const REQUIRED_FRACTION_DIGITS = 0b0000_0010;
+        /// Digits are required after the exponent character.
+        const REQUIRED_EXPONENT_DIGITS = 0b0000_0100;
+        /// Positive sign before the mantissa is not allowed.
+        const NO_POSITIVE_MANTISSA_SIGN = 0b0000_1000;
+        /// Positive sign before the exponent is not allowed.
+        const NO_POSITIVE_EXPONENT_SIGN = 0b0001_0000;
+        /// Exponent without a fraction component is not allowed.
+        const NO_EXPONENT_WITHOUT_FRACTION = 0b0010_0000;
+        /// Special (non-finite) values are not allowed.
+        const NO_SPECIAL = 0b0100_0000;
+        /// Leading zeros before an integer value are not allowed.
+        const NO_INTEGER_LEADING_ZEROS = 0b1000_0000;
+        /// Leading zeros before a float value are not allowed.
+        const NO_FLOAT_LEADING_ZEROS = 0b0001_0000_0000;
+        /// Special values must match the case of the NaN and infinity strings.
+        const CASE_SENSITIVE_SPECIAL = 0b0010_0000_0000;
+        /// NaN must be lowercase, such as `nan`.
+        const LOWERCASE_NAN = 0b0100_0000_0000;
+        /// NaN must be uppercase, such as `NAN`.
+        const UPPERCASE_NAN = 0b1000_0000_0000;
+        /// Infinity must be lowercase, such as `inf`.
+        const LOWERCASE_INFINITY = 0b0001_0000_0000_0000;
+        /// Infinity must be uppercase, such as `INF`.
+        const UPPERCASE_INFINITY = 0b0010_0000_0000_0000;
+        /// Long infinity string, such as `infinity`, is not allowed.
+        const NO_LONG_INFINITY = 0b0100_0000_0000_0000;
+        /// Positive sign before a special value is not allowed.
+        const NO_POSITIVE_SPECIAL_SIGN = 0b1000_0000_0000_0000;
+        /// Sign before NaN is not allowed.
+        const NO_SIGNED_NAN = 0b0001_0000_0000_0000_0000;
+        /// Exponent after a decimal point without fraction digits is not allowed.
+        const NO_EXPONENT_WITHOUT_FRACTION_DIGITS = 0b0010_0000_0000_0000_0000;
+        /// Leading zeros before a non-zero integer value are not allowed.
+        const NO_NONZERO_INTEGER_LEADING_ZEROS = 0b0100_0000_0000_0000_0000;
+
+        /// Digits are required in every component.
+        const REQUIRED_DIGITS = 0b0000_0111;
+        /// Special values must be lowercase, such as `nan` and `inf`.
+        const LOWERCASE_SPECIAL = 0b0001_0100_0000_0000;
+
+        // DIGIT SEPARATOR FLAGS
+
+        /// Digit separators may occur between integer digits.
+        const INTEGER_INTERNAL_DIGIT_SEPARATOR = 0b0000_0001 << 32;
+        /// Digit separators may occur between fraction digits.
+        const FRACTION_INTERNAL_DIGIT_SEPARATOR = 0b0000_0010 << 32;
+        /// Digit separators may occur between exponent digits.
+        const EXPONENT_INTERNAL_DIGIT_SEPARATOR = 0b0000_0100 << 32;
+        /// Digit separators may occur before any integer digits.
+        const INTEGER_LEADING_DIGIT_SEPARATOR = 0b0000_1000 << 32;
+        /// Digit separators may occur before any fraction digits.
+        const FRACTION_LEADING_DIGIT_SEPARATOR = 0b0001_0000 << 32;
+        /// Digit separators may occur before any exponent digits.
+        const EXPONENT_LEADING_DIGIT_SEPARATOR = 0b0010_0000 << 32;
+        /// Digit separators may occur after all the integer digits.
+        const INTEGER_TRAILING_DIGIT_SEPARATOR = 0b0100_0000 << 32;
+        /// Digit separators may occur after all the fraction digits.
+        const FRACTION_TRAILING_DIGIT_SEPARATOR = 0b1000_0000 << 32;
+        /// Digit separators may occur after all the exponent digits.
+        const EXPONENT_TRAILING_DIGIT_SEPARATOR = 0b0001_0000_0000 << 32;
+        /// Multiple adjacent digit separators may occur in the integer.
+        const INTEGER_CONSECUTIVE_DIGIT_SEPARATOR = 0b0010_0000_0000 << 32;
+        /// Multiple adjacent digit separators may occur in the fraction.
+        const FRACTION_CONSECUTIVE_DIGIT_SEPARATOR = 0b0100_0000_0000 << 32;
+        /// Multiple adjacent digit separators may occur in the exponent.
+        const EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR = 0b1000_0000_0000 << 32;
+
+        /// Digit separators may occur between digits in any component.
+        const INTERNAL_DIGIT_SEPARATOR = 0b0000_0111 << 32;
+        /// Digit separators may occur before the digits in any component.
+        const LEADING_DIGIT_SEPARATOR = 0b0011_1000 << 32;
+        /// Digit separators may occur after the digits in any component.
+        const TRAILING_DIGIT_SEPARATOR = 0b0001_1100_0000 << 32;
+        /// Multiple adjacent digit separators may occur in any component.
+        const CONSECUTIVE_DIGIT_SEPARATOR = 0b1110_0000_0000 << 32;
+
+        // MASKS
+
+        /// Mask for all the special value flags.
+        const SPECIAL_FLAG_MASK = 0b0001_1111_1110_0000_0000;
+        /// Mask for all the digit separator flags.
+        const DIGIT_SEPARATOR_FLAG_MASK = 0b1111_1111_1111 << 32;
+        /// Mask for the digit separator character.
+        const DIGIT_SEPARATOR_MASK = 0xFF << DIGIT_SEPARATOR_SHIFT;
+    }
+}
+
+/// Determine if the character is a valid digit separator.
+///
+/// Digit separators must be printable ASCII characters, and cannot
+/// be a digit in any radix, a sign, or the decimal point.
+#[inline]
+pub(crate) fn is_valid_separator(ch: u8) -> bool {
+    match ch {
+        b'0' ... b'9'   => false,
+        b'a' ... b'z'   => false,
+        b'A' ... b'Z'   => false,
+        b'+' | b'-'     => false,
+        b'.'            => false,
+        _               => ch == b' ' || ch.is_ascii_graphic(),
+    }
+}
+
+// PRESETS
+
+// Digit separator flags for Rust literals. Separators may not occur
+// before the first integer or fraction digit, but may occur anywhere
+// in the exponent.
+const RUST_DIGIT_SEPARATOR_FLAGS: u64 =
+    NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR.bits
+    | NumberFormat::INTEGER_TRAILING_DIGIT_SEPARATOR.bits
+    | NumberFormat::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR.bits
+    | NumberFormat::FRACTION_INTERNAL_DIGIT_SEPARATOR.bits
+    | NumberFormat::FRACTION_TRAILING_DIGIT_SEPARATOR.bits
+    | NumberFormat::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR.bits
+    | NumberFormat::EXPONENT_INTERNAL_DIGIT_SEPARATOR.bits
+    | NumberFormat::EXPONENT_LEADING_DIGIT_SEPARATOR.bits
+    | NumberFormat::EXPONENT_TRAILING_DIGIT_SEPARATOR.bits
+    | NumberFormat::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR.bits;
+
+impl NumberFormat {
+    /// Number format for a JSON number.
+    ///
+    /// Requires integer, fraction and exponent digits, and forbids a
+    /// positive mantissa sign, leading zeros, and special values.
+    /// For example, `.5`, `5.`, `5e`, `+5`, `05` and `NaN` are all invalid.
+    pub const JSON: NumberFormat = NumberFormat {
+        bits: NumberFormat::REQUIRED_DIGITS.bits
+            | NumberFormat::NO_POSITIVE_MANTISSA_SIGN.bits
+            | NumberFormat::NO_SPECIAL.bits
+            | NumberFormat::NO_INTEGER_LEADING_ZEROS.bits
+            | NumberFormat::NO_FLOAT_LEADING_ZEROS.bits
+    };
+
+    /// Number format for a TOML number.
+    ///
+    /// Requires integer, fraction and exponent digits, forbids leading
+    /// zeros, and allows single `_` digit separators between digits.
+    /// Special values must be lowercase, and only the short infinity
+    /// string is allowed. For example, `1_000.0` and `-inf` are valid,
+    /// while `.5`, `5.`, `01`, `1__000`, `NaN` and `infinity` are invalid.
+    pub const TOML: NumberFormat = NumberFormat {
+        bits: NumberFormat::REQUIRED_DIGITS.bits
+            | NumberFormat::NO_INTEGER_LEADING_ZEROS.bits
+            | NumberFormat::NO_FLOAT_LEADING_ZEROS.bits
+            | NumberFormat::LOWERCASE_SPECIAL.bits
+            | NumberFormat::NO_LONG_INFINITY.bits
+            | NumberFormat::INTERNAL_DIGIT_SEPARATOR.bits
+            | ((b'_' as u64) << DIGIT_SEPARATOR_SHIFT)
+    };
+
+    /// Number format for a YAML 1.2 core schema number.
+    ///
+    /// Forbids special values, since YAML uses `.inf` and `.nan`.
+    pub const YAML: NumberFormat = NumberFormat {
+        bits: NumberFormat::NO_SPECIAL.bits
+    };
+
+    /// Number format for an XML Schema `xsd:double` value.
+    ///
+    /// Requires exponent digits, and only allows the special values `INF`,
+    /// `-INF` and `NaN`, which are case-sensitive. For example, `+.5` and
+    /// `5.` are valid, while `5e`, `inf`, `+INF`, `-NaN` and `INFINITY`
+    /// are invalid.
+    pub const XML: NumberFormat = NumberFormat {
+        bits: NumberFormat::REQUIRED_EXPONENT_DIGITS.bits
+            | NumberFormat::CASE_SENSITIVE_SPECIAL.bits
+            | NumberFormat::UPPERCASE_INFINITY.bits
+            | NumberFormat::NO_LONG_INFINITY.bits
+            | NumberFormat::NO_POSITIVE_SPECIAL_SIGN.bits
+            | NumberFormat::NO_SIGNED_NAN.bits
+    };
+
+    /// Number format for a Rust number literal.
+    ///
+    /// Requires integer and exponent digits, and fraction digits before
+    /// an exponent, forbids a positive mantissa sign and special values,
+    /// and allows `_` digit separators after the first digit, including
+    /// trailing and consecutive separators. For example, `1__000_.5_` and
+    /// `1.` are valid, while `.5`, `1.e5`, `_1` and `+1` are invalid.
+    pub const RUST_LITERAL: NumberFormat = NumberFormat {
+        bits: NumberFormat::REQUIRED_INTEGER_DIGITS.bits
+            | NumberFormat::REQUIRED_EXPONENT_DIGITS.bits
+            | NumberFormat::NO_EXPONENT_WITHOUT_FRACTION_DIGITS.bits
+            | NumberFormat::NO_POSITIVE_MANTISSA_SIGN.bits
+            | NumberFormat::NO_SPECIAL.bits
+            | RUST_DIGIT_SEPARATOR_FLAGS
+            | ((b'_' as u64) << DIGIT_SEPARATOR_SHIFT)
+    };
+
+    /// Number format for a Python number literal.
+    ///
+    /// Requires exponent digits, forbids a positive mantissa sign, special
+    /// values, and leading zeros for non-zero integers, and allows single
+    /// `_` digit separators between digits. For example, `1_000`, `00`
+    /// and `.5` are valid, while `01` is invalid.
+    pub const PYTHON_LITERAL: NumberFormat = NumberFormat {
+        bits: NumberFormat::REQUIRED_EXPONENT_DIGITS.bits
+            | NumberFormat::NO_POSITIVE_MANTISSA_SIGN.bits
+            | NumberFormat::NO_SPECIAL.bits
+            | NumberFormat::NO_NONZERO_INTEGER_LEADING_ZEROS.bits
+            | NumberFormat::INTERNAL_DIGIT_SEPARATOR.bits
+            | ((b'_' as u64) << DIGIT_SEPARATOR_SHIFT)
+    };
+
+    /// Number format for a C number literal.
+    ///
+    /// Requires exponent digits, and forbids a positive mantissa sign and
+    /// special values. For example, `.5` and `5.` are valid, while `+5`
+    /// and `inf` are invalid.
+    pub const C_LITERAL: NumberFormat = NumberFormat {
+        bits: NumberFormat::REQUIRED_EXPONENT_DIGITS.bits
+            | NumberFormat::NO_POSITIVE_MANTISSA_SIGN.bits
+            | NumberFormat::NO_SPECIAL.bits
+    };
+
+    /// Compile a number format from a digit separator and format flags.
+    ///
+    /// * `digit_separator` - Character to separate digits, or `0` to disable digit separators.
+    /// * `flags`           - Flags for the number format.
+    ///
+    /// Returns `None` if the digit separator is not a valid digit separator.
+    /// A valid digit separator is a printable ASCII character, which
+    /// is not a digit in any radix, a sign, or the decimal point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::NumberFormat;
+    /// # pub fn main() {
+    /// let flags = NumberFormat::INTERNAL_DIGIT_SEPARATOR;
+    /// let format = NumberFormat::compile(b'_', flags).unwrap();
+    /// assert_eq!(format.digit_separator(), b'_');
+    /// assert_eq!(format.flags(), flags);
+    /// assert_eq!(NumberFormat::compile(b'1', flags), None);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn compile(digit_separator: u8, flags: NumberFormat) -> Option<NumberFormat> {
+        if digit_separator != 0 && !is_valid_separator(digit_separator) {
+            return None;
+        }
+        let separator = (digit_separator as u64) << DIGIT_SEPARATOR_SHIFT;
+        let flags = flags.bits() & !NumberFormat::DIGIT_SEPARATOR_MASK.bits();
+        Some(NumberFormat { bits: flags | separator })
+    }
+
+    /// Get the flags from the number format, without the digit separator.
+    #[inline]
+    pub fn flags(&self) -> NumberFormat {
+        *self & !NumberFormat::DIGIT_SEPARATOR_MASK
+    }
+
+    /// Get the digit separator character, or `0` if digit separators are disabled.
+    #[inline]
+    pub fn digit_separator(&self) -> u8 {
+        (self.bits >> DIGIT_SEPARATOR_SHIFT) as u8
+    }
+
+    /// Get the letter case required for NaN, or `None` if case-insensitive.
+    #[inline]
+    pub(crate) fn nan_case(&self) -> Option<LetterCase> {
+        self.special_case(NumberFormat::LOWERCASE_NAN, NumberFormat::UPPERCASE_NAN)
+    }
+
+    /// Get the letter case required for infinity, or `None` if case-insensitive.
+    #[inline]
+    pub(crate) fn infinity_case(&self) -> Option<LetterCase> {
+        self.special_case(NumberFormat::LOWERCASE_INFINITY, NumberFormat::UPPERCASE_INFINITY)
+    }
+
+    /// Get the letter case required for a special value from the case flags.
+    #[inline]
+    fn special_case(&self, lowercase: NumberFormat, uppercase: NumberFormat) -> Option<LetterCase> {
+        if self.contains(lowercase) {
+            Some(LetterCase::Lower)
+        } else if self.contains(uppercase) {
+            Some(LetterCase::Upper)
+        } else if self.contains(NumberFormat::CASE_SENSITIVE_SPECIAL) {
+            Some(LetterCase::Preserve)
+        } else {
+            None
+        }
+    }
+
+    /// Get the number format with a group separator as the digit separator.
+    ///
+    /// The group separator may only occur between two integer digits,
+    /// and replaces any digit separator.
+    #[inline]
+    pub(crate) fn with_group_separator(&self, group_separator: u8) -> NumberFormat {
+        let mask = NumberFormat::DIGIT_SEPARATOR_FLAG_MASK | NumberFormat::DIGIT_SEPARATOR_MASK;
+        let separator = (group_separator as u64) << DIGIT_SEPARATOR_SHIFT;
+        let flags = (*self & !mask) | NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR;
+        NumberFormat { bits: flags.bits | separator }
+    }
+
+    /// Get the digit separator flags for a component, shifted to the integer flags.
+    #[inline]
+    pub(crate) fn component_separator_flags(&self, shift: u32) -> NumberFormat {
+        let mask = NumberFormat::DIGIT_SEPARATOR_FLAG_MASK.bits();
+        NumberFormat { bits: (self.bits & mask) >> shift }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_valid_separator_test() {
+        assert!(is_valid_separator(b'_'));
+        assert!(is_valid_separator(b'\''));
+        assert!(is_valid_separator(b' '));
+        assert!(is_valid_separator(b','));
+        assert!(!is_valid_separator(b'0'));
+        assert!(!is_valid_separator(b'a'));
+        assert!(!is_valid_separator(b'Z'));
+        assert!(!is_valid_separator(b'.'));
+        assert!(!is_valid_separator(b'+'));
+        assert!(!is_valid_separator(b'-'));
+        assert!(!is_valid_separator(b'\n'));
+        assert!(!is_valid_separator(0x80));
+    }
+
+    #[test]
+    fn compile_test() {
+        let flags = NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR | NumberFormat::FRACTION_TRAILING_DIGIT_SEPARATOR;
+        let format = NumberFormat::compile(b'\'', flags).unwrap();
+        assert_eq!(format.digit_separator(), b'\'');
+        assert_eq!(format.flags(), flags);
+        assert!(format.contains(NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR));
+        assert!(!format.contains(NumberFormat::FRACTION_INTERNAL_DIGIT_SEPARATOR));
+
+        let format = NumberFormat::compile(0, flags).unwrap();
+        assert_eq!(format.digit_separator(), 0);
+        assert_eq!(format.flags(), flags);
+
+        assert_eq!(NumberFormat::compile(b'e', flags), None);
+        assert_eq!(NumberFormat::default().digit_separator(), 0);
+    }
+
+    #[test]
+    fn presets_test() {
+        assert_eq!(NumberFormat::JSON.digit_separator(), 0);
+        assert_eq!(NumberFormat::TOML.digit_separator(), b'_');
+        assert_eq!(NumberFormat::RUST_LITERAL.digit_separator(), b'_');
+        assert_eq!(NumberFormat::PYTHON_LITERAL.digit_separator(), b'_');
+        assert_eq!(NumberFormat::C_LITERAL.digit_separator(), 0);
+        assert!(NumberFormat::XML.contains(NumberFormat::CASE_SENSITIVE_SPECIAL | NumberFormat::NO_SIGNED_NAN));
+        assert!(NumberFormat::TOML.contains(NumberFormat::LOWERCASE_SPECIAL | NumberFormat::NO_LONG_INFINITY));
+        assert!(NumberFormat::JSON.contains(NumberFormat::REQUIRED_DIGITS | NumberFormat::NO_SPECIAL));
+        assert!(!NumberFormat::JSON.intersects(NumberFormat::NO_POSITIVE_EXPONENT_SIGN | NumberFormat::NO_EXPONENT_WITHOUT_FRACTION));
+        assert!(!NumberFormat::RUST_LITERAL.contains(NumberFormat::INTEGER_LEADING_DIGIT_SEPARATOR));
+        assert!(NumberFormat::RUST_LITERAL.contains(NumberFormat::EXPONENT_LEADING_DIGIT_SEPARATOR));
+    }
+
+    #[test]
+    fn component_separator_flags_test() {
+        let format = NumberFormat::FRACTION_INTERNAL_DIGIT_SEPARATOR | NumberFormat::EXPONENT_LEADING_DIGIT_SEPARATOR;
+        let integer = format.component_separator_flags(INTEGER_SHIFT);
+        let fraction = format.component_separator_flags(FRACTION_SHIFT);
+        let exponent = format.component_separator_flags(EXPONENT_SHIFT);
+        assert!(!integer.intersects(NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR | NumberFormat::INTEGER_LEADING_DIGIT_SEPARATOR));
+        assert!(fraction.contains(NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR));
+        assert!(!fraction.contains(NumberFormat::INTEGER_LEADING_DIGIT_SEPARATOR));
+        assert!(!exponent.contains(NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR));
+        assert!(exponent.contains(NumberFormat::INTEGER_LEADING_DIGIT_SEPARATOR));
+    }
+
+    #[test]
+    fn special_case_test() {
+        assert_eq!(NumberFormat::default().nan_case(), None);
+        assert_eq!(NumberFormat::default().infinity_case(), None);
+        assert_eq!(NumberFormat::TOML.nan_case(), Some(LetterCase::Lower));
+        assert_eq!(NumberFormat::TOML.infinity_case(), Some(LetterCase::Lower));
+        assert_eq!(NumberFormat::XML.nan_case(), Some(LetterCase::Preserve));
+        assert_eq!(NumberFormat::XML.infinity_case(), Some(LetterCase::Upper));
+        assert_eq!(NumberFormat::UPPERCASE_NAN.nan_case(), Some(LetterCase::Upper));
+        assert_eq!(NumberFormat::UPPERCASE_NAN.infinity_case(), None);
+    }
+
+    #[test]
+    fn with_group_separator_test() {
+        let format = NumberFormat::RUST_LITERAL.with_group_separator(b'.');
+        assert_eq!(format.digit_separator(), b'.');
+        assert!(format.contains(NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR));
+        assert!(!format.intersects(NumberFormat::FRACTION_INTERNAL_DIGIT_SEPARATOR | NumberFormat::INTEGER_TRAILING_DIGIT_SEPARATOR));
+        assert!(format.contains(NumberFormat::REQUIRED_EXPONENT_DIGITS));
+    }
+}
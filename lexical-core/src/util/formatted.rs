@@ -0,0 +1,298 @@
+//! Stack-allocated strings for formatted numbers.
+
+use lib::{fmt, ops, str};
+use super::options::WriteOptions;
+use super::result::Result;
+use super::sink::try_write_buffered;
+use super::traits::ToLexical;
+
+// ASCII
+
+// Convert the bytes of a written number to a string.
+//
+// Every byte written is ASCII, since the NaN and infinity strings,
+// exponent characters, decimal point, group separator and fill
+// character must all be ASCII.
+perftools_inline!{
+pub(crate) fn ascii_to_str(bytes: &[u8]) -> &str {
+    debug_assert!(bytes.is_ascii());
+    // This is safe, since ASCII is always valid UTF-8.
+    unsafe {
+        str::from_utf8_unchecked(bytes)
+    }
+}}
+
+// FORMATTED BUFFER
+
+/// Byte array to store a formatted number inline.
+#[doc(hidden)]
+pub trait FormattedBuffer: Copy {
+    /// Create a zeroed buffer.
+    fn new() -> Self;
+
+    /// Get the buffer as a slice.
+    fn as_slice(&self) -> &[u8];
+
+    /// Get the buffer as a mutable slice.
+    fn as_mut_slice(&mut self) -> &mut [u8];
+}
+
+macro_rules! formatted_buffer_impl {
+    ($($size:tt)*) => ($(
+        impl FormattedBuffer for [u8; $size] {
+            #[inline]
+            fn new() -> Self {
+                [b'\0'; $size]
+            }
+
+            #[inline]
+            fn as_slice(&self) -> &[u8] {
+                self
+            }
+
+            #[inline]
+            fn as_mut_slice(&mut self) -> &mut [u8] {
+                self
+            }
+        }
+    )*)
+}
+
+// Every distinct `FORMATTED_SIZE`, with and without the `radix` feature.
+formatted_buffer_impl! { 5 6 9 10 16 19 20 32 38 39 64 77 78 128 256 }
+
+// FORMATTED NUMBER
+
+/// Number formatted to a string stored inline, without allocating.
+///
+/// Holds up to [`FORMATTED_SIZE`] bytes for the number type, and
+/// dereferences to `&str`, so a number can be formatted without sizing
+/// a buffer by hand.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::FormattedNumber;
+/// # pub fn main() {
+/// let number = FormattedNumber::new(-42i8);
+/// assert_eq!(&*number, "-42");
+/// assert_eq!(FormattedNumber::new(1.5f64).as_str(), "1.5");
+/// # }
+/// ```
+///
+/// [`FORMATTED_SIZE`]: trait.ToLexical.html#associatedconstant.FORMATTED_SIZE
+#[derive(Clone, Copy)]
+pub struct FormattedNumber<N: ToLexical> {
+    /// Buffer containing the formatted number.
+    buffer: N::Buffer,
+    /// Number of bytes written to the buffer.
+    length: usize,
+}
+
+impl<N: ToLexical> FormattedNumber<N> {
+    /// Format a number to a decimal string.
+    #[inline]
+    pub fn new(n: N) -> FormattedNumber<N> {
+        let mut buffer = N::Buffer::new();
+        let length = n.to_lexical(buffer.as_mut_slice()).len();
+        FormattedNumber { buffer, length }
+    }
+
+    /// Format a number to a string in the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[cfg(feature = "radix")]
+    #[inline]
+    pub fn radix(n: N, radix: u8) -> FormattedNumber<N> {
+        let mut buffer = N::Buffer::new();
+        let length = n.to_lexical_radix(radix, buffer.as_mut_slice()).len();
+        FormattedNumber { buffer, length }
+    }
+
+    /// Format a number to a string with custom options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ErrorCode, FormattedNumber, ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_group_separator(b',');
+    /// let number = FormattedNumber::with_options(1234567u32, &options).unwrap();
+    /// assert_eq!(&*number, "1,234,567");
+    ///
+    /// options.set_width(u32::FORMATTED_SIZE + 1);
+    /// let error = FormattedNumber::with_options(1234567u32, &options).err().unwrap();
+    /// assert_eq!(error.code, ErrorCode::BufferTooSmall);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferTooSmall`] error if the formatted number requires
+    /// more than [`FORMATTED_SIZE`] bytes, such as for a large minimum width
+    /// or fixed precision, like [`try_write_with_options`]. Options that
+    /// may require a larger buffer, such as a fixed precision, succeed
+    /// if the formatted number fits.
+    ///
+    /// [`BufferTooSmall`]: enum.ErrorCode.html#variant.BufferTooSmall
+    /// [`FORMATTED_SIZE`]: trait.ToLexical.html#associatedconstant.FORMATTED_SIZE
+    /// [`try_write_with_options`]: fn.try_write_with_options.html
+    #[inline]
+    pub fn with_options(n: N, options: &WriteOptions) -> Result<FormattedNumber<N>> {
+        let mut buffer = N::Buffer::new();
+        let length = try_write_buffered(n, options, buffer.as_mut_slice())?.len();
+        Ok(FormattedNumber { buffer, length })
+    }
+
+    /// Get the formatted number as a string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        ascii_to_str(self.as_bytes())
+    }
+
+    /// Get the formatted number as a byte slice.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        let bytes = self.buffer.as_slice();
+        &index!(bytes[..self.length])
+    }
+}
+
+impl<N: ToLexical> ops::Deref for FormattedNumber<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<N: ToLexical> AsRef<str> for FormattedNumber<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<N: ToLexical> AsRef<[u8]> for FormattedNumber<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<N: ToLexical> fmt::Display for FormattedNumber<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<N: ToLexical> fmt::Debug for FormattedNumber<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use lib::mem;
+    use util::*;
+    use super::*;
+
+    #[test]
+    fn ascii_to_str_test() {
+        assert_eq!(ascii_to_str(b""), "");
+        assert_eq!(ascii_to_str(b"-1.5e+05"), "-1.5e+05");
+        assert_eq!(ascii_to_str(b"1.#INF"), "1.#INF");
+    }
+
+    #[test]
+    fn formatted_size_test() {
+        assert_eq!(mem::size_of::<<u8 as ToLexical>::Buffer>(), <u8 as ToLexical>::FORMATTED_SIZE);
+        assert_eq!(mem::size_of::<<i64 as ToLexical>::Buffer>(), <i64 as ToLexical>::FORMATTED_SIZE);
+        assert_eq!(mem::size_of::<<f64 as ToLexical>::Buffer>(), <f64 as ToLexical>::FORMATTED_SIZE);
+        assert!(<u8 as ToLexical>::FORMATTED_SIZE_DECIMAL <= <u8 as ToLexical>::FORMATTED_SIZE);
+        assert!(<f32 as ToLexical>::FORMATTED_SIZE_DECIMAL <= <f32 as ToLexical>::FORMATTED_SIZE);
+        assert!(<f64 as ToLexical>::FORMATTED_SIZE <= BUFFER_SIZE);
+    }
+
+    #[test]
+    fn new_test() {
+        assert_eq!(&*FormattedNumber::new(0u8), "0");
+        assert_eq!(&*FormattedNumber::new(255u8), "255");
+        assert_eq!(&*FormattedNumber::new(-128i8), "-128");
+        assert_eq!(&*FormattedNumber::new(u64::max_value()), "18446744073709551615");
+        assert_eq!(&*FormattedNumber::new(i64::min_value()), "-9223372036854775808");
+        assert_eq!(&*FormattedNumber::new(-1.5f32), "-1.5");
+        assert_eq!(FormattedNumber::new(1.5f64).as_bytes(), b"1.5");
+        assert_eq!(&*FormattedNumber::new(bf16::from_bits(0x4049)), "3.14");
+        assert_eq!(FormattedNumber::new(f64::NAN).as_str(), "NaN");
+        assert_eq!(FormattedNumber::new(-7isize).len(), 2);
+    }
+
+    #[cfg(has_i128)]
+    #[test]
+    fn new_i128_test() {
+        assert_eq!(&*FormattedNumber::new(i128::min_value()), "-170141183460469231731687303715884105728");
+        assert_eq!(&*FormattedNumber::new(u128::max_value()), "340282366920938463463374607431768211455");
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn radix_test() {
+        assert_eq!(&*FormattedNumber::radix(255u8, 2), "11111111");
+        assert_eq!(FormattedNumber::radix(i64::min_value(), 2).len(), 65);
+        assert_eq!(&*FormattedNumber::radix(-255i16, 16), "-FF");
+        assert_eq!(&*FormattedNumber::radix(0.5f64, 2), "0.1");
+    }
+
+    #[test]
+    fn with_options_test() {
+        let mut options = WriteOptions::new();
+        options.set_width(6);
+        options.set_zero_pad(true);
+        assert_eq!(&*FormattedNumber::with_options(-42i32, &options).unwrap(), "-00042");
+
+        options.set_width(<u8 as ToLexical>::FORMATTED_SIZE);
+        assert_eq!(FormattedNumber::with_options(42u8, &options).unwrap().len(), <u8 as ToLexical>::FORMATTED_SIZE);
+        options.set_width(<u8 as ToLexical>::FORMATTED_SIZE + 1);
+        let error = FormattedNumber::with_options(42u8, &options).err().unwrap();
+        assert_eq!(error, (ErrorCode::BufferTooSmall, <u8 as ToLexical>::FORMATTED_SIZE + 1).into());
+    }
+
+    #[test]
+    fn with_options_fixed_test() {
+        let mut options = WriteOptions::new();
+        options.set_fixed_precision(Some(2));
+        assert!(f64::buffer_size(&options) > <f64 as ToLexical>::FORMATTED_SIZE);
+        assert_eq!(&*FormattedNumber::with_options(-1.5f64, &options).unwrap(), "-1.50");
+        assert_eq!(&*FormattedNumber::with_options(1e20f64, &options).unwrap(), "100000000000000000000.00");
+
+        options.set_fixed_precision(Some(300));
+        let error = FormattedNumber::with_options(1.5f64, &options).err().unwrap();
+        assert_eq!(error, (ErrorCode::BufferTooSmall, 302).into());
+    }
+
+    #[test]
+    fn traits_test() {
+        let number = FormattedNumber::new(1234u16);
+        let copy = number;
+        let string: &str = number.as_ref();
+        let bytes: &[u8] = number.as_ref();
+        assert_eq!(string, "1234");
+        assert_eq!(bytes, b"1234");
+        assert_eq!(copy.as_str(), "1234");
+        assert_eq!(format!("{:>6}", number), "  1234");
+        assert_eq!(format!("{:?}", number), "\"1234\"");
+    }
+}
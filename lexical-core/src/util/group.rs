@@ -0,0 +1,122 @@
+//! Digit grouping for number writers.
+
+use super::options::WriteOptions;
+
+// GROUPING
+
+/// Get the number of group separators for the number of integer digits.
+///
+/// The first (rightmost) group has `primary` digits, and every group
+/// after has `secondary` digits, like `12,34,567` with Indian grouping.
+#[inline]
+pub(crate) fn group_separator_count(digits: usize, primary: usize, secondary: usize)
+    -> usize
+{
+    debug_assert!(primary != 0 && secondary != 0);
+    if digits <= primary {
+        0
+    } else {
+        1 + (digits - primary - 1) / secondary
+    }
+}
+
+// Insert group separators into the integer digits at the start of a buffer.
+//
+// `bytes[..len]` contains the written number, the first `digits` bytes
+// of which are integer digits. Any bytes after the integer digits are
+// shifted right, and the buffer must have room for the separators.
+// Returns the new length of the written number.
+perftools_inline!{
+pub(crate) fn insert_group_separators(bytes: &mut [u8], len: usize, digits: usize, separator: u8, primary: usize, secondary: usize)
+    -> usize
+{
+    debug_assert!(digits <= len);
+    let count = group_separator_count(digits, primary, secondary);
+    if count == 0 {
+        return len;
+    }
+
+    // Shift the bytes after the integer digits, without `copy_within`,
+    // which requires Rustc 1.37.
+    let new_len = len + count;
+    let mut src = len;
+    while src > digits {
+        src -= 1;
+        index_mut!(bytes[src + count] = index!(bytes[src]));
+    }
+
+    // Move the integer digits backwards, writing separators between groups.
+    let mut dst = digits + count;
+    let mut group = primary;
+    let mut src = digits;
+    while src > 0 {
+        if group == 0 {
+            dst -= 1;
+            index_mut!(bytes[dst] = separator);
+            group = secondary;
+        }
+        src -= 1;
+        dst -= 1;
+        index_mut!(bytes[dst] = index!(bytes[src]));
+        group -= 1;
+    }
+    debug_assert!(dst == 0);
+
+    new_len
+}}
+
+// Group the integer digits of a written number from the write options.
+//
+// Returns the new length of the written number, which is unchanged
+// if grouping is disabled.
+perftools_inline!{
+pub(crate) fn group_digits(bytes: &mut [u8], len: usize, digits: usize, options: &WriteOptions)
+    -> usize
+{
+    match options.group_separator {
+        0 => len,
+        separator => {
+            let primary = options.group_size as usize;
+            let secondary = options.secondary_group_size as usize;
+            insert_group_separators(bytes, len, digits, separator, primary, secondary)
+        },
+    }
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(input: &[u8], digits: usize, separator: u8, primary: usize, secondary: usize, expected: &[u8]) {
+        let mut buffer = [b'\0'; 64];
+        buffer[..input.len()].copy_from_slice(input);
+        let len = insert_group_separators(&mut buffer, input.len(), digits, separator, primary, secondary);
+        assert_eq!(&buffer[..len], expected);
+    }
+
+    #[test]
+    fn group_separator_count_test() {
+        assert_eq!(group_separator_count(0, 3, 3), 0);
+        assert_eq!(group_separator_count(3, 3, 3), 0);
+        assert_eq!(group_separator_count(4, 3, 3), 1);
+        assert_eq!(group_separator_count(6, 3, 3), 1);
+        assert_eq!(group_separator_count(7, 3, 3), 2);
+        assert_eq!(group_separator_count(7, 3, 2), 2);
+        assert_eq!(group_separator_count(8, 3, 2), 3);
+        assert_eq!(group_separator_count(20, 2, 2), 9);
+    }
+
+    #[test]
+    fn insert_group_separators_test() {
+        check(b"1", 1, b',', 3, 3, b"1");
+        check(b"123", 3, b',', 3, 3, b"123");
+        check(b"1234", 4, b',', 3, 3, b"1,234");
+        check(b"1234567", 7, b',', 3, 3, b"1,234,567");
+        check(b"1234567", 7, b',', 3, 2, b"12,34,567");
+        check(b"1234567.89", 7, b' ', 3, 3, b"1 234 567.89");
+        check(b"123456e5", 6, b'_', 4, 4, b"12_3456e5");
+    }
+}
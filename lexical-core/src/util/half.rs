@@ -0,0 +1,790 @@
+//! 16-bit floats, for the IEEE754 half-precision and bfloat16 formats.
+//!
+//! Rust has no native 16-bit floats, so the floats store the bits, and
+//! are converted to and from the wider floats. Arithmetic is computed in
+//! an f32 and rounded back to the 16-bit float, which is correctly
+//! rounded, since the f32 has more than twice the precision.
+
+#![allow(non_camel_case_types)]
+
+use lib::{cmp, f64, fmt, iter, ops};
+use super::cast::*;
+use super::config::*;
+use super::num::*;
+use super::primitive::*;
+
+// CONVERSIONS
+
+// Round an f64 to the bits of the nearest 16-bit float, with ties to even.
+//
+// NaNs keep the sign and the most significant payload bits, and are
+// always quiet, like the hardware conversions between floats.
+fn narrow<F: Float<Unsigned=u16>>(value: f64) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 48) as u16 & F::SIGN_MASK;
+    if value.is_nan() {
+        let shift = f64::MANTISSA_SIZE - F::MANTISSA_SIZE;
+        let payload = ((bits & f64::MANTISSA_MASK) >> shift) as u16;
+        let quiet = F::HIDDEN_BIT_MASK >> 1;
+        return sign | F::EXPONENT_MASK | quiet | payload;
+    } else if value.is_infinite() {
+        return sign | F::INFINITY_BITS;
+    }
+
+    let mantissa = value.mantissa();
+    if mantissa == 0 {
+        return sign;
+    }
+
+    // The value is `mantissa * 2^exponent`. Find the exponent of the last
+    // mantissa bit of the 16-bit float, and shift the truncated bits
+    // out, rounding to the nearest. The f64 always has more mantissa
+    // bits, so at least 1 bit is truncated.
+    let bit_length = 64 - mantissa.leading_zeros() as i32;
+    let exponent = value.exponent() + bit_length - (F::MANTISSA_SIZE + 1);
+    let exponent = cmp::max(exponent, F::DENORMAL_EXPONENT);
+    let shift = exponent - value.exponent();
+    debug_assert!(shift > 0);
+    let rounded = match shift >= 64 {
+        // Below half of the smallest denormal.
+        true  => 0,
+        false => {
+            let halfway = 1u64 << (shift - 1);
+            let truncated = mantissa & ((halfway << 1) - 1);
+            let rounded = mantissa >> shift;
+            let is_above = truncated > halfway || (truncated == halfway && rounded & 1 == 1);
+            rounded + is_above as u64
+        },
+    };
+
+    // Rounding may carry into the hidden bit of a denormal, or past the
+    // hidden bit of a normal float, which adds to the exponent bits.
+    let biased = match rounded < F::HIDDEN_BIT_MASK as u64 {
+        true  => 0,
+        false => (exponent + F::EXPONENT_BIAS - 1) as u64,
+    };
+    let bits = (biased << F::MANTISSA_SIZE) + rounded;
+    if bits >= F::INFINITY_BITS as u64 {
+        sign | F::INFINITY_BITS
+    } else {
+        sign | bits as u16
+    }
+}
+
+// Convert the bits of a 16-bit float to the f64 with the same value.
+fn widen<F: Float<Unsigned=u16>>(bits: u16) -> f64 {
+    let sign = ((bits & F::SIGN_MASK) as u64) << 48;
+    let fraction = (bits & F::MANTISSA_MASK) as u64;
+    let biased = ((bits & F::EXPONENT_MASK) >> F::MANTISSA_SIZE) as i32;
+    let shift = f64::MANTISSA_SIZE - F::MANTISSA_SIZE;
+    let max_biased = (F::EXPONENT_MASK >> F::MANTISSA_SIZE) as i32;
+    // Convert an exponent of the last mantissa bit to the biased f64 exponent.
+    let f64_biased = |exponent: i32| (exponent + F::MANTISSA_SIZE + f64::EXPONENT_BIAS - f64::MANTISSA_SIZE) as u64;
+    if biased == max_biased {
+        // Infinity or NaN, keeping the payload.
+        f64::from_bits(sign | f64::INFINITY_BITS | (fraction << shift))
+    } else if biased == 0 {
+        // Denormal or zero, scaled by the smallest denormal, which is exact.
+        let scale = f64::from_bits(f64_biased(F::DENORMAL_EXPONENT - F::MANTISSA_SIZE) << f64::MANTISSA_SIZE);
+        f64::from_bits(sign | (fraction as f64 * scale).to_bits())
+    } else {
+        let exponent = f64_biased(biased - F::EXPONENT_BIAS);
+        f64::from_bits(sign | (exponent << f64::MANTISSA_SIZE) | (fraction << shift))
+    }
+}
+
+// F16
+
+/// IEEE754 half-precision (binary16) float.
+///
+/// Has 10 mantissa bits and 5 exponent bits. Parsing is correctly
+/// rounded to the 16-bit float, and writing uses the shortest digits
+/// that round-trip to the 16-bit float. Use [`from_bits`] and
+/// [`to_bits`] to convert to and from other half-precision types.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::f16;
+/// # pub fn main() {
+/// let value: f16 = lexical_core::parse(b"65500").unwrap();
+/// assert_eq!(value.to_bits(), 0x7BFF);
+/// assert_eq!(value.to_f32(), 65504.0);
+///
+/// let value: f16 = lexical_core::parse(b"0.1").unwrap();
+/// assert_eq!(value.to_f32(), 0.0999755859375);
+/// assert_eq!(&*lexical_core::FormattedNumber::new(value), "0.1");
+/// # }
+/// ```
+///
+/// [`from_bits`]: #method.from_bits
+/// [`to_bits`]: #method.to_bits
+#[derive(Clone, Copy, Default)]
+pub struct f16 {
+    /// Raw bits of the float.
+    bits: u16,
+}
+
+impl f16 {
+    /// Create a float from its raw bits.
+    #[inline]
+    pub fn from_bits(bits: u16) -> f16 {
+        f16 { bits }
+    }
+
+    /// Get the raw bits of the float.
+    #[inline]
+    pub fn to_bits(self) -> u16 {
+        self.bits
+    }
+
+    /// Round an f32 to the nearest float, with ties to even.
+    #[inline]
+    pub fn from_f32(value: f32) -> f16 {
+        f16::from_f64(value as f64)
+    }
+
+    /// Round an f64 to the nearest float, with ties to even.
+    #[inline]
+    pub fn from_f64(value: f64) -> f16 {
+        f16 { bits: narrow::<f16>(value) }
+    }
+
+    /// Convert to an f32 with the same value.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    /// Convert to an f64 with the same value.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        widen::<f16>(self.bits)
+    }
+}
+
+impl Float for f16 {
+    type Unsigned = u16;
+    const ZERO: f16 = f16 { bits: 0x0000 };
+    const ONE: f16 = f16 { bits: 0x3C00 };
+    const TWO: f16 = f16 { bits: 0x4000 };
+    const MAX: f16 = f16 { bits: 0x7BFF };
+    const MIN: f16 = f16 { bits: 0xFBFF };
+    const INFINITY: f16 = f16 { bits: 0x7C00 };
+    const NEG_INFINITY: f16 = f16 { bits: 0xFC00 };
+    const NAN: f16 = f16 { bits: 0x7E00 };
+    const BITS: usize = 16;
+    const SIGN_MASK: u16            = 0x8000;
+    const EXPONENT_MASK: u16        = 0x7C00;
+    const HIDDEN_BIT_MASK: u16      = 0x0400;
+    const MANTISSA_MASK: u16        = 0x03FF;
+    const INFINITY_BITS: u16        = 0x7C00;
+    const NEGATIVE_INFINITY_BITS: u16 = Self::INFINITY_BITS | Self::SIGN_MASK;
+    const MANTISSA_SIZE: i32        = 10;
+    const EXPONENT_BIAS: i32        = 15 + Self::MANTISSA_SIZE;
+    const DENORMAL_EXPONENT: i32    = 1 - Self::EXPONENT_BIAS;
+    const MAX_EXPONENT: i32         = 0x1F - Self::EXPONENT_BIAS;
+
+    #[inline]
+    fn abs(self) -> f16 {
+        f16 { bits: self.bits & !Self::SIGN_MASK }
+    }
+
+    #[inline]
+    fn ceil(self) -> f16 {
+        f16::from_f32(Float::ceil(self.to_f32()))
+    }
+
+    #[inline]
+    fn exp(self) -> f16 {
+        f16::from_f32(Float::exp(self.to_f32()))
+    }
+
+    #[inline]
+    fn floor(self) -> f16 {
+        f16::from_f32(Float::floor(self.to_f32()))
+    }
+
+    #[inline]
+    fn ln(self) -> f16 {
+        f16::from_f32(Float::ln(self.to_f32()))
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> f16 {
+        f16::from_f32(Float::powi(self.to_f32(), n))
+    }
+
+    #[inline]
+    fn powf(self, n: f16) -> f16 {
+        f16::from_f32(Float::powf(self.to_f32(), n.to_f32()))
+    }
+
+    #[inline]
+    fn round(self) -> f16 {
+        f16::from_f32(Float::round(self.to_f32()))
+    }
+
+    #[inline]
+    fn to_bits(self) -> u16 {
+        self.bits
+    }
+
+    #[inline]
+    fn from_bits(u: u16) -> f16 {
+        f16 { bits: u }
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.bits & Self::SIGN_MASK == 0
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        !self.is_sign_positive()
+    }
+}
+
+// BF16
+
+/// Brain floating-point (bfloat16) float.
+///
+/// Has 7 mantissa bits and 8 exponent bits, the same exponent range
+/// as an f32. Parsing is correctly rounded to the 16-bit float, and
+/// writing uses the shortest digits that round-trip to the 16-bit
+/// float. Use [`from_bits`] and [`to_bits`] to convert to and from
+/// other bfloat16 types.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::bf16;
+/// # pub fn main() {
+/// let value: bf16 = lexical_core::parse(b"3.14159").unwrap();
+/// assert_eq!(value.to_bits(), 0x4049);
+/// assert_eq!(value.to_f32(), 3.140625);
+/// assert_eq!(&*lexical_core::FormattedNumber::new(value), "3.14");
+/// # }
+/// ```
+///
+/// [`from_bits`]: #method.from_bits
+/// [`to_bits`]: #method.to_bits
+#[derive(Clone, Copy, Default)]
+pub struct bf16 {
+    /// Raw bits of the float.
+    bits: u16,
+}
+
+impl bf16 {
+    /// Create a float from its raw bits.
+    #[inline]
+    pub fn from_bits(bits: u16) -> bf16 {
+        bf16 { bits }
+    }
+
+    /// Get the raw bits of the float.
+    #[inline]
+    pub fn to_bits(self) -> u16 {
+        self.bits
+    }
+
+    /// Round an f32 to the nearest float, with ties to even.
+    #[inline]
+    pub fn from_f32(value: f32) -> bf16 {
+        bf16::from_f64(value as f64)
+    }
+
+    /// Round an f64 to the nearest float, with ties to even.
+    #[inline]
+    pub fn from_f64(value: f64) -> bf16 {
+        bf16 { bits: narrow::<bf16>(value) }
+    }
+
+    /// Convert to an f32 with the same value.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    /// Convert to an f64 with the same value.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        widen::<bf16>(self.bits)
+    }
+}
+
+impl Float for bf16 {
+    type Unsigned = u16;
+    const ZERO: bf16 = bf16 { bits: 0x0000 };
+    const ONE: bf16 = bf16 { bits: 0x3F80 };
+    const TWO: bf16 = bf16 { bits: 0x4000 };
+    const MAX: bf16 = bf16 { bits: 0x7F7F };
+    const MIN: bf16 = bf16 { bits: 0xFF7F };
+    const INFINITY: bf16 = bf16 { bits: 0x7F80 };
+    const NEG_INFINITY: bf16 = bf16 { bits: 0xFF80 };
+    const NAN: bf16 = bf16 { bits: 0x7FC0 };
+    const BITS: usize = 16;
+    const SIGN_MASK: u16            = 0x8000;
+    const EXPONENT_MASK: u16        = 0x7F80;
+    const HIDDEN_BIT_MASK: u16      = 0x0080;
+    const MANTISSA_MASK: u16        = 0x007F;
+    const INFINITY_BITS: u16        = 0x7F80;
+    const NEGATIVE_INFINITY_BITS: u16 = Self::INFINITY_BITS | Self::SIGN_MASK;
+    const MANTISSA_SIZE: i32        = 7;
+    const EXPONENT_BIAS: i32        = 127 + Self::MANTISSA_SIZE;
+    const DENORMAL_EXPONENT: i32    = 1 - Self::EXPONENT_BIAS;
+    const MAX_EXPONENT: i32         = 0xFF - Self::EXPONENT_BIAS;
+
+    #[inline]
+    fn abs(self) -> bf16 {
+        bf16 { bits: self.bits & !Self::SIGN_MASK }
+    }
+
+    #[inline]
+    fn ceil(self) -> bf16 {
+        bf16::from_f32(Float::ceil(self.to_f32()))
+    }
+
+    #[inline]
+    fn exp(self) -> bf16 {
+        bf16::from_f32(Float::exp(self.to_f32()))
+    }
+
+    #[inline]
+    fn floor(self) -> bf16 {
+        bf16::from_f32(Float::floor(self.to_f32()))
+    }
+
+    #[inline]
+    fn ln(self) -> bf16 {
+        bf16::from_f32(Float::ln(self.to_f32()))
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> bf16 {
+        bf16::from_f32(Float::powi(self.to_f32(), n))
+    }
+
+    #[inline]
+    fn powf(self, n: bf16) -> bf16 {
+        bf16::from_f32(Float::powf(self.to_f32(), n.to_f32()))
+    }
+
+    #[inline]
+    fn round(self) -> bf16 {
+        bf16::from_f32(Float::round(self.to_f32()))
+    }
+
+    #[inline]
+    fn to_bits(self) -> u16 {
+        self.bits
+    }
+
+    #[inline]
+    fn from_bits(u: u16) -> bf16 {
+        bf16 { bits: u }
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.bits & Self::SIGN_MASK == 0
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        !self.is_sign_positive()
+    }
+}
+
+// SHARED
+
+/// Implement the comparison, conversion and formatting traits.
+macro_rules! half_impl {
+    ($($t:ident)*) => ($(
+        impl PartialEq for $t {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<cmp::Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl From<$t> for f32 {
+            #[inline]
+            fn from(value: $t) -> f32 {
+                value.to_f32()
+            }
+        }
+
+        impl From<$t> for f64 {
+            #[inline]
+            fn from(value: $t) -> f64 {
+                value.to_f64()
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl ops::Neg for $t {
+            type Output = $t;
+
+            #[inline]
+            fn neg(self) -> $t {
+                $t { bits: self.bits ^ <$t as Float>::SIGN_MASK }
+            }
+        }
+    )*)
+}
+
+half_impl! { f16 bf16 }
+
+// IMPL PRIMITIVE
+
+/// Implement the primitive traits, through the f32 with the same value.
+macro_rules! half_primitive_impl {
+    ($($t:ident)*) => ($(
+        impl AsPrimitive for $t {
+            #[inline]
+            fn as_u8(self) -> u8 {
+                self.to_f32() as u8
+            }
+
+            #[inline]
+            fn as_u16(self) -> u16 {
+                self.to_f32() as u16
+            }
+
+            #[inline]
+            fn as_u32(self) -> u32 {
+                self.to_f32() as u32
+            }
+
+            #[inline]
+            fn as_u64(self) -> u64 {
+                self.to_f32() as u64
+            }
+
+            #[cfg(has_i128)]
+            #[inline]
+            fn as_u128(self) -> u128 {
+                self.to_f32() as u128
+            }
+
+            #[inline]
+            fn as_usize(self) -> usize {
+                self.to_f32() as usize
+            }
+
+            #[inline]
+            fn as_i8(self) -> i8 {
+                self.to_f32() as i8
+            }
+
+            #[inline]
+            fn as_i16(self) -> i16 {
+                self.to_f32() as i16
+            }
+
+            #[inline]
+            fn as_i32(self) -> i32 {
+                self.to_f32() as i32
+            }
+
+            #[inline]
+            fn as_i64(self) -> i64 {
+                self.to_f32() as i64
+            }
+
+            #[cfg(has_i128)]
+            #[inline]
+            fn as_i128(self) -> i128 {
+                self.to_f32() as i128
+            }
+
+            #[inline]
+            fn as_isize(self) -> isize {
+                self.to_f32() as isize
+            }
+
+            #[inline]
+            fn as_f32(self) -> f32 {
+                self.to_f32()
+            }
+
+            #[inline]
+            fn as_f64(self) -> f64 {
+                self.to_f64()
+            }
+        }
+
+        impl AsCast for $t {
+            #[inline]
+            fn as_cast<N: AsPrimitive>(n: N) -> $t {
+                $t::from_f64(n.as_f64())
+            }
+        }
+
+        impl<N: Primitive> TryCast<N> for $t where f32: TryCast<N> {
+            #[inline]
+            fn try_cast(self) -> Option<N> {
+                try_cast(self.to_f32())
+            }
+        }
+
+        impl TryPrimitive for $t {
+        }
+
+        impl Primitive for $t {
+        }
+    )*)
+}
+
+half_primitive_impl! { f16 bf16 }
+
+// IMPL NUMBER
+
+/// Implement arithmetic operations, rounding the result of the f32.
+macro_rules! half_ops_impl {
+    ($t:ident, $($op:ident, $meth:ident ;)*) => ($(
+        impl ops::$op for $t {
+            type Output = $t;
+
+            #[inline]
+            fn $meth(self, other: $t) -> $t {
+                $t::from_f32(ops::$op::$meth(self.to_f32(), other.to_f32()))
+            }
+        }
+    )*);
+}
+
+/// Implement arithmetic assignment operations, rounding the result of the f32.
+macro_rules! half_ops_assign_impl {
+    ($t:ident, $($op:ident, $meth:ident ;)*) => ($(
+        impl ops::$op for $t {
+            #[inline]
+            fn $meth(&mut self, other: $t) {
+                let mut value = self.to_f32();
+                value.$meth(other.to_f32());
+                *self = $t::from_f32(value);
+            }
+        }
+    )*);
+}
+
+/// Implement the number traits.
+macro_rules! half_number_impl {
+    ($($t:ident $radix_size:ident $decimal_size:ident ; )*) => ($(
+        half_ops_impl! {
+            $t,
+            Add, add ;
+            Div, div ;
+            Mul, mul ;
+            Rem, rem ;
+            Sub, sub ;
+        }
+
+        half_ops_assign_impl! {
+            $t,
+            AddAssign, add_assign ;
+            DivAssign, div_assign ;
+            MulAssign, mul_assign ;
+            RemAssign, rem_assign ;
+            SubAssign, sub_assign ;
+        }
+
+        impl iter::Product for $t {
+            #[inline]
+            fn product<Iter: Iterator<Item=$t>>(iter: Iter) -> $t {
+                iter.fold(<$t as Float>::ONE, ops::Mul::mul)
+            }
+        }
+
+        impl iter::Sum for $t {
+            #[inline]
+            fn sum<Iter: Iterator<Item=$t>>(iter: Iter) -> $t {
+                iter.fold(<$t as Float>::ZERO, ops::Add::add)
+            }
+        }
+
+        impl Number for $t {
+            const FORMATTED_SIZE: usize = $radix_size;
+            const FORMATTED_SIZE_DECIMAL: usize = $decimal_size;
+        }
+    )*)
+}
+
+half_number_impl! {
+    f16 F16_FORMATTED_SIZE F16_FORMATTED_SIZE_DECIMAL ;
+    bf16 BF16_FORMATTED_SIZE BF16_FORMATTED_SIZE_DECIMAL ;
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f16_narrow_test() {
+        assert_eq!(f16::from_f32(0.0).to_bits(), 0x0000);
+        assert_eq!(f16::from_f32(-0.0).to_bits(), 0x8000);
+        assert_eq!(f16::from_f32(1.0).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f32(-2.0).to_bits(), 0xC000);
+        assert_eq!(f16::from_f32(65504.0).to_bits(), 0x7BFF);
+        assert_eq!(f16::from_f32(0.1).to_bits(), 0x2E66);
+
+        // Denormals.
+        assert_eq!(f16::from_f64(5.960464477539063e-8).to_bits(), 0x0001);
+        assert_eq!(f16::from_f64(6.097555160522461e-5).to_bits(), 0x03FF);
+        assert_eq!(f16::from_f64(6.103515625e-5).to_bits(), 0x0400);
+
+        // Halfway cases round to even.
+        assert_eq!(f16::from_f64(1.0 + 2f64.powi(-11)).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f64(1.0 + 3.0 * 2f64.powi(-11)).to_bits(), 0x3C02);
+        assert_eq!(f16::from_f64(2f64.powi(-25)).to_bits(), 0x0000);
+        assert_eq!(f16::from_f64(3.0 * 2f64.powi(-25)).to_bits(), 0x0002);
+        assert_eq!(f16::from_f64(2f64.powi(-25) + 2f64.powi(-60)).to_bits(), 0x0001);
+        assert_eq!(f16::from_f64(1e-300).to_bits(), 0x0000);
+
+        // Overflow.
+        assert_eq!(f16::from_f32(65519.0).to_bits(), 0x7BFF);
+        assert_eq!(f16::from_f32(65520.0).to_bits(), 0x7C00);
+        assert_eq!(f16::from_f64(-1e300).to_bits(), 0xFC00);
+
+        // Special.
+        assert_eq!(f16::from_f32(f32::INFINITY).to_bits(), 0x7C00);
+        assert_eq!(f16::from_f32(f32::NEG_INFINITY).to_bits(), 0xFC00);
+        assert_eq!(f16::from_f32(f32::NAN).to_bits(), 0x7E00);
+        assert_eq!(f16::from_f64(f64::from_bits(0x7FF0000000000001)).to_bits(), 0x7E00);
+        assert_eq!(f16::from_f64(f64::from_bits(0xFFF4000000000000)).to_bits(), 0xFF00);
+    }
+
+    #[test]
+    fn bf16_narrow_test() {
+        assert_eq!(bf16::from_f32(1.0).to_bits(), 0x3F80);
+        assert_eq!(bf16::from_f32(3.140625).to_bits(), 0x4049);
+        assert_eq!(bf16::from_f32(f32::MAX).to_bits(), 0x7F80);
+        assert_eq!(bf16::from_f32(3.3895314e38).to_bits(), 0x7F7F);
+        assert_eq!(bf16::from_f32(1e-45).to_bits(), 0x0000);
+        assert_eq!(bf16::from_f64(2f64.powi(-133)).to_bits(), 0x0001);
+        assert_eq!(bf16::from_f64(1.0 + 2f64.powi(-8)).to_bits(), 0x3F80);
+        assert_eq!(bf16::from_f64(1.0 + 3.0 * 2f64.powi(-8)).to_bits(), 0x3F82);
+        assert_eq!(bf16::from_f32(f32::NAN).to_bits(), 0x7FC0);
+    }
+
+    #[test]
+    fn widen_test() {
+        assert_eq!(f16::from_bits(0x0001).to_f64(), 2f64.powi(-24));
+        assert_eq!(f16::from_bits(0x8400).to_f64(), -2f64.powi(-14));
+        assert_eq!(f16::from_bits(0x7BFF).to_f32(), 65504.0);
+        assert_eq!(f16::from_bits(0xFC00).to_f32(), f32::NEG_INFINITY);
+        assert_eq!(f16::from_bits(0x7E01).to_f64().to_bits(), 0x7FF8040000000000);
+        assert_eq!(bf16::from_bits(0x0001).to_f64(), 2f64.powi(-133));
+        assert_eq!(bf16::from_bits(0x7F7F).to_f32(), 3.3895314e38);
+        assert!(bf16::from_bits(0x7FC0).to_f32().is_nan());
+
+        // Every finite float round-trips through an f32.
+        for bits in 0..0x7C00u16 {
+            let value = f16::from_bits(bits);
+            assert_eq!(f16::from_f32(value.to_f32()).to_bits(), bits);
+            assert_eq!(f16::from_f32(-value.to_f32()).to_bits(), bits | 0x8000);
+        }
+        for bits in 0..0x7F80u16 {
+            let value = bf16::from_bits(bits);
+            assert_eq!(bf16::from_f32(value.to_f32()).to_bits(), bits);
+        }
+    }
+
+    #[test]
+    fn narrow_midpoint_test() {
+        // Values between 2 floats round to the nearest, and the midpoints
+        // round to even, which is identical to truncating an f32 to a
+        // bfloat16 with round-to-nearest.
+        for bits in 0..0x7F7Fu32 {
+            let lower = f32::from_bits(bits << 16) as f64;
+            let upper = f32::from_bits((bits + 1) << 16) as f64;
+            let halfway = (lower + upper) / 2.0;
+            let even = if bits % 2 == 0 { bits } else { bits + 1 };
+            assert_eq!(bf16::from_f64(halfway).to_bits() as u32, even);
+            assert_eq!(bf16::from_f64(halfway.prev_positive()).to_bits() as u32, bits);
+            assert_eq!(bf16::from_f64(halfway.next_positive()).to_bits() as u32, bits + 1);
+        }
+    }
+
+    #[test]
+    fn float_test() {
+        let x = f16::from_f32(123.0);
+        assert!(x > f16::ONE);
+        assert!(x != f16::ONE);
+        assert_eq!(x + f16::ONE, f16::from_f32(124.0));
+        assert_eq!(x - f16::ONE, f16::from_f32(122.0));
+        assert_eq!(x * f16::TWO, f16::from_f32(246.0));
+        assert_eq!(x / f16::TWO, f16::from_f32(61.5));
+        assert_eq!(x % f16::TWO, f16::ONE);
+        assert_eq!(-x, f16::from_f32(-123.0));
+        assert_eq!(f16::from_f32(1.0) / f16::from_f32(3.0), f16::from_bits(0x3555));
+        assert_eq!((-x).abs(), x);
+        assert_eq!(f16::from_f32(1.5).floor(), f16::ONE);
+        assert_eq!(f16::from_f32(1.5).ceil(), f16::TWO);
+        assert_eq!(f16::TWO.powi(16), f16::INFINITY);
+
+        let mut y = x;
+        y += f16::ONE;
+        y *= f16::TWO;
+        assert_eq!(y, f16::from_f32(248.0));
+        assert_eq!(vec![x, f16::ONE].into_iter().sum::<f16>(), f16::from_f32(124.0));
+
+        assert!(f16::NAN.is_nan());
+        assert!(f16::NAN != f16::NAN);
+        assert!(f16::ZERO == -f16::ZERO);
+        assert!(f16::INFINITY.is_inf());
+        assert!(f16::from_bits(0x0001).is_denormal());
+        assert_eq!(f16::MAX.to_f32(), 65504.0);
+        assert_eq!(f16::MIN.to_f32(), -65504.0);
+        assert_eq!(f16::MAX.next(), f16::INFINITY);
+        assert_eq!(f16::ONE.mantissa(), 0x0400);
+        assert_eq!(f16::ONE.exponent(), -10);
+        assert_eq!(f16::from_bits(0x0001).exponent(), -24);
+        assert_eq!(bf16::ONE.to_f32(), 1.0);
+        assert_eq!(bf16::TWO.to_f32(), 2.0);
+        assert_eq!(bf16::MAX.to_f32(), 3.3895314e38);
+        assert_eq!(bf16::ONE.exponent(), -7);
+        assert_eq!(bf16::from_bits(0x0001).exponent(), -133);
+        assert_eq!(bf16::MAX.exponent(), 120);
+        assert_eq!(bf16::from_f32(2.0) + bf16::from_f32(0.5), bf16::from_f32(2.5));
+    }
+
+    #[test]
+    fn primitive_test() {
+        let x = f16::from_f32(-3.5);
+        assert_eq!(x.as_i32(), -3);
+        assert_eq!(x.as_f64(), -3.5);
+        assert_eq!(f16::as_cast(2049u32), f16::from_f32(2048.0));
+        assert_eq!(f16::as_cast(2051u32), f16::from_f32(2052.0));
+        assert_eq!(bf16::as_cast(257u32), bf16::from_f32(256.0));
+        assert_eq!(f32::from(x), -3.5);
+        assert_eq!(f64::from(bf16::ONE), 1.0);
+        assert_eq!(format!("{}", f16::from_f32(0.5)), "0.5");
+        assert_eq!(format!("{:?}", bf16::from_f32(-2.0)), "-2.0");
+    }
+}
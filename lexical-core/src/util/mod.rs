@@ -22,60 +22,99 @@ pub(crate) mod test;
 #[cfg(has_i128)]
 mod div128;
 
+#[cfg(feature = "format")]
+mod format;
+
+#[cfg(feature = "radix")]
+mod prefix;
+
 // Hide implementation details.
 mod algorithm;
+mod case;
 mod cast;
 pub(crate) mod config;
 pub(crate) mod error;
+mod formatted;
+mod group;
+mod half;
 mod mask;
+mod nan;
+mod notation;
 mod num;
+mod options;
+mod padding;
 mod pointer_methods;
 mod primitive;
 mod pow;
 pub(crate) mod result;
 mod rounding;
 mod sign;
+mod sink;
+mod special;
+mod suffix;
 mod table;
+mod whitespace;
 
-cfg_if! {
-if #[cfg(feature = "correct")] {
-    mod bound;
-    mod range_bounds;
-    mod slice_index;
+mod bound;
+mod range_bounds;
+mod slice_index;
+
+#[macro_use]
+mod sequence;
 
-    #[macro_use]
-    mod sequence;
-} else {
-    mod wrapped;
-}}  // cfg_if
+#[cfg(not(feature = "correct"))]
+mod wrapped;
 
 // Publicly export everything with crate-visibility.
 pub(crate) use self::algorithm::*;
 pub(crate) use self::cast::*;
+pub(crate) use self::formatted::*;
+pub(crate) use self::group::*;
 pub(crate) use self::mask::*;
+pub(crate) use self::nan::*;
+pub(crate) use self::padding::*;
 pub(crate) use self::pointer_methods::*;
 pub(crate) use self::primitive::*;
 pub(crate) use self::pow::*;
 pub(crate) use self::rounding::*;
+pub(crate) use self::sequence::*;
 pub(crate) use self::sign::*;
+pub(crate) use self::sink::*;
+pub(crate) use self::special::*;
+pub(crate) use self::suffix::*;
 pub(crate) use self::table::*;
+pub(crate) use self::whitespace::*;
 
 #[cfg(has_i128)]
 pub(crate) use self::div128::*;
 
-cfg_if! {
-if #[cfg(feature = "correct")] {
-    pub(crate) use self::sequence::*;
-} else {
-    pub(crate) use self::wrapped::*;
-}}  // cfg_if
+#[cfg(feature = "radix")]
+pub(crate) use self::prefix::*;
+
+#[cfg(not(feature = "correct"))]
+pub(crate) use self::wrapped::*;
 
 // Publicly export config globally.
+pub use self::case::LetterCase;
 pub use self::config::*;
 pub use self::error::*;
+pub use self::formatted::{FormattedBuffer, FormattedNumber};
+pub use self::half::{bf16, f16};
+pub use self::notation::{Notation, TrailingZero};
 pub use self::num::*;
+pub use self::options::*;
+pub use self::padding::Alignment;
 pub use self::result::*;
+pub use self::special::{SpecialAlias, SpecialKind};
+pub use self::suffix::{SuffixType, TypeSuffix};
 pub use self::traits::*;
+pub use self::whitespace::{Whitespace, WhitespaceSet};
+
+#[cfg(feature = "format")]
+pub use self::format::*;
+
+#[cfg(feature = "radix")]
+pub use self::prefix::BasePrefix;
 
 #[cfg(feature = "rounding")]
 pub use self::rounding::RoundingKind;
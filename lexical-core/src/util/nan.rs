@@ -0,0 +1,78 @@
+//! NaN payloads for parsing and writing special values.
+//!
+//! NaN payloads are the significant bits below the quiet bit, which
+//! is set for quiet NaNs and unset for signaling NaNs. Signaling NaNs
+//! must have a non-zero payload, otherwise they would be infinity.
+
+use super::cast::as_cast;
+use super::num::Float;
+
+// Get the quiet bit, the most significant bit of the mantissa.
+perftools_inline!{
+fn quiet_bit<F: Float>() -> u64 {
+    let mantissa_mask: u64 = as_cast(F::MANTISSA_MASK);
+    (mantissa_mask >> 1) + 1
+}}
+
+/// Get the mask for the NaN payload bits.
+#[inline]
+pub(crate) fn nan_payload_mask<F: Float>() -> u64 {
+    quiet_bit::<F>() - 1
+}
+
+/// Get the payload for a signaling NaN without an explicit payload.
+///
+/// Uses the most significant payload bit, like `__builtin_nans("")`.
+#[inline]
+pub(crate) fn default_signaling_payload<F: Float>() -> u64 {
+    quiet_bit::<F>() >> 1
+}
+
+/// Create a positive NaN from the signaling flag and payload.
+///
+/// Returns `None` if the payload does not fit in the payload bits,
+/// or a signaling NaN has a zero payload.
+#[inline]
+pub(crate) fn nan_from_payload<F: Float>(is_signaling: bool, payload: u64) -> Option<F> {
+    if payload & !nan_payload_mask::<F>() != 0 || (is_signaling && payload == 0) {
+        return None;
+    }
+    let exponent: u64 = as_cast(F::EXPONENT_MASK);
+    let quiet = if is_signaling { 0 } else { quiet_bit::<F>() };
+    Some(F::from_bits(as_cast(exponent | quiet | payload)))
+}
+
+/// Get the signaling flag and payload of a NaN.
+#[inline]
+pub(crate) fn nan_to_payload<F: Float>(value: F) -> (bool, u64) {
+    debug_assert!(value.is_nan());
+    let bits: u64 = as_cast(value.to_bits());
+    (bits & quiet_bit::<F>() == 0, bits & nan_payload_mask::<F>())
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nan_payload_test() {
+        assert_eq!(nan_payload_mask::<f32>(), 0x3FFFFF);
+        assert_eq!(nan_payload_mask::<f64>(), 0x7FFFFFFFFFFFF);
+        assert_eq!(default_signaling_payload::<f64>(), 0x4000000000000);
+
+        let quiet: f64 = nan_from_payload(false, 0).unwrap();
+        assert_eq!(quiet.to_bits(), f64::NAN.to_bits());
+        let value: f64 = nan_from_payload(false, 0x1234).unwrap();
+        assert_eq!(value.to_bits(), 0x7FF8000000001234);
+        assert_eq!(nan_to_payload(value), (false, 0x1234));
+        let value: f32 = nan_from_payload(true, 1).unwrap();
+        assert_eq!(value.to_bits(), 0x7F800001);
+        assert_eq!(nan_to_payload(value), (true, 1));
+
+        assert_eq!(nan_from_payload::<f32>(true, 0), None);
+        assert_eq!(nan_from_payload::<f32>(false, 0x400000), None);
+    }
+}
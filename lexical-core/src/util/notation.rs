@@ -0,0 +1,28 @@
+//! Notation identifiers for float writers.
+
+/// Notation for writing decimal floats.
+///
+/// Unlike the notation chosen by the decimal backend, which differs
+/// between `ryu`, `grisu3` and the default writer, every notation is
+/// written the same way with any backend.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Notation {
+    /// Always write a decimal exponent, such as `1.5e3`.
+    Scientific,
+    /// Never write a decimal exponent, such as `1500.0`.
+    Positional,
+    /// Write a decimal exponent outside of the exponent limits.
+    Auto,
+}
+
+/// Fraction written for floats without fraction digits.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TrailingZero {
+    /// Write the fraction chosen by the float writer, such as `1.0` and
+    /// `1e21`, or trim it with the `trim_floats` feature, such as `1`.
+    Default,
+    /// Never write a `.0` fraction, such as `1` and `1e21`.
+    Trim,
+    /// Always write a `.0` fraction, such as `1.0` and `1.0e21`.
+    Force,
+}
@@ -31,8 +31,10 @@ pub trait Number:
     ops::SubAssign
 {
     /// Maximum number of bytes required to serialize a number to string.
+    #[deprecated(note = "use `ToLexical::FORMATTED_SIZE`")]
     const FORMATTED_SIZE: usize;
     /// Maximum number of bytes required to serialize a number to a decimal string.
+    #[deprecated(note = "use `ToLexical::FORMATTED_SIZE_DECIMAL`")]
     const FORMATTED_SIZE_DECIMAL: usize;
 }
 
@@ -1116,6 +1118,15 @@ mod tests {
         check_number(1f64, 5.0);
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn number_formatted_size_test() {
+        assert_eq!(u8::FORMATTED_SIZE, U8_FORMATTED_SIZE);
+        assert_eq!(i64::FORMATTED_SIZE_DECIMAL, I64_FORMATTED_SIZE_DECIMAL);
+        assert_eq!(f64::FORMATTED_SIZE, F64_FORMATTED_SIZE);
+        assert_eq!(f64::FORMATTED_SIZE_DECIMAL, F64_FORMATTED_SIZE_DECIMAL);
+    }
+
     fn check_integer<T: Integer>(mut x: T) {
         // Copy, partialeq, partialord, ord, eq
         let _ = x;
@@ -0,0 +1,2391 @@
+//! Per-call options for lexical-core.
+//!
+//! Unlike the global configuration in `config`, options are passed
+//! explicitly to each parser or writer, and therefore are thread-safe.
+//! The global configuration is only used to initialize the defaults.
+
+use lib::cmp;
+use super::case::LetterCase;
+use super::config::*;
+use super::group::group_separator_count;
+use super::notation::{Notation, TrailingZero};
+use super::num::{Float, Integer};
+#[cfg(feature = "format")]
+use super::format::{is_valid_separator, NumberFormat};
+use super::padding::Alignment;
+#[cfg(feature = "radix")]
+use super::prefix::BasePrefix;
+use super::primitive::AsPrimitive;
+use super::rounding::RoundingKind;
+use super::special::SpecialAlias;
+use super::suffix::TypeSuffix;
+use super::traits::ToLexical;
+use super::whitespace::{Whitespace, WhitespaceSet};
+
+// Maximum number of bytes written for a NaN besides the NaN string:
+// a sign, a signaling prefix, and a hexadecimal payload, like `(0x1234)`.
+const NAN_PAYLOAD_SIZE: usize = 19;
+
+// PARSE OPTIONS
+
+/// Options to customize how numbers are parsed.
+///
+/// Options are created from the current global configuration, and may
+/// then be modified without affecting any other parsers.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ParseOptions;
+/// # pub fn main() {
+/// let mut options = ParseOptions::new();
+/// options.set_nan_string(b"nan");
+/// let value: f64 = lexical_core::parse_with_options(b"nan", &options).unwrap();
+/// assert!(value.is_nan());
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseOptions {
+    /// Radix for number parsing.
+    pub(crate) radix: u32,
+    /// Default character for the exponent symbol.
+    pub(crate) exponent_default_char: u8,
+    /// Backup character for the exponent symbol.
+    #[cfg(feature = "radix")]
+    pub(crate) exponent_backup_char: u8,
+    /// Character separating the integer and fraction digits.
+    pub(crate) decimal_point: u8,
+    /// Character separating groups of integer digits, skipped during parsing.
+    #[cfg(feature = "format")]
+    pub(crate) group_separator: u8,
+    /// Parse floats as C99 hexadecimal floats.
+    #[cfg(feature = "radix")]
+    pub(crate) hex_float: bool,
+    /// Handling of base prefixes, such as `0x`.
+    #[cfg(feature = "radix")]
+    pub(crate) base_prefix: BasePrefix,
+    /// Only match lowercase base prefixes.
+    #[cfg(feature = "radix")]
+    pub(crate) prefix_case_sensitive: bool,
+    /// Handling of whitespace surrounding the number.
+    pub(crate) whitespace: Whitespace,
+    /// Characters considered whitespace.
+    pub(crate) whitespace_set: WhitespaceSet,
+    /// Rounding scheme for float parsing.
+    #[cfg(feature = "rounding")]
+    pub(crate) rounding: RoundingKind,
+    /// Number format specification.
+    #[cfg(feature = "format")]
+    pub(crate) format: NumberFormat,
+    /// Parse NaN payloads and signaling NaNs, such as `nan(0x1234)`.
+    pub(crate) nan_payloads: bool,
+    /// Not a Number literal.
+    pub(crate) nan_string: &'static [u8],
+    /// Short infinity literal.
+    pub(crate) inf_string: &'static [u8],
+    /// Long infinity literal.
+    pub(crate) infinity_string: &'static [u8],
+    /// Alternative spellings of special values, such as `1.#INF`.
+    pub(crate) special_aliases: &'static [SpecialAlias],
+    /// Only match special values with the exact case.
+    pub(crate) special_case_sensitive: bool,
+    /// Reject all special values, such as NaN and infinity.
+    pub(crate) no_special: bool,
+    /// Literal type suffixes, such as `u32`.
+    pub(crate) type_suffixes: &'static [TypeSuffix],
+}
+
+impl ParseOptions {
+    /// Create new options from the current global configuration.
+    #[inline]
+    pub fn new() -> ParseOptions {
+        ParseOptions {
+            radix: 10,
+            exponent_default_char: get_exponent_default_char(),
+            #[cfg(feature = "radix")]
+            exponent_backup_char: get_exponent_backup_char(),
+            decimal_point: b'.',
+            #[cfg(feature = "format")]
+            group_separator: 0,
+            #[cfg(feature = "radix")]
+            hex_float: false,
+            #[cfg(feature = "radix")]
+            base_prefix: BasePrefix::Disabled,
+            #[cfg(feature = "radix")]
+            prefix_case_sensitive: false,
+            whitespace: Whitespace::Disabled,
+            whitespace_set: WhitespaceSet::Ascii,
+            #[cfg(feature = "rounding")]
+            rounding: get_float_rounding(),
+            #[cfg(feature = "format")]
+            format: NumberFormat::default(),
+            nan_payloads: false,
+            nan_string: get_nan_string(),
+            inf_string: get_inf_string(),
+            infinity_string: get_infinity_string(),
+            special_aliases: &[],
+            special_case_sensitive: false,
+            no_special: false,
+            type_suffixes: &[],
+        }
+    }
+
+    /// Create new options from the current global configuration with a custom radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(radix: u8) -> ParseOptions {
+        let mut options = ParseOptions::new();
+        options.set_radix(radix);
+        options
+    }
+
+    /// Create new options from the current global configuration to parse C99 hexadecimal floats.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::ParseOptions;
+    /// # pub fn main() {
+    /// let options = ParseOptions::hex_float();
+    /// let value: f64 = lexical_core::parse_with_options(b"0x1.8p+3", &options).unwrap();
+    /// assert_eq!(value, 12.0);
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn hex_float() -> ParseOptions {
+        let mut options = ParseOptions::new();
+        options.set_hex_float(true);
+        options
+    }
+
+    /// Create new options from the current global configuration with a number format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ErrorCode, NumberFormat, ParseOptions};
+    /// # pub fn main() {
+    /// let options = ParseOptions::format(NumberFormat::JSON);
+    /// let value: f64 = lexical_core::parse_with_options(b"1.5e3", &options).unwrap();
+    /// assert_eq!(value, 1500.0);
+    /// let err = lexical_core::parse_with_options::<f64>(b"+1.5", &options).err().unwrap();
+    /// assert_eq!(err.code, ErrorCode::InvalidPositiveMantissaSign);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digit separator is the same as the default or
+    /// backup character for the exponent symbol.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(format: NumberFormat) -> ParseOptions {
+        let mut options = ParseOptions::new();
+        options.set_format(format);
+        options
+    }
+
+    /// Get the radix for number parsing.
+    #[inline]
+    pub fn get_radix(&self) -> u8 {
+        self.radix as u8
+    }
+
+    /// Set the radix for number parsing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn set_radix(&mut self, radix: u8) {
+        assert_radix!(radix);
+        self.radix = radix as u32;
+    }
+
+    /// Get the default character for the exponent symbol.
+    ///
+    /// Default character for scientific notation, used when the `radix < 15`.
+    #[inline]
+    pub fn get_exponent_default_char(&self) -> u8 {
+        self.exponent_default_char
+    }
+
+    /// Set the default character for the exponent symbol.
+    ///
+    /// Default character for scientific notation, used when the `radix < 15`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is in the character set `[A-Da-d.+\-]`,
+    /// or is the decimal point.
+    #[inline]
+    pub fn set_exponent_default_char(&mut self, ch: u8) {
+        assert!(!is_control_character(ch, true) && ch != self.decimal_point);
+        self.exponent_default_char = ch;
+    }
+
+    /// Get the backup character for the exponent symbol.
+    ///
+    /// For numerical strings of `radix >= 15`, 'e' or 'E' is a valid digit,
+    /// and therefore may no longer be used as a marker for the exponent.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn get_exponent_backup_char(&self) -> u8 {
+        self.exponent_backup_char
+    }
+
+    /// Set the backup character for the exponent symbol.
+    ///
+    /// For numerical strings of `radix >= 15`, 'e' or 'E' is a valid digit,
+    /// and therefore may no longer be used as a marker for the exponent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is in the character set `[A-Za-z.+\-]`,
+    /// or is the decimal point.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn set_exponent_backup_char(&mut self, ch: u8) {
+        assert!(!is_control_character(ch, false) && ch != self.decimal_point);
+        self.exponent_backup_char = ch;
+    }
+
+    /// Get the character separating the integer and fraction digits.
+    #[inline]
+    pub fn get_decimal_point(&self) -> u8 {
+        self.decimal_point
+    }
+
+    /// Set the character separating the integer and fraction digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::ParseOptions;
+    /// # pub fn main() {
+    /// let mut options = ParseOptions::new();
+    /// options.set_decimal_point(b',');
+    /// assert_eq!(lexical_core::parse_with_options::<f64>(b"3,14", &options), Ok(3.14));
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not a printable ASCII character, is
+    /// in the character set `[A-Za-z0-9+\-]`, or is the exponent
+    /// character, the digit separator, or the group separator.
+    #[inline]
+    pub fn set_decimal_point(&mut self, ch: u8) {
+        assert!(is_valid_decimal_point(ch));
+        assert!(ch != self.exponent_default_char && ch != self.exponent_backup_char());
+        #[cfg(feature = "format")] {
+            assert!(ch != self.format.digit_separator() && ch != self.group_separator);
+        }
+        self.decimal_point = ch;
+    }
+
+    /// Get the character separating groups of integer digits.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn get_group_separator(&self) -> u8 {
+        self.group_separator
+    }
+
+    /// Set the character separating groups of integer digits, or `0` to disable it.
+    ///
+    /// Group separators, like the `.` in `1.234,56`, are skipped when
+    /// parsing integer digits, and may only occur between two digits.
+    /// The group separator replaces the digit separator from the number
+    /// format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::ParseOptions;
+    /// # pub fn main() {
+    /// let mut options = ParseOptions::new();
+    /// options.set_decimal_point(b',');
+    /// options.set_group_separator(b'.');
+    /// assert_eq!(lexical_core::parse_with_options::<f64>(b"1.234,56", &options), Ok(1234.56));
+    /// assert_eq!(lexical_core::parse_with_options::<i32>(b"-1.234.567", &options), Ok(-1234567));
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not a valid digit separator or `.`,
+    /// or is the decimal point or exponent character.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn set_group_separator(&mut self, ch: u8) {
+        assert!(ch == 0 || ch == b'.' || is_valid_separator(ch));
+        assert!(ch == 0 || ch != self.decimal_point);
+        assert!(ch == 0 || (ch != self.exponent_default_char && ch != self.exponent_backup_char()));
+        self.group_separator = ch;
+    }
+
+
+    /// Get if floats are parsed as C99 hexadecimal floats.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn get_hex_float(&self) -> bool {
+        self.hex_float
+    }
+
+    /// Set if floats are parsed as C99 hexadecimal floats.
+    ///
+    /// Hexadecimal floats, like `0x1.8p+3`, require a `0x` or `0X` prefix,
+    /// followed by hexadecimal digits and an optional binary exponent,
+    /// written in decimal after a `p` or `P`, like the input for `strtod`.
+    /// The radix and exponent characters are ignored for floats while
+    /// enabled, and integers are unaffected.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn set_hex_float(&mut self, hex_float: bool) {
+        self.hex_float = hex_float;
+    }
+
+    /// Get the handling of base prefixes, such as `0x`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn get_base_prefix(&self) -> BasePrefix {
+        self.base_prefix
+    }
+
+    /// Set the handling of base prefixes, such as `0x`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{BasePrefix, ParseOptions};
+    /// # pub fn main() {
+    /// let mut options = ParseOptions::new();
+    /// options.set_base_prefix(BasePrefix::Detect);
+    /// assert_eq!(lexical_core::parse_with_options::<i32>(b"0x1F", &options), Ok(31));
+    /// assert_eq!(lexical_core::parse_with_options::<i32>(b"-0b1010", &options), Ok(-10));
+    /// assert_eq!(lexical_core::parse_with_options::<i32>(b"017", &options), Ok(15));
+    /// assert_eq!(lexical_core::parse_with_options::<i32>(b"17", &options), Ok(17));
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn set_base_prefix(&mut self, base_prefix: BasePrefix) {
+        self.base_prefix = base_prefix;
+    }
+
+    /// Get if base prefixes must be lowercase.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn get_prefix_case_sensitive(&self) -> bool {
+        self.prefix_case_sensitive
+    }
+
+    /// Set if base prefixes must be lowercase.
+    ///
+    /// If case-sensitive, only `0x`, `0o` and `0b` are base prefixes,
+    /// otherwise, `0X`, `0O` and `0B` are also base prefixes.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn set_prefix_case_sensitive(&mut self, case_sensitive: bool) {
+        self.prefix_case_sensitive = case_sensitive;
+    }
+
+    /// Get the handling of whitespace surrounding the number.
+    #[inline]
+    pub fn get_whitespace(&self) -> Whitespace {
+        self.whitespace
+    }
+
+    /// Set the handling of whitespace surrounding the number.
+    ///
+    /// Skipped whitespace is counted as processed by the partial parsers,
+    /// and error indexes refer to positions in the original input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ErrorCode, ParseOptions, Whitespace};
+    /// # pub fn main() {
+    /// let mut options = ParseOptions::new();
+    /// options.set_whitespace(Whitespace::Both);
+    /// assert_eq!(lexical_core::parse_with_options::<i32>(b" 42\n", &options), Ok(42));
+    /// let error = lexical_core::parse_with_options::<f64>(b"  1.5x", &options).unwrap_err();
+    /// assert_eq!((error.code, error.index), (ErrorCode::InvalidDigit, 5));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_whitespace(&mut self, whitespace: Whitespace) {
+        self.whitespace = whitespace;
+    }
+
+    /// Get the characters considered whitespace.
+    #[inline]
+    pub fn get_whitespace_set(&self) -> WhitespaceSet {
+        self.whitespace_set
+    }
+
+    /// Set the characters considered whitespace.
+    ///
+    /// Whitespace is only skipped if enabled by `set_whitespace`.
+    #[inline]
+    pub fn set_whitespace_set(&mut self, set: WhitespaceSet) {
+        self.whitespace_set = set;
+    }
+
+    /// Get the rounding scheme for float parsing.
+    #[inline]
+    #[cfg(feature = "rounding")]
+    pub fn get_rounding(&self) -> RoundingKind {
+        self.rounding
+    }
+
+    /// Set the rounding scheme for float parsing.
+    #[inline]
+    #[cfg(feature = "rounding")]
+    pub fn set_rounding(&mut self, rounding: RoundingKind) {
+        self.rounding = rounding;
+    }
+
+    /// Get the number format specification.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn get_format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Set the number format specification.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digit separator is the same as the default or
+    /// backup character for the exponent symbol, or the decimal point.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn set_format(&mut self, format: NumberFormat) {
+        let separator = format.digit_separator();
+        assert!(separator == 0 || separator != self.exponent_default_char);
+        assert!(separator == 0 || separator != self.exponent_backup_char());
+        assert!(separator == 0 || separator != self.decimal_point);
+        self.format = format;
+    }
+
+    /// Get if NaN payloads and signaling NaNs are parsed.
+    #[inline]
+    pub fn get_nan_payloads(&self) -> bool {
+        self.nan_payloads
+    }
+
+    /// Set if NaN payloads and signaling NaNs are parsed.
+    ///
+    /// NaN payloads use the C99 `strtod` syntax, such as `nan(0x1234)`,
+    /// where the payload is a hexadecimal, octal or decimal integer
+    /// stored in the significant bits below the quiet bit. Signaling
+    /// NaNs have an `s` prefix, such as `snan` or `snan(1)`. The sign
+    /// of the NaN is always preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::ParseOptions;
+    /// # pub fn main() {
+    /// let mut options = ParseOptions::new();
+    /// options.set_nan_payloads(true);
+    /// let value: f64 = lexical_core::parse_with_options(b"-nan(0x1234)", &options).unwrap();
+    /// assert_eq!(value.to_bits(), 0xFFF8000000001234);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_nan_payloads(&mut self, nan_payloads: bool) {
+        self.nan_payloads = nan_payloads;
+    }
+
+    /// Get the string representation of Not a Number.
+    #[inline]
+    pub fn get_nan_string(&self) -> &'static [u8] {
+        self.nan_string
+    }
+
+    /// Set the string representation of Not a Number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `bytes.len() >= f32::FORMATTED_SIZE`
+    /// - `bytes` is empty
+    /// - `bytes` does not start with an `'N'` or `'n'`.
+    #[inline]
+    pub fn set_nan_string(&mut self, bytes: &'static [u8]) {
+        assert!(starts_with_n(bytes) && bytes.len() < F32_FORMATTED_SIZE);
+        self.nan_string = bytes;
+    }
+
+    /// Get the short representation of Infinity.
+    #[inline]
+    pub fn get_inf_string(&self) -> &'static [u8] {
+        self.inf_string
+    }
+
+    /// Set the short representation of Infinity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `bytes.len() >= f32::FORMATTED_SIZE`
+    /// - `bytes.len() > self.get_infinity_string().len()`
+    /// - `bytes` is empty
+    /// - `bytes` does not start with an `'I'` or `'i'`.
+    #[inline]
+    pub fn set_inf_string(&mut self, bytes: &'static [u8]) {
+        assert!(starts_with_i(bytes) && bytes.len() <= self.infinity_string.len());
+        self.inf_string = bytes;
+    }
+
+    /// Get the long representation of Infinity.
+    #[inline]
+    pub fn get_infinity_string(&self) -> &'static [u8] {
+        self.infinity_string
+    }
+
+    /// Set the long representation of Infinity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `bytes.len() >= f32::FORMATTED_SIZE`
+    /// - `bytes.len() < self.get_inf_string().len()`
+    /// - `bytes` is empty
+    /// - `bytes` does not start with an `'I'` or `'i'`.
+    #[inline]
+    pub fn set_infinity_string(&mut self, bytes: &'static [u8]) {
+        assert!(starts_with_i(bytes) && bytes.len() < F32_FORMATTED_SIZE);
+        assert!(bytes.len() >= self.inf_string.len());
+        self.infinity_string = bytes;
+    }
+
+    /// Get the alternative spellings of special values.
+    #[inline]
+    pub fn get_special_aliases(&self) -> &'static [SpecialAlias] {
+        self.special_aliases
+    }
+
+    /// Set the alternative spellings of special values, such as `1.#INF`.
+    ///
+    /// Aliases are parsed in addition to the NaN and infinity strings,
+    /// and may start with any character, such as `1.#QNAN` or `∞`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ParseOptions, SpecialAlias};
+    /// # pub fn main() {
+    /// let mut options = ParseOptions::new();
+    /// options.set_special_aliases(SpecialAlias::MSVC);
+    /// let value: f64 = lexical_core::parse_with_options(b"-1.#INF", &options).unwrap();
+    /// assert_eq!(value, -f64::INFINITY);
+    /// let value: f64 = lexical_core::parse_with_options(b"-1.#IND", &options).unwrap();
+    /// assert!(value.is_nan());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_special_aliases(&mut self, aliases: &'static [SpecialAlias]) {
+        self.special_aliases = aliases;
+    }
+
+    /// Get if special values only match with the exact case.
+    #[inline]
+    pub fn get_special_case_sensitive(&self) -> bool {
+        self.special_case_sensitive
+    }
+
+    /// Set if special values only match with the exact case.
+    ///
+    /// Applies to the NaN and infinity strings, and the special aliases.
+    /// By default, special values are case-insensitive.
+    #[inline]
+    pub fn set_special_case_sensitive(&mut self, case_sensitive: bool) {
+        self.special_case_sensitive = case_sensitive;
+    }
+
+    /// Get if all special values are rejected.
+    #[inline]
+    pub fn get_no_special(&self) -> bool {
+        self.no_special
+    }
+
+    /// Set if all special values are rejected.
+    ///
+    /// Special values, including the special aliases, return an
+    /// `InvalidSpecial` error, like for formats that forbid them, such
+    /// as JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ErrorCode, ParseOptions};
+    /// # pub fn main() {
+    /// let mut options = ParseOptions::new();
+    /// options.set_no_special(true);
+    /// let result = lexical_core::parse_with_options::<f64>(b"-inf", &options);
+    /// assert_eq!(result.map_err(|e| (e.code, e.index)), Err((ErrorCode::InvalidSpecial, 1)));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_no_special(&mut self, no_special: bool) {
+        self.no_special = no_special;
+    }
+
+    /// Get the literal type suffixes.
+    #[inline]
+    pub fn get_type_suffixes(&self) -> &'static [TypeSuffix] {
+        self.type_suffixes
+    }
+
+    /// Set the literal type suffixes, such as `u32` in `10u32`.
+    ///
+    /// Type suffixes are only used by [`parse_partial_with_suffix`].
+    ///
+    /// [`parse_partial_with_suffix`]: fn.parse_partial_with_suffix.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ParseOptions, TypeSuffix};
+    /// # pub fn main() {
+    /// let mut options = ParseOptions::new();
+    /// options.set_type_suffixes(TypeSuffix::JAVA);
+    /// let (value, count, suffix) = lexical_core::parse_partial_with_suffix::<i64>(b"100L", &options).unwrap();
+    /// assert_eq!((value, count), (100, 4));
+    /// assert_eq!(suffix.unwrap().suffix, b"L");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_type_suffixes(&mut self, suffixes: &'static [TypeSuffix]) {
+        self.type_suffixes = suffixes;
+    }
+
+    /// Get the exponent character for the current radix.
+    #[inline]
+    pub(crate) fn exponent_char(&self) -> u8 {
+        #[cfg(feature = "radix")] {
+            if self.hex_float {
+                return b'p';
+            }
+        }
+
+        exponent_notation_char(self.radix, self.exponent_default_char, self.exponent_backup_char())
+    }
+
+    /// Get the radix for the exponent digits.
+    #[inline]
+    pub(crate) fn exponent_radix(&self) -> u32 {
+        #[cfg(feature = "radix")] {
+            if self.hex_float {
+                return 10;
+            }
+        }
+
+        self.radix
+    }
+
+    /// Get the number format, with the group separator as a digit separator.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub(crate) fn number_format(&self) -> NumberFormat {
+        match self.group_separator {
+            0 => self.format,
+            c => self.format.with_group_separator(c),
+        }
+    }
+
+    /// Get the backup character, or the default character without radix support.
+    #[inline]
+    fn exponent_backup_char(&self) -> u8 {
+        #[cfg(feature = "radix")] {
+            self.exponent_backup_char
+        }
+
+        #[cfg(not(feature = "radix"))] {
+            self.exponent_default_char
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> ParseOptions {
+        ParseOptions::new()
+    }
+}
+
+// WRITE OPTIONS
+
+/// Options to customize how numbers are written.
+///
+/// Options are created from the current global configuration, and may
+/// then be modified without affecting any other writers.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ToLexical, WriteOptions};
+/// # pub fn main() {
+/// let mut options = WriteOptions::new();
+/// options.set_inf_string(b"Infinity");
+/// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+/// let bytes = lexical_core::write_with_options(f64::INFINITY, &options, &mut buffer);
+/// assert_eq!(bytes, b"Infinity");
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WriteOptions {
+    /// Radix for number encoding.
+    pub(crate) radix: u32,
+    /// Default character for the exponent symbol.
+    pub(crate) exponent_default_char: u8,
+    /// Backup character for the exponent symbol.
+    #[cfg(feature = "radix")]
+    pub(crate) exponent_backup_char: u8,
+    /// Character separating the integer and fraction digits.
+    pub(crate) decimal_point: u8,
+    /// Character separating groups of integer digits, or `0` to disable grouping.
+    pub(crate) group_separator: u8,
+    /// Number of digits in the group adjacent to the decimal point.
+    pub(crate) group_size: u8,
+    /// Number of digits in every other group.
+    pub(crate) secondary_group_size: u8,
+    /// Write floats as C99 hexadecimal floats.
+    #[cfg(feature = "radix")]
+    pub(crate) hex_float: bool,
+    /// Number of digits written after the decimal point, if fixed.
+    pub(crate) fixed_precision: Option<usize>,
+    /// Notation for decimal floats, or `None` for the backend notation.
+    pub(crate) notation: Option<Notation>,
+    /// Smallest decimal exponent written positionally in automatic notation.
+    pub(crate) min_exponent: i32,
+    /// Smallest decimal exponent written scientifically in automatic notation.
+    pub(crate) max_exponent: i32,
+    /// Minimum number of significant digits for decimal floats.
+    pub(crate) min_significant_digits: Option<usize>,
+    /// Maximum number of significant digits for decimal floats.
+    pub(crate) max_significant_digits: Option<usize>,
+    /// Rounding scheme for fixed-precision and significant-digit floats.
+    #[cfg(feature = "rounding")]
+    pub(crate) rounding: RoundingKind,
+    /// Write a `+` sign before positive floats.
+    pub(crate) positive_sign: bool,
+    /// Write a `+` sign before positive exponents.
+    pub(crate) exponent_sign: bool,
+    /// Minimum number of exponent digits, padded with leading zeros.
+    pub(crate) min_exponent_digits: usize,
+    /// Letter case of the exponent character.
+    pub(crate) exponent_case: LetterCase,
+    /// Letter case of the NaN and infinity strings.
+    pub(crate) special_case: LetterCase,
+    /// Fraction written for floats without fraction digits.
+    pub(crate) trailing_zero: TrailingZero,
+    /// Write negative zero with a `-` sign.
+    pub(crate) signed_zero: bool,
+    /// Minimum width of written numbers, in bytes.
+    pub(crate) width: usize,
+    /// Character written to pad numbers to the minimum width.
+    pub(crate) fill: u8,
+    /// Alignment of numbers within the minimum width.
+    pub(crate) alignment: Alignment,
+    /// Pad numbers with zeros after the sign.
+    pub(crate) zero_pad: bool,
+    /// Write NaN payloads and signaling NaNs, such as `nan(0x1234)`.
+    pub(crate) nan_payloads: bool,
+    /// Not a Number literal.
+    pub(crate) nan_string: &'static [u8],
+    /// Short infinity literal.
+    pub(crate) inf_string: &'static [u8],
+}
+
+impl WriteOptions {
+    /// Create new options from the current global configuration.
+    #[inline]
+    pub fn new() -> WriteOptions {
+        WriteOptions {
+            radix: 10,
+            exponent_default_char: get_exponent_default_char(),
+            #[cfg(feature = "radix")]
+            exponent_backup_char: get_exponent_backup_char(),
+            decimal_point: b'.',
+            group_separator: 0,
+            group_size: 3,
+            secondary_group_size: 3,
+            #[cfg(feature = "radix")]
+            hex_float: false,
+            fixed_precision: None,
+            notation: None,
+            min_exponent: -5,
+            max_exponent: 21,
+            min_significant_digits: None,
+            max_significant_digits: None,
+            #[cfg(feature = "rounding")]
+            rounding: RoundingKind::NearestTieEven,
+            positive_sign: false,
+            exponent_sign: false,
+            min_exponent_digits: 0,
+            exponent_case: LetterCase::Preserve,
+            special_case: LetterCase::Preserve,
+            trailing_zero: TrailingZero::Default,
+            signed_zero: !cfg!(feature = "trim_floats"),
+            width: 0,
+            fill: b' ',
+            alignment: Alignment::Right,
+            zero_pad: false,
+            nan_payloads: false,
+            nan_string: get_nan_string(),
+            inf_string: get_inf_string(),
+        }
+    }
+
+    /// Create new options from the current global configuration with a custom radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(radix: u8) -> WriteOptions {
+        let mut options = WriteOptions::new();
+        options.set_radix(radix);
+        options
+    }
+
+    /// Create new options from the current global configuration to write C99 hexadecimal floats.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let options = WriteOptions::hex_float();
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// let bytes = lexical_core::write_with_options(12.0f64, &options, &mut buffer);
+    /// assert_eq!(bytes, b"0x1.8p+3");
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn hex_float() -> WriteOptions {
+        let mut options = WriteOptions::new();
+        options.set_hex_float(true);
+        options
+    }
+
+    /// Get the radix for number encoding.
+    #[inline]
+    pub fn get_radix(&self) -> u8 {
+        self.radix as u8
+    }
+
+    /// Set the radix for number encoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn set_radix(&mut self, radix: u8) {
+        assert_radix!(radix);
+        self.radix = radix as u32;
+    }
+
+    /// Get the default character for the exponent symbol.
+    ///
+    /// Default character for scientific notation, used when the `radix < 15`.
+    #[inline]
+    pub fn get_exponent_default_char(&self) -> u8 {
+        self.exponent_default_char
+    }
+
+    /// Set the default character for the exponent symbol.
+    ///
+    /// Default character for scientific notation, used when the `radix < 15`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not ASCII, is in the character set
+    /// `[A-Da-d.+\-]`, or is the decimal point or group separator.
+    #[inline]
+    pub fn set_exponent_default_char(&mut self, ch: u8) {
+        assert!(ch.is_ascii() && !is_control_character(ch, true));
+        assert!(ch != self.decimal_point && ch != self.group_separator);
+        self.exponent_default_char = ch;
+    }
+
+    /// Get the backup character for the exponent symbol.
+    ///
+    /// For numerical strings of `radix >= 15`, 'e' or 'E' is a valid digit,
+    /// and therefore may no longer be used as a marker for the exponent.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn get_exponent_backup_char(&self) -> u8 {
+        self.exponent_backup_char
+    }
+
+    /// Set the backup character for the exponent symbol.
+    ///
+    /// For numerical strings of `radix >= 15`, 'e' or 'E' is a valid digit,
+    /// and therefore may no longer be used as a marker for the exponent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not ASCII, is in the character set
+    /// `[A-Za-z.+\-]`, or is the decimal point or group separator.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn set_exponent_backup_char(&mut self, ch: u8) {
+        assert!(ch.is_ascii() && !is_control_character(ch, false));
+        assert!(ch != self.decimal_point && ch != self.group_separator);
+        self.exponent_backup_char = ch;
+    }
+
+    /// Get the character separating the integer and fraction digits.
+    #[inline]
+    pub fn get_decimal_point(&self) -> u8 {
+        self.decimal_point
+    }
+
+    /// Set the character separating the integer and fraction digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_decimal_point(b',');
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(3.14f64, &options, &mut buffer), b"3,14");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not a printable ASCII character, is
+    /// in the character set `[A-Za-z0-9+\-]`, or is the exponent
+    /// character or group separator.
+    #[inline]
+    pub fn set_decimal_point(&mut self, ch: u8) {
+        assert!(is_valid_decimal_point(ch) && ch != self.group_separator);
+        assert!(ch != self.exponent_default_char && ch != self.exponent_backup_char());
+        self.decimal_point = ch;
+    }
+
+    /// Get the character separating groups of integer digits.
+    ///
+    /// A value of `0` means integer digits are not grouped.
+    #[inline]
+    pub fn get_group_separator(&self) -> u8 {
+        self.group_separator
+    }
+
+    /// Set the character separating groups of integer digits.
+    ///
+    /// Group separators, like the `,` in `1,234,567`, are written between
+    /// groups of integer digits, for both integers and floats. Fraction
+    /// and exponent digits are never grouped. Use `0` to disable grouping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_group_separator(b',');
+    /// let mut buffer = [b'0'; i64::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(-1234567i64, &options, &mut buffer), b"-1,234,567");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not a space or a printable ASCII
+    /// character, is in the character set `[A-Za-z0-9+\-]`, or is the
+    /// decimal point or exponent character.
+    #[inline]
+    pub fn set_group_separator(&mut self, ch: u8) {
+        assert!(ch == 0 || ch == b' ' || is_valid_decimal_point(ch));
+        assert!(ch == 0 || ch != self.decimal_point);
+        assert!(ch == 0 || (ch != self.exponent_default_char && ch != self.exponent_backup_char()));
+        self.group_separator = ch;
+    }
+
+    /// Get the number of digits in the primary and secondary groups.
+    #[inline]
+    pub fn get_group_sizes(&self) -> (u8, u8) {
+        (self.group_size, self.secondary_group_size)
+    }
+
+    /// Set the number of digits in the primary and secondary groups.
+    ///
+    /// The primary group is the group adjacent to the decimal point,
+    /// and every other group has the secondary size. Most locales use
+    /// groups of 3 digits, like `1,234,567`, while Indian numbering uses
+    /// a primary size of 3 and a secondary size of 2, like `12,34,567`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_group_separator(b',');
+    /// options.set_group_sizes(3, 2);
+    /// let mut buffer = [b'0'; u32::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(1234567u32, &options, &mut buffer), b"12,34,567");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either group size is 0.
+    #[inline]
+    pub fn set_group_sizes(&mut self, primary: u8, secondary: u8) {
+        assert!(primary != 0 && secondary != 0);
+        self.group_size = primary;
+        self.secondary_group_size = secondary;
+    }
+
+    /// Get the number of digits written after the decimal point, if fixed.
+    #[inline]
+    pub fn get_fixed_precision(&self) -> Option<usize> {
+        self.fixed_precision
+    }
+
+    /// Set the number of digits written after the decimal point.
+    ///
+    /// With a fixed precision, floats are written with exactly `precision`
+    /// digits after the decimal point, correctly rounded from the exact
+    /// binary value with ties to even, like `printf("%.*f")` with glibc,
+    /// or with the [`rounding scheme`] from the `rounding` feature.
+    /// No decimal point is written with a precision of 0. `None` writes
+    /// the shortest representation that round-trips. Fixed precision is
+    /// ignored for hexadecimal floats and radixes other than 10.
+    ///
+    /// Large values and precisions may require more than `FORMATTED_SIZE`
+    /// bytes, so the buffer must have at least [`float_buffer_size`] bytes.
+    ///
+    /// [`rounding scheme`]: #method.set_rounding
+    /// [`float_buffer_size`]: #method.float_buffer_size
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::WriteOptions;
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_fixed_precision(Some(2));
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(2.675f64, &options, &mut buffer), b"2.67");
+    /// assert_eq!(lexical_core::write_with_options(-1e3f64, &options, &mut buffer), b"-1000.00");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_fixed_precision(&mut self, precision: Option<usize>) {
+        self.fixed_precision = precision;
+    }
+
+    /// Get the notation for decimal floats.
+    #[inline]
+    pub fn get_notation(&self) -> Option<Notation> {
+        self.notation
+    }
+
+    /// Set the notation for decimal floats.
+    ///
+    /// By default, or with `None`, the notation depends on the decimal
+    /// backend, which differs between the `ryu` and `grisu3` features.
+    /// Otherwise, floats are always written with a decimal exponent,
+    /// never written with a decimal exponent, or written with a decimal
+    /// exponent outside of the [`exponent limits`], the same way with
+    /// every backend. Exponents are written without a `+` sign, unless
+    /// [`signed`], and the notation is ignored for hexadecimal floats,
+    /// radixes other than 10, and with a fixed precision.
+    ///
+    /// Positional notation may require more than `FORMATTED_SIZE` bytes,
+    /// so the buffer must have at least [`float_buffer_size`] bytes.
+    ///
+    /// [`exponent limits`]: #method.set_exponent_limits
+    /// [`signed`]: #method.set_exponent_sign
+    /// [`float_buffer_size`]: #method.float_buffer_size
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{Notation, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_notation(Some(Notation::Scientific));
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(1234.5f64, &options, &mut buffer), b"1.2345e3");
+    ///
+    /// options.set_notation(Some(Notation::Positional));
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(1.5e-7f64, &options, &mut buffer), b"0.00000015");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_notation(&mut self, notation: Option<Notation>) {
+        self.notation = notation;
+    }
+
+    /// Get the decimal exponent limits for automatic notation.
+    #[inline]
+    pub fn get_exponent_limits(&self) -> (i32, i32) {
+        (self.min_exponent, self.max_exponent)
+    }
+
+    /// Set the decimal exponent limits for automatic notation.
+    ///
+    /// Floats with a decimal exponent in `[min, max)` are written in
+    /// positional notation, and all other floats in scientific notation.
+    /// The default limits are `(-5, 21)`, so `1e-5` is written as
+    /// `0.00001` and `1e21` as `1e21`. JavaScript uses `(-6, 21)`, and
+    /// Python's `repr` uses `(-4, 16)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{Notation, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_notation(Some(Notation::Auto));
+    /// options.set_exponent_limits(-4, 16);
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(1.5e-4f64, &options, &mut buffer), b"0.00015");
+    /// assert_eq!(lexical_core::write_with_options(1.5e-5f64, &options, &mut buffer), b"1.5e-5");
+    /// assert_eq!(lexical_core::write_with_options(1.5e16f64, &options, &mut buffer), b"1.5e16");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    #[inline]
+    pub fn set_exponent_limits(&mut self, min: i32, max: i32) {
+        assert!(min <= max);
+        self.min_exponent = min;
+        self.max_exponent = max;
+    }
+
+    /// Get the minimum number of significant digits for decimal floats.
+    #[inline]
+    pub fn get_min_significant_digits(&self) -> Option<usize> {
+        self.min_significant_digits
+    }
+
+    /// Set the minimum number of significant digits for decimal floats.
+    ///
+    /// Floats with fewer significant digits are padded with trailing
+    /// zeros, like `printf("%#.*g")`, and are never [`trimmed`]. Like
+    /// with a maximum number of significant digits, floats are written
+    /// in [`automatic notation`] by default.
+    /// Significant digits are ignored for hexadecimal floats, radixes
+    /// other than 10, and with a fixed precision.
+    ///
+    /// [`trimmed`]: #method.set_trailing_zero
+    /// [`automatic notation`]: #method.set_notation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::WriteOptions;
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_min_significant_digits(Some(4));
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(1.5f64, &options, &mut buffer), b"1.500");
+    /// assert_eq!(lexical_core::write_with_options(1.5e-7f64, &options, &mut buffer), b"1.500e-7");
+    /// assert_eq!(lexical_core::write_with_options(12345.5f64, &options, &mut buffer), b"12345.5");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_min_significant_digits(&mut self, digits: Option<usize>) {
+        self.min_significant_digits = digits;
+    }
+
+    /// Get the maximum number of significant digits for decimal floats.
+    #[inline]
+    pub fn get_max_significant_digits(&self) -> Option<usize> {
+        self.max_significant_digits
+    }
+
+    /// Set the maximum number of significant digits for decimal floats.
+    ///
+    /// Floats are rounded to at most `digits` significant digits,
+    /// without trailing zeros, like `printf("%.*g")`. The digits are
+    /// correctly rounded from the exact binary value, rather than from
+    /// the shortest representation, so `0.15`, which is slightly less
+    /// than 0.15, is rounded to `0.1`. With the `rounding` feature, the
+    /// [`rounding scheme`] may be changed from ties to even.
+    ///
+    /// Floats are written in the [`notation`] from the options, or in
+    /// automatic notation by default. Significant digits are ignored
+    /// for hexadecimal floats, radixes other than 10, and with a fixed
+    /// precision.
+    ///
+    /// [`rounding scheme`]: #method.set_rounding
+    /// [`notation`]: #method.set_notation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::WriteOptions;
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_max_significant_digits(Some(3));
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(12.345f64, &options, &mut buffer), b"12.3");
+    /// assert_eq!(lexical_core::write_with_options(2.0f64 / 3.0, &options, &mut buffer), b"0.667");
+    ///
+    /// options.set_max_significant_digits(Some(1));
+    /// assert_eq!(lexical_core::write_with_options(0.15f64, &options, &mut buffer), b"0.1");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is `Some(0)`.
+    #[inline]
+    pub fn set_max_significant_digits(&mut self, digits: Option<usize>) {
+        assert!(digits != Some(0));
+        self.max_significant_digits = digits;
+    }
+
+    /// Get the rounding scheme for fixed-precision and significant-digit floats.
+    #[inline]
+    #[cfg(feature = "rounding")]
+    pub fn get_rounding(&self) -> RoundingKind {
+        self.rounding
+    }
+
+    /// Set the rounding scheme for fixed-precision and significant-digit floats.
+    ///
+    /// Digits removed by a [`fixed precision`] or a [`maximum number`]
+    /// of significant digits are rounded with this scheme, from the
+    /// exact binary value. The default is `NearestTieEven`.
+    ///
+    /// [`fixed precision`]: #method.set_fixed_precision
+    /// [`maximum number`]: #method.set_max_significant_digits
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{RoundingKind, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_max_significant_digits(Some(2));
+    /// options.set_rounding(RoundingKind::TowardPositiveInfinity);
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(1.01f64, &options, &mut buffer), b"1.1");
+    /// assert_eq!(lexical_core::write_with_options(-1.19f64, &options, &mut buffer), b"-1.1");
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "rounding")]
+    pub fn set_rounding(&mut self, rounding: RoundingKind) {
+        self.rounding = rounding;
+    }
+
+    /// Get if a `+` sign is written before positive floats.
+    #[inline]
+    pub fn get_positive_sign(&self) -> bool {
+        self.positive_sign
+    }
+
+    /// Set if a `+` sign is written before positive floats.
+    ///
+    /// The sign is written before every float without a `-` sign,
+    /// including zero and special values, such as `+1.5` and `+inf`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_positive_sign(true);
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(1.5f64, &options, &mut buffer), b"+1.5");
+    /// assert_eq!(lexical_core::write_with_options(-1.5f64, &options, &mut buffer), b"-1.5");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_positive_sign(&mut self, positive_sign: bool) {
+        self.positive_sign = positive_sign;
+    }
+
+    /// Get if a `+` sign is written before positive exponents.
+    #[inline]
+    pub fn get_exponent_sign(&self) -> bool {
+        self.exponent_sign
+    }
+
+    /// Set if a `+` sign is written before positive exponents.
+    ///
+    /// Negative exponents always have a `-` sign, and hexadecimal floats
+    /// always have a signed exponent, such as `0x1.8p+3`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{Notation, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_notation(Some(Notation::Scientific));
+    /// options.set_exponent_sign(true);
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(1.5e5f64, &options, &mut buffer), b"1.5e+5");
+    /// assert_eq!(lexical_core::write_with_options(1.5e-5f64, &options, &mut buffer), b"1.5e-5");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_exponent_sign(&mut self, exponent_sign: bool) {
+        self.exponent_sign = exponent_sign;
+    }
+
+    /// Get the minimum number of exponent digits.
+    #[inline]
+    pub fn get_min_exponent_digits(&self) -> usize {
+        self.min_exponent_digits
+    }
+
+    /// Set the minimum number of exponent digits.
+    ///
+    /// Exponents with fewer digits are padded with leading zeros after
+    /// the sign, so with a [`signed exponent`] and 2 digits, exponents
+    /// are written like `printf("%e")`, such as `1.5e+05`. Exponents of
+    /// hexadecimal floats are never padded.
+    ///
+    /// Large minimums may require more than `FORMATTED_SIZE` bytes,
+    /// so the buffer must have at least [`float_buffer_size`] bytes.
+    ///
+    /// [`signed exponent`]: #method.set_exponent_sign
+    /// [`float_buffer_size`]: #method.float_buffer_size
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{Notation, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_notation(Some(Notation::Scientific));
+    /// options.set_exponent_sign(true);
+    /// options.set_min_exponent_digits(2);
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(1.5e5f64, &options, &mut buffer), b"1.5e+05");
+    /// assert_eq!(lexical_core::write_with_options(1.5e-300f64, &options, &mut buffer), b"1.5e-300");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_min_exponent_digits(&mut self, digits: usize) {
+        self.min_exponent_digits = digits;
+    }
+
+    /// Get the letter case of the exponent character.
+    #[inline]
+    pub fn get_exponent_case(&self) -> LetterCase {
+        self.exponent_case
+    }
+
+    /// Set the letter case of the exponent character.
+    ///
+    /// The [`default`] or [`backup`] exponent character is converted to
+    /// the letter case, or written as configured with `Preserve`.
+    /// Hexadecimal floats always use `p`.
+    ///
+    /// [`default`]: #method.set_exponent_default_char
+    /// [`backup`]: #method.set_exponent_backup_char
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{LetterCase, Notation, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_notation(Some(Notation::Scientific));
+    /// options.set_exponent_case(LetterCase::Upper);
+    /// let mut buffer = vec![b'0'; options.float_buffer_size::<f64>()];
+    /// assert_eq!(lexical_core::write_with_options(1.5e5f64, &options, &mut buffer), b"1.5E5");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_exponent_case(&mut self, case: LetterCase) {
+        self.exponent_case = case;
+    }
+
+    /// Get the letter case of the NaN and infinity strings.
+    #[inline]
+    pub fn get_special_case(&self) -> LetterCase {
+        self.special_case
+    }
+
+    /// Set the letter case of the NaN and infinity strings.
+    ///
+    /// The [`NaN`] and [`infinity`] strings, and the `s` prefix of
+    /// signaling NaNs, are converted to the letter case, or written as
+    /// configured with `Preserve`. Non-ASCII characters are unchanged.
+    ///
+    /// [`NaN`]: #method.set_nan_string
+    /// [`infinity`]: #method.set_inf_string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{LetterCase, ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_special_case(LetterCase::Upper);
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(f64::NAN, &options, &mut buffer), b"NAN");
+    /// assert_eq!(lexical_core::write_with_options(-f64::INFINITY, &options, &mut buffer), b"-INF");
+    ///
+    /// options.set_special_case(LetterCase::Lower);
+    /// assert_eq!(lexical_core::write_with_options(f64::NAN, &options, &mut buffer), b"nan");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_special_case(&mut self, case: LetterCase) {
+        self.special_case = case;
+    }
+
+    /// Get the fraction written for floats without fraction digits.
+    #[inline]
+    pub fn get_trailing_zero(&self) -> TrailingZero {
+        self.trailing_zero
+    }
+
+    /// Set the fraction written for floats without fraction digits.
+    ///
+    /// By default, floats are written as by the float writer, such as
+    /// `1.0` and `1e21`, or trimmed with the `trim_floats` feature, such
+    /// as `1`. Trimmed floats are written like integers, such as `1` and
+    /// `1e21`, and otherwise, the `.0` fraction may be forced, such as
+    /// `1.0` and `1.0e21`. Fixed-precision floats, floats with a minimum
+    /// number of significant digits, and hexadecimal floats are never
+    /// trimmed or extended.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, TrailingZero, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_trailing_zero(TrailingZero::Trim);
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(12.0f64, &options, &mut buffer), b"12");
+    ///
+    /// options.set_trailing_zero(TrailingZero::Force);
+    /// assert_eq!(lexical_core::write_with_options(12.0f64, &options, &mut buffer), b"12.0");
+    /// assert_eq!(lexical_core::write_with_options(1e21f64, &options, &mut buffer), b"1.0e21");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_trailing_zero(&mut self, trailing_zero: TrailingZero) {
+        self.trailing_zero = trailing_zero;
+    }
+
+    /// Get if negative zero is written with a `-` sign.
+    #[inline]
+    pub fn get_signed_zero(&self) -> bool {
+        self.signed_zero
+    }
+
+    /// Set if negative zero is written with a `-` sign.
+    ///
+    /// Otherwise, negative zero is written as positive zero. With
+    /// [`trimmed floats`], negative zero is written as `-0` or `0`,
+    /// and otherwise as `-0.0` or `0.0`. Negative zero is only unsigned
+    /// by default with the `trim_floats` feature, and always keeps its
+    /// sign with a fixed precision, minimum significant digits,
+    /// scientific notation, or as a hexadecimal float.
+    ///
+    /// [`trimmed floats`]: #method.set_trailing_zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, TrailingZero, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_trailing_zero(TrailingZero::Force);
+    /// options.set_signed_zero(true);
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(-0.0f64, &options, &mut buffer), b"-0.0");
+    ///
+    /// options.set_trailing_zero(TrailingZero::Trim);
+    /// assert_eq!(lexical_core::write_with_options(-0.0f64, &options, &mut buffer), b"-0");
+    ///
+    /// options.set_signed_zero(false);
+    /// assert_eq!(lexical_core::write_with_options(-0.0f64, &options, &mut buffer), b"0");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_signed_zero(&mut self, signed_zero: bool) {
+        self.signed_zero = signed_zero;
+    }
+
+    /// Get the minimum width of written numbers, in bytes.
+    #[inline]
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// Set the minimum width of written numbers, in bytes.
+    ///
+    /// Shorter integers and floats are padded to the width with the
+    /// [`fill`] character, in the [`alignment`], or with zeros after
+    /// the sign, with [`zero padding`]. Longer numbers are never
+    /// truncated.
+    ///
+    /// Widths larger than `FORMATTED_SIZE` require a larger buffer,
+    /// so the buffer must have at least [`integer_buffer_size`] or
+    /// [`float_buffer_size`] bytes.
+    ///
+    /// [`fill`]: #method.set_fill
+    /// [`alignment`]: #method.set_alignment
+    /// [`zero padding`]: #method.set_zero_pad
+    /// [`integer_buffer_size`]: #method.integer_buffer_size
+    /// [`float_buffer_size`]: #method.float_buffer_size
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_width(6);
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(42i32, &options, &mut buffer), b"    42");
+    /// assert_eq!(lexical_core::write_with_options(-1.5f64, &options, &mut buffer), b"  -1.5");
+    /// assert_eq!(lexical_core::write_with_options(1234567i32, &options, &mut buffer), b"1234567");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_width(&mut self, width: usize) {
+        self.width = width;
+    }
+
+    /// Get the character written to pad numbers to the minimum width.
+    #[inline]
+    pub fn get_fill(&self) -> u8 {
+        self.fill
+    }
+
+    /// Set the character written to pad numbers to the minimum width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_width(6);
+    /// options.set_fill(b'*');
+    /// let mut buffer = [b'0'; u32::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(42u32, &options, &mut buffer), b"****42");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not a space or a printable ASCII
+    /// character.
+    #[inline]
+    pub fn set_fill(&mut self, ch: u8) {
+        assert!(ch == b' ' || ch.is_ascii_graphic());
+        self.fill = ch;
+    }
+
+    /// Get the alignment of numbers within the minimum width.
+    #[inline]
+    pub fn get_alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Set the alignment of numbers within the minimum width.
+    ///
+    /// Numbers are right-aligned by default, like `printf("%6d")`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{Alignment, ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_width(6);
+    /// options.set_alignment(Alignment::Left);
+    /// let mut buffer = [b'0'; i32::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(-42i32, &options, &mut buffer), b"-42   ");
+    ///
+    /// options.set_alignment(Alignment::Center);
+    /// assert_eq!(lexical_core::write_with_options(-42i32, &options, &mut buffer), b" -42  ");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_alignment(&mut self, alignment: Alignment) {
+        self.alignment = alignment;
+    }
+
+    /// Get if numbers are padded with zeros after the sign.
+    #[inline]
+    pub fn get_zero_pad(&self) -> bool {
+        self.zero_pad
+    }
+
+    /// Set if numbers are padded with zeros after the sign.
+    ///
+    /// Zeros are written between the sign and the digits, like
+    /// `printf("%05d")`, instead of the fill character and alignment.
+    /// Zeros are never grouped, and are written after the `0x` prefix
+    /// of hexadecimal floats. NaN and infinity are padded with the
+    /// fill character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_width(5);
+    /// options.set_zero_pad(true);
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(-42i32, &options, &mut buffer), b"-0042");
+    /// assert_eq!(lexical_core::write_with_options(1.5f64, &options, &mut buffer), b"001.5");
+    /// assert_eq!(lexical_core::write_with_options(f64::NEG_INFINITY, &options, &mut buffer), b" -inf");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_zero_pad(&mut self, zero_pad: bool) {
+        self.zero_pad = zero_pad;
+    }
+
+    /// Get the buffer size required to write any integer of type `I`.
+    ///
+    /// This is `I::FORMATTED_SIZE` or `I::FORMATTED_SIZE_DECIMAL`, unless
+    /// the [`minimum width`] is larger.
+    ///
+    /// [`minimum width`]: #method.set_width
+    #[inline]
+    pub fn integer_buffer_size<I: Integer + ToLexical>(&self) -> usize {
+        let size = match self.radix {
+            10 => <I as ToLexical>::FORMATTED_SIZE_DECIMAL,
+            _  => <I as ToLexical>::FORMATTED_SIZE,
+        };
+        cmp::max(size, self.width)
+    }
+
+    /// Get the buffer size required to write any float of type `F`.
+    ///
+    /// This is `F::FORMATTED_SIZE` or `F::FORMATTED_SIZE_DECIMAL`, unless
+    /// a fixed precision, positional notation or significant digits are
+    /// set, which require room for every integer digit of the largest
+    /// float and any group separators, every leading zero of the smallest
+    /// float, or every significant digit, or the [`minimum width`] is larger.
+    ///
+    /// [`minimum width`]: #method.set_width
+    #[inline]
+    pub fn float_buffer_size<F: Float + ToLexical>(&self) -> usize {
+        let size = match self.radix {
+            10 => <F as ToLexical>::FORMATTED_SIZE_DECIMAL,
+            _  => <F as ToLexical>::FORMATTED_SIZE,
+        };
+        let size = cmp::max(size, self.width);
+
+        // The largest float is less than `2^(MAX_EXPONENT + MANTISSA_SIZE)`,
+        // and `78913 / 2^18` is slightly less than `log10(2)`.
+        let bits = (F::MAX_EXPONENT + F::MANTISSA_SIZE) as usize;
+        let integer_digits = ((bits * 78913) >> 18) + 1;
+        if let Some(precision) = self.fixed_precision {
+            let fixed = 1 + self.grouped_size(integer_digits) + (precision != 0) as usize + precision;
+            return cmp::max(size, fixed);
+        }
+
+        // The shortest representation has at most `shortest` significant
+        // digits, with one more for backends that are not always shortest,
+        // and the exact value has at most `exact` significant digits.
+        let shortest = (((F::MANTISSA_SIZE + 1) as usize * 78913) >> 18) + 3;
+        let fraction_bits = -F::DENORMAL_EXPONENT as usize;
+        let exact = fraction_bits - (((fraction_bits - F::MANTISSA_SIZE as usize - 1) * 78913) >> 18);
+        let (digits, notation) = match (self.min_significant_digits, self.max_significant_digits) {
+            (None, None)    => (shortest, self.notation),
+            (min, max)      => {
+                let max = max.map_or(shortest, |max| cmp::min(max, exact));
+                let digits = cmp::max(min.unwrap_or(0), max);
+                (digits, Some(self.notation.unwrap_or(Notation::Auto)))
+            },
+        };
+
+        // Write the sign, digits, `.0`, and exponent, such as `-1.0e-308`,
+        // with the exponent digits padded to the minimum. Non-decimal
+        // floats have up to `MANTISSA_SIZE + 1` digits and 11 exponent
+        // digits, in radix 2.
+        let has_digits = self.min_significant_digits.is_some() || self.max_significant_digits.is_some();
+        let scientific = match (has_digits || self.min_exponent_digits != 0, self.radix) {
+            (false, _)  => 0,
+            (true, 10)  => digits + 5 + cmp::max(self.min_exponent_digits, 3),
+            (true, _)   => (F::MANTISSA_SIZE + 1) as usize + 5 + cmp::max(self.min_exponent_digits, 11),
+        };
+        let positional = match notation {
+            Some(Notation::Positional)  => self.positional_size::<F>(digits, i32::min_value(), i32::max_value()),
+            Some(Notation::Auto)        => self.positional_size::<F>(digits, self.min_exponent, self.max_exponent),
+            _                           => 0,
+        };
+        cmp::max(size, cmp::max(scientific, positional))
+    }
+
+    /// Get the number of integer digits, including any group separators.
+    #[inline]
+    pub(crate) fn grouped_size(&self, digits: usize) -> usize {
+        match self.group_separator {
+            0 => digits,
+            _ => digits + group_separator_count(digits, self.group_size as usize, self.secondary_group_size as usize),
+        }
+    }
+
+    /// Get the buffer size for floats with up to `digits` significant
+    /// digits, written positionally with exponents in `[min, max)`.
+    #[inline]
+    fn positional_size<F: Float>(&self, digits: usize, min: i32, max: i32) -> usize {
+        // The smallest denormal float is greater than `10^min_exponent`.
+        let bits = (F::MAX_EXPONENT + F::MANTISSA_SIZE) as usize;
+        let max_exponent = ((bits * 78913) >> 18) as i32;
+        let min_exponent = -(((-F::DENORMAL_EXPONENT as usize * 78913) >> 18) as i32) - 1;
+
+        // Write the integer digits and `.0`, or `0.` and leading zeros.
+        let large = match cmp::min(max.saturating_sub(1), max_exponent) {
+            e if e >= 0 => self.grouped_size(cmp::max(e as usize + 1, digits)) + 2,
+            _           => 0,
+        };
+        let small = match cmp::max(min, min_exponent) {
+            e if e < 0  => 2 + (-e - 1) as usize + digits,
+            _           => 0,
+        };
+        1 + cmp::max(large, small)
+    }
+
+    /// Get if floats are written as C99 hexadecimal floats.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn get_hex_float(&self) -> bool {
+        self.hex_float
+    }
+
+    /// Set if floats are written as C99 hexadecimal floats.
+    ///
+    /// Hexadecimal floats, like `0x1.8p+3`, are written with a `0x` prefix,
+    /// lowercase hexadecimal digits and a binary exponent written in decimal
+    /// after a `p`, like the output of `printf("%a")`. The radix and exponent
+    /// characters are ignored for floats while enabled, and integers are
+    /// unaffected.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn set_hex_float(&mut self, hex_float: bool) {
+        self.hex_float = hex_float;
+    }
+
+    /// Get if NaN payloads and signaling NaNs are written.
+    #[inline]
+    pub fn get_nan_payloads(&self) -> bool {
+        self.nan_payloads
+    }
+
+    /// Set if NaN payloads and signaling NaNs are written.
+    ///
+    /// Non-zero payloads are written in hexadecimal after the NaN string,
+    /// such as `NaN(0x1234)`, and signaling NaNs have an `s` prefix, such
+    /// as `sNaN`. Values written with NaN payloads round-trip bit-exactly
+    /// when parsed with NaN payloads enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_nan_payloads(true);
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// let value = f64::from_bits(0xFFF8000000001234);
+    /// assert_eq!(lexical_core::write_with_options(value, &options, &mut buffer), b"-NaN(0x1234)");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if enabled and the NaN string is longer than 44 bytes.
+    #[inline]
+    pub fn set_nan_payloads(&mut self, nan_payloads: bool) {
+        assert!(!nan_payloads || self.nan_string.len() + NAN_PAYLOAD_SIZE < F32_FORMATTED_SIZE_DECIMAL);
+        self.nan_payloads = nan_payloads;
+    }
+
+    /// Get the string representation of Not a Number.
+    #[inline]
+    pub fn get_nan_string(&self) -> &'static [u8] {
+        self.nan_string
+    }
+
+    /// Set the string representation of Not a Number.
+    ///
+    /// Unlike for parsing, the string may start with any character,
+    /// such as `1.#QNAN`, but must be ASCII, so written numbers are
+    /// always valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `bytes.len() >= f32::FORMATTED_SIZE`
+    /// - `bytes.len() > 44` with NaN payloads enabled
+    /// - `bytes` is empty
+    /// - `bytes` is not ASCII
+    #[inline]
+    pub fn set_nan_string(&mut self, bytes: &'static [u8]) {
+        assert!(!bytes.is_empty() && bytes.len() < F32_FORMATTED_SIZE && bytes.is_ascii());
+        assert!(!self.nan_payloads || bytes.len() + NAN_PAYLOAD_SIZE < F32_FORMATTED_SIZE_DECIMAL);
+        self.nan_string = bytes;
+    }
+
+    /// Get the short representation of Infinity.
+    #[inline]
+    pub fn get_inf_string(&self) -> &'static [u8] {
+        self.inf_string
+    }
+
+    /// Set the short representation of Infinity.
+    ///
+    /// Unlike for parsing, the string may start with any character,
+    /// such as `1.#INF`, but must be ASCII, so written numbers are
+    /// always valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ToLexical, WriteOptions};
+    /// # pub fn main() {
+    /// let mut options = WriteOptions::new();
+    /// options.set_inf_string(b"1.#INF");
+    /// let mut buffer = [b'0'; f64::FORMATTED_SIZE];
+    /// let bytes = lexical_core::write_with_options(-f64::INFINITY, &options, &mut buffer);
+    /// assert_eq!(bytes, b"-1.#INF");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `bytes.len() >= f32::FORMATTED_SIZE`
+    /// - `bytes` is empty
+    /// - `bytes` is not ASCII
+    #[inline]
+    pub fn set_inf_string(&mut self, bytes: &'static [u8]) {
+        assert!(!bytes.is_empty() && bytes.len() < F32_FORMATTED_SIZE && bytes.is_ascii());
+        self.inf_string = bytes;
+    }
+
+    /// Get if the `.0` fraction is trimmed from floats without fraction digits.
+    #[inline]
+    pub(crate) fn is_trimmed(&self) -> bool {
+        match self.trailing_zero {
+            TrailingZero::Default   => cfg!(feature = "trim_floats"),
+            TrailingZero::Trim      => true,
+            TrailingZero::Force     => false,
+        }
+    }
+
+    /// Get the exponent character for the current radix, in the exponent letter case.
+    #[inline]
+    pub(crate) fn exponent_char(&self) -> u8 {
+        let ch = exponent_notation_char(self.radix, self.exponent_default_char, self.exponent_backup_char());
+        self.exponent_case.convert(ch)
+    }
+
+    /// Get the backup character, or the default character without radix support.
+    #[inline]
+    fn exponent_backup_char(&self) -> u8 {
+        #[cfg(feature = "radix")] {
+            self.exponent_backup_char
+        }
+
+        #[cfg(not(feature = "radix"))] {
+            self.exponent_default_char
+        }
+    }
+}
+
+impl Default for WriteOptions {
+    #[inline]
+    fn default() -> WriteOptions {
+        WriteOptions::new()
+    }
+}
+
+// TEST
+// ----
+
+#[cfg(test)]
+mod tests {
+    use util::*;
+    use util::test::*;
+
+    #[test]
+    fn parse_options_test() {
+        let mut options = ParseOptions::new();
+        assert_eq!(options.get_radix(), 10);
+        assert_eq!(options.get_nan_string(), get_nan_string());
+        assert_eq!(options.get_inf_string(), get_inf_string());
+        assert_eq!(options.get_infinity_string(), get_infinity_string());
+
+        options.set_nan_string(b"nan");
+        options.set_inf_string(b"Inf");
+        options.set_infinity_string(b"Infinity");
+        assert_eq!(options.get_nan_string(), b"nan");
+        assert_eq!(options.get_inf_string(), b"Inf");
+        assert_eq!(options.get_infinity_string(), b"Infinity");
+
+        // The global configuration is unaffected.
+        assert_eq!(get_nan_string(), b"NaN");
+        assert_eq!(get_inf_string(), b"inf");
+        assert_eq!(get_infinity_string(), b"infinity");
+    }
+
+    #[test]
+    fn parse_with_options_test() {
+        let mut options = ParseOptions::new();
+        options.set_exponent_default_char(b'^');
+        assert_eq!(f64::from_lexical_with_options(b"1.5^3", &options), Ok(1.5e3));
+        assert_eq!(f64::from_lexical_partial_with_options(b"1.5e3", &options), Ok((1.5, 3)));
+        assert_eq!(f64::from_lexical_lossy_with_options(b"1.5^3", &options), Ok(1.5e3));
+        assert_eq!(f64::from_lexical_partial_lossy_with_options(b"1.5e3", &options), Ok((1.5, 3)));
+        assert_eq!(f64::from_lexical(b"1.5e3"), Ok(1.5e3));
+
+        options.set_inf_string(b"i");
+        options.set_nan_string(b"nan");
+        assert!(f64::from_lexical_with_options(b"I", &options).unwrap().is_infinite());
+        assert!(f64::from_lexical_with_options(b"-I", &options).unwrap().is_sign_negative());
+        assert!(f64::from_lexical_with_options(b"NAN", &options).unwrap().is_nan());
+        assert!(f64::from_lexical(b"I").is_err());
+
+        assert_eq!(i32::from_lexical_with_options(b"-15", &options), Ok(-15));
+        assert_eq!(u8::from_lexical_partial_with_options(b"15e", &options), Ok((15, 2)));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn parse_radix_options_test() {
+        let mut options = ParseOptions::radix(36);
+        assert_eq!(options.get_radix(), 36);
+        assert_eq!(f64::from_lexical_with_options(b"YA", &options), Ok(1234.0));
+        assert_eq!(i32::from_lexical_with_options(b"-YA", &options), Ok(-1234));
+
+        options.set_radix(2);
+        assert_eq!(f64::from_lexical_with_options(b"1.1e10", &options), Ok(6.0));
+        options.set_exponent_default_char(b'x');
+        assert_eq!(f64::from_lexical_with_options(b"1.1x10", &options), Ok(6.0));
+
+        options.set_radix(16);
+        options.set_exponent_backup_char(b'#');
+        assert_eq!(f64::from_lexical_with_options(b"1.8#1", &options), Ok(24.0));
+    }
+
+    #[cfg(all(feature = "correct", feature = "rounding"))]
+    #[test]
+    fn parse_rounding_options_test() {
+        let mut options = ParseOptions::new();
+        options.set_rounding(RoundingKind::TowardPositiveInfinity);
+        assert_eq!(f64::from_lexical_with_options(b"9007199254740993", &options), Ok(9007199254740994.0));
+        assert_eq!(f64::from_lexical_with_options(b"-9007199254740993", &options), Ok(-9007199254740992.0));
+        options.set_rounding(RoundingKind::TowardZero);
+        assert_eq!(f64::from_lexical_with_options(b"9007199254740995", &options), Ok(9007199254740994.0));
+        assert_eq!(f64::from_lexical(b"9007199254740995"), Ok(9007199254740996.0));
+    }
+
+    #[test]
+    fn write_options_test() {
+        let mut buffer = new_buffer();
+        let mut options = WriteOptions::new();
+        assert_eq!(options.get_radix(), 10);
+        assert_eq!(options.get_nan_string(), get_nan_string());
+        assert_eq!(options.get_inf_string(), get_inf_string());
+
+        options.set_nan_string(b"nan");
+        options.set_inf_string(b"Infinity");
+        assert_eq!(f64::NAN.to_lexical_with_options(&options, &mut buffer), b"nan");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&options, &mut buffer), b"Infinity");
+        assert_eq!(f32::NEG_INFINITY.to_lexical_with_options(&options, &mut buffer), b"-Infinity");
+        assert_eq!(f64::NAN.to_lexical(&mut buffer), b"NaN");
+        assert_eq!(f64::INFINITY.to_lexical(&mut buffer), b"inf");
+
+        options.set_exponent_default_char(b'^');
+        assert_eq!(1.5e-300f64.to_lexical_with_options(&options, &mut buffer), b"1.5^-300");
+        assert_eq!(1.5e-30f64.to_lexical_with_options(&options, &mut buffer), b"1.5^-30");
+        assert_eq!(15i32.to_lexical_with_options(&options, &mut buffer), b"15");
+    }
+
+    #[test]
+    fn write_decimal_point_options_test() {
+        let mut buffer = new_buffer();
+        let mut options = WriteOptions::new();
+        assert_eq!(options.get_decimal_point(), b'.');
+        options.set_decimal_point(b',');
+        assert_eq!(3.14f64.to_lexical_with_options(&options, &mut buffer), b"3,14");
+        assert_eq!((-1.5e-30f64).to_lexical_with_options(&options, &mut buffer), b"-1,5e-30");
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(0.0f64.to_lexical_with_options(&options, &mut buffer), b"0,0");
+            assert_eq!(1.0f64.to_lexical_with_options(&options, &mut buffer), b"1,0");
+        }
+        #[cfg(feature = "trim_floats")] {
+            assert_eq!(1.0f64.to_lexical_with_options(&options, &mut buffer), b"1");
+        }
+
+        options.set_nan_string(b"n.a.");
+        assert_eq!(f64::NAN.to_lexical_with_options(&options, &mut buffer), b"n.a.");
+    }
+
+    #[test]
+    fn write_group_separator_options_test() {
+        let mut buffer = new_buffer();
+        let mut options = WriteOptions::new();
+        assert_eq!(options.get_group_separator(), 0);
+        assert_eq!(options.get_group_sizes(), (3, 3));
+        options.set_group_separator(b',');
+        assert_eq!(123u8.to_lexical_with_options(&options, &mut buffer), b"123");
+        assert_eq!(1234u16.to_lexical_with_options(&options, &mut buffer), b"1,234");
+        assert_eq!(1234567i32.to_lexical_with_options(&options, &mut buffer), b"1,234,567");
+        assert_eq!((-123456i32).to_lexical_with_options(&options, &mut buffer), b"-123,456");
+        assert_eq!(i64::min_value().to_lexical_with_options(&options, &mut buffer), &b"-9,223,372,036,854,775,808"[..]);
+        assert_eq!(1234567.5f64.to_lexical_with_options(&options, &mut buffer), b"1,234,567.5");
+        assert_eq!((-1234.5f64).to_lexical_with_options(&options, &mut buffer), b"-1,234.5");
+        assert_eq!(0.12345f64.to_lexical_with_options(&options, &mut buffer), b"0.12345");
+        assert!(!1.2345e30f64.to_lexical_with_options(&options, &mut buffer).contains(&b','));
+        assert_eq!(f64::NAN.to_lexical_with_options(&options, &mut buffer), b"NaN");
+
+        options.set_group_sizes(3, 2);
+        assert_eq!(1234567u32.to_lexical_with_options(&options, &mut buffer), b"12,34,567");
+        assert_eq!(123456789.25f64.to_lexical_with_options(&options, &mut buffer), b"12,34,56,789.25");
+
+        options.set_group_sizes(2, 2);
+        assert_eq!(u64::max_value().to_lexical_with_options(&options, &mut buffer), &b"18,44,67,44,07,37,09,55,16,15"[..]);
+
+        options.set_group_sizes(1, 1);
+        assert_eq!(u64::max_value().to_lexical_with_options(&options, &mut buffer).len(), <u64 as ToLexical>::FORMATTED_SIZE_DECIMAL);
+        assert_eq!(i64::min_value().to_lexical_with_options(&options, &mut buffer).len(), <i64 as ToLexical>::FORMATTED_SIZE_DECIMAL);
+        assert_eq!((-1234.5f64).to_lexical_with_options(&options, &mut buffer), b"-1,2,3,4.5");
+        options.set_group_sizes(3, 1);
+        assert_eq!(1234567u32.to_lexical_with_options(&options, &mut buffer), b"1,2,3,4,567");
+
+        options.set_group_sizes(3, 3);
+        options.set_group_separator(b' ');
+        assert_eq!(1234567.89f64.to_lexical_with_options(&options, &mut buffer), b"1 234 567.89");
+        options.set_decimal_point(b',');
+        options.set_group_separator(b'.');
+        assert_eq!(1234567.89f64.to_lexical_with_options(&options, &mut buffer), b"1.234.567,89");
+
+        options.set_group_separator(0);
+        assert_eq!(1234567u32.to_lexical_with_options(&options, &mut buffer), b"1234567");
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn write_group_separator_radix_options_test() {
+        let mut buffer = new_buffer();
+        let mut options = WriteOptions::radix(16);
+        options.set_group_separator(b'_');
+        options.set_group_sizes(4, 4);
+        assert_eq!(0xDEADBEEFu32.to_lexical_with_options(&options, &mut buffer), b"DEAD_BEEF");
+        assert_eq!(u128::max_value().to_lexical_with_options(&WriteOptions::radix(2), &mut buffer).len(), 128);
+
+        options.set_radix(2);
+        options.set_group_sizes(2, 2);
+        assert_eq!(u128::max_value().to_lexical_with_options(&options, &mut buffer).len(), 191);
+        options.set_group_sizes(1, 1);
+        assert_eq!(i128::min_value().to_lexical_with_options(&options, &mut buffer).len(), <i128 as ToLexical>::FORMATTED_SIZE);
+        options.set_group_sizes(2, 2);
+        assert_eq!(10.5f64.to_lexical_with_options(&options, &mut buffer), b"10_10.1");
+
+        options.set_radix(10);
+        options.set_group_sizes(3, 3);
+        options.set_hex_float(true);
+        assert_eq!(4096.0f64.to_lexical_with_options(&options, &mut buffer), b"0x1p+12");
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn write_radix_options_test() {
+        let mut buffer = new_buffer();
+        let mut options = WriteOptions::radix(36);
+        assert_eq!(1234i32.to_lexical_with_options(&options, &mut buffer), b"YA");
+        assert_eq!((-1234i64).to_lexical_with_options(&options, &mut buffer), b"-YA");
+
+        options.set_radix(2);
+        options.set_exponent_default_char(b'x');
+        assert_eq!(1649267441664.0f64.to_lexical_with_options(&options, &mut buffer), b"1.1x101000");
+        options.set_decimal_point(b',');
+        assert_eq!(1.5f64.to_lexical_with_options(&options, &mut buffer), b"1,1");
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn parse_format_options_test() {
+        let mut options = ParseOptions::new();
+        assert_eq!(options.get_format(), NumberFormat::default());
+        assert_eq!(f64::from_lexical_with_options(b"1_000.5", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+
+        let format = NumberFormat::compile(b'_', NumberFormat::INTERNAL_DIGIT_SEPARATOR).unwrap();
+        options.set_format(format);
+        assert_eq!(options.get_format(), format);
+        assert_eq!(f64::from_lexical_with_options(b"1_000.5", &options), Ok(1000.5));
+        assert_eq!(u32::from_lexical_with_options(b"1_000", &options), Ok(1000));
+    }
+
+    #[test]
+    fn float_buffer_size_test() {
+        let mut options = WriteOptions::new();
+        assert_eq!(options.float_buffer_size::<f32>(), <f32 as ToLexical>::FORMATTED_SIZE_DECIMAL);
+        assert_eq!(options.float_buffer_size::<f64>(), <f64 as ToLexical>::FORMATTED_SIZE_DECIMAL);
+
+        options.set_fixed_precision(Some(0));
+        assert_eq!(options.float_buffer_size::<f32>(), <f32 as ToLexical>::FORMATTED_SIZE_DECIMAL);
+        assert_eq!(options.float_buffer_size::<f64>(), 310);
+
+        options.set_fixed_precision(Some(100));
+        assert_eq!(options.float_buffer_size::<f32>(), 141);
+        assert_eq!(options.float_buffer_size::<f64>(), 411);
+
+        options.set_group_separator(b',');
+        assert_eq!(options.float_buffer_size::<f32>(), 153);
+        assert_eq!(options.float_buffer_size::<f64>(), 513);
+    }
+
+    #[test]
+    fn float_buffer_size_exponent_digits_test() {
+        let mut options = WriteOptions::new();
+        options.set_min_exponent_digits(2);
+        assert_eq!(options.float_buffer_size::<f64>(), <f64 as ToLexical>::FORMATTED_SIZE_DECIMAL);
+
+        options.set_min_exponent_digits(100);
+        assert_eq!(options.float_buffer_size::<f32>(), 115);
+        assert_eq!(options.float_buffer_size::<f64>(), 123);
+    }
+
+    #[test]
+    fn buffer_size_width_test() {
+        let mut options = WriteOptions::new();
+        assert_eq!(options.integer_buffer_size::<u8>(), <u8 as ToLexical>::FORMATTED_SIZE_DECIMAL);
+        assert_eq!(options.integer_buffer_size::<i64>(), <i64 as ToLexical>::FORMATTED_SIZE_DECIMAL);
+
+        options.set_width(100);
+        assert_eq!(options.integer_buffer_size::<u8>(), 100);
+        assert_eq!(options.float_buffer_size::<f64>(), 100);
+
+        options.set_fixed_precision(Some(100));
+        assert_eq!(options.float_buffer_size::<f64>(), 411);
+
+        options.set_width(500);
+        assert_eq!(options.float_buffer_size::<f64>(), 500);
+    }
+
+    #[test]
+    fn write_padding_options_test() {
+        let mut options = WriteOptions::new();
+        assert_eq!(options.get_width(), 0);
+        assert_eq!(options.get_fill(), b' ');
+        assert_eq!(options.get_alignment(), Alignment::Right);
+        assert_eq!(options.get_zero_pad(), false);
+
+        options.set_fill(b'0');
+        assert_eq!(options.get_fill(), b'0');
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_write_fill_invalid_test() {
+        let mut options = WriteOptions::new();
+        options.set_fill(b'\n');
+    }
+
+    #[test]
+    fn write_style_options_test() {
+        let mut options = WriteOptions::new();
+        assert_eq!(options.get_positive_sign(), false);
+        assert_eq!(options.get_exponent_sign(), false);
+        assert_eq!(options.get_min_exponent_digits(), 0);
+        assert_eq!(options.get_exponent_case(), LetterCase::Preserve);
+        assert_eq!(options.get_special_case(), LetterCase::Preserve);
+        assert_eq!(options.get_trailing_zero(), TrailingZero::Default);
+        assert_eq!(options.get_signed_zero(), !cfg!(feature = "trim_floats"));
+
+        options.set_exponent_default_char(b'x');
+        options.set_exponent_case(LetterCase::Upper);
+        assert_eq!(options.exponent_char(), b'X');
+        options.set_exponent_case(LetterCase::Lower);
+        assert_eq!(options.exponent_char(), b'x');
+    }
+
+    #[cfg(feature = "format")]
+    #[should_panic]
+    #[test]
+    fn set_format_exponent_char_test() {
+        let mut options = ParseOptions::new();
+        options.set_exponent_default_char(b'^');
+        options.set_format(NumberFormat::compile(b'^', NumberFormat::INTERNAL_DIGIT_SEPARATOR).unwrap());
+    }
+
+    #[test]
+    fn parse_decimal_point_options_test() {
+        let mut options = ParseOptions::new();
+        assert_eq!(options.get_decimal_point(), b'.');
+        options.set_decimal_point(b',');
+        assert_eq!(f64::from_lexical_with_options(b"3,14", &options), Ok(3.14));
+        assert_eq!(f64::from_lexical_with_options(b"-1,5e3", &options), Ok(-1500.0));
+        assert_eq!(f64::from_lexical_with_options(b",5", &options), Ok(0.5));
+        assert_eq!(f64::from_lexical_partial_with_options(b"3.14", &options), Ok((3.0, 1)));
+        assert_eq!(f64::from_lexical_lossy_with_options(b"3,14", &options), Ok(3.14));
+        assert_eq!(f64::from_lexical_with_options(b",", &options), Err((ErrorCode::EmptyFraction, 0).into()));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn parse_group_separator_options_test() {
+        let mut options = ParseOptions::new();
+        assert_eq!(options.get_group_separator(), 0);
+        options.set_decimal_point(b',');
+        options.set_group_separator(b'.');
+        assert_eq!(f64::from_lexical_with_options(b"1.234,56", &options), Ok(1234.56));
+        assert_eq!(f64::from_lexical_with_options(b"1.234.567", &options), Ok(1234567.0));
+        assert_eq!(u32::from_lexical_with_options(b"1.234", &options), Ok(1234));
+        assert_eq!(f64::from_lexical_with_options(b"1,234.5", &options), Err((ErrorCode::InvalidDigit, 5).into()));
+        assert_eq!(f64::from_lexical_with_options(b".1", &options), Err((ErrorCode::EmptyFraction, 0).into()));
+        assert_eq!(u32::from_lexical_with_options(b"1..234", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+
+        options.set_group_separator(b' ');
+        options.set_decimal_point(b'.');
+        assert_eq!(f64::from_lexical_with_options(b"1 234 567.5", &options), Ok(1234567.5));
+        options.set_group_separator(0);
+        assert_eq!(f64::from_lexical_with_options(b"1 234", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_decimal_point_exponent_char_test() {
+        ParseOptions::new().set_decimal_point(b'e');
+    }
+
+    #[cfg(feature = "format")]
+    #[should_panic]
+    #[test]
+    fn set_group_separator_decimal_point_test() {
+        ParseOptions::new().set_group_separator(b'.');
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_parse_nan_string_invalid_test() {
+        ParseOptions::new().set_nan_string(b"i");
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_parse_inf_string_long_test() {
+        ParseOptions::new().set_inf_string(b"infinityinfinf");
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_parse_infinity_string_short_test() {
+        ParseOptions::new().set_infinity_string(b"i");
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_write_exponent_default_char_digit_test() {
+        WriteOptions::new().set_exponent_default_char(b'0');
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_write_exponent_default_char_non_ascii_test() {
+        WriteOptions::new().set_exponent_default_char(b'\xE2');
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_write_group_separator_decimal_point_test() {
+        WriteOptions::new().set_group_separator(b'.');
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_write_group_sizes_invalid_test() {
+        WriteOptions::new().set_group_sizes(3, 0);
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_write_inf_string_empty_test() {
+        WriteOptions::new().set_inf_string(b"");
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_write_inf_string_non_ascii_test() {
+        WriteOptions::new().set_inf_string("∞".as_bytes());
+    }
+
+    #[should_panic]
+    #[test]
+    fn set_write_nan_string_non_ascii_test() {
+        WriteOptions::new().set_nan_string(b"NaN\xFF");
+    }
+
+    #[cfg(feature = "radix")]
+    #[should_panic]
+    #[test]
+    fn set_radix_invalid_test() {
+        WriteOptions::new().set_radix(37);
+    }
+}
@@ -0,0 +1,151 @@
+//! Width, alignment and zero padding for number writers.
+
+use super::algorithm::write_bytes;
+use super::options::WriteOptions;
+
+/// Alignment of a written number within the minimum width.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Alignment {
+    /// Write fill characters after the number, such as `42  `.
+    Left,
+    /// Write fill characters before the number, such as `  42`.
+    Right,
+    /// Write fill characters on both sides of the number, such as ` 42 `.
+    ///
+    /// Any odd fill character is written after the number.
+    Center,
+}
+
+// PADDING
+
+/// Fill characters and zeros required to pad a written number.
+pub(crate) struct Padding {
+    /// Number of fill characters before the number.
+    pub(crate) before: usize,
+    /// Number of zeros after the sign of the number.
+    pub(crate) zeros: usize,
+    /// Number of fill characters after the number.
+    pub(crate) after: usize,
+}
+
+impl Padding {
+    /// Get the total number of padding bytes.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.before + self.zeros + self.after
+    }
+}
+
+// Get the padding for a number of `len` bytes from the write options.
+//
+// Numbers are padded with zeros after the sign if zero padding is set,
+// and `can_zero_pad` is true, and otherwise with fill characters
+// around the number.
+perftools_inline!{
+pub(crate) fn padding(len: usize, can_zero_pad: bool, options: &WriteOptions)
+    -> Padding
+{
+    let count = options.width.saturating_sub(len);
+    if options.zero_pad && can_zero_pad {
+        return Padding { before: 0, zeros: count, after: 0 };
+    }
+    match options.alignment {
+        Alignment::Left     => Padding { before: 0, zeros: 0, after: count },
+        Alignment::Right    => Padding { before: count, zeros: 0, after: 0 },
+        Alignment::Center   => Padding { before: count / 2, zeros: 0, after: count - count / 2 },
+    }
+}}
+
+// Pad a number written to the start of the buffer, in place.
+//
+// `bytes[..len]` contains the written number, the first `prefix` bytes
+// of which are the sign and any base prefix, which are written before
+// the zeros. The bytes are shifted right, without `copy_within`, which
+// requires Rustc 1.37, and the buffer must have room for the width.
+// Returns the new length of the written number.
+perftools_inline!{
+pub(crate) fn pad_in_place(bytes: &mut [u8], len: usize, prefix: usize, can_zero_pad: bool, options: &WriteOptions)
+    -> usize
+{
+    debug_assert!(prefix <= len);
+    let padding = padding(len, can_zero_pad, options);
+    if padding.len() == 0 {
+        return len;
+    }
+
+    // Shift the digits after the prefix, and then the prefix.
+    let mut src = len;
+    while src > prefix {
+        src -= 1;
+        index_mut!(bytes[src + padding.before + padding.zeros] = index!(bytes[src]));
+    }
+    while src > 0 {
+        src -= 1;
+        index_mut!(bytes[src + padding.before] = index!(bytes[src]));
+    }
+
+    let zeros = padding.before + prefix;
+    write_bytes(&mut index_mut!(bytes[..padding.before]), options.fill);
+    write_bytes(&mut index_mut!(bytes[zeros..zeros + padding.zeros]), b'0');
+    let end = len + padding.len();
+    write_bytes(&mut index_mut!(bytes[end - padding.after..end]), options.fill);
+    end
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(input: &[u8], prefix: usize, can_zero_pad: bool, options: &WriteOptions, expected: &[u8]) {
+        let mut buffer = [b'\0'; 64];
+        buffer[..input.len()].copy_from_slice(input);
+        let len = pad_in_place(&mut buffer, input.len(), prefix, can_zero_pad, options);
+        assert_eq!(&buffer[..len], expected);
+    }
+
+    fn split(len: usize, can_zero_pad: bool, options: &WriteOptions) -> (usize, usize, usize) {
+        let padding = padding(len, can_zero_pad, options);
+        (padding.before, padding.zeros, padding.after)
+    }
+
+    #[test]
+    fn padding_test() {
+        let mut options = WriteOptions::new();
+        options.set_width(6);
+        assert_eq!(split(3, true, &options), (3, 0, 0));
+        assert_eq!(split(8, true, &options), (0, 0, 0));
+
+        options.set_alignment(Alignment::Center);
+        assert_eq!(split(3, true, &options), (1, 0, 2));
+
+        options.set_zero_pad(true);
+        assert_eq!(split(3, true, &options), (0, 3, 0));
+        assert_eq!(split(3, false, &options), (1, 0, 2));
+    }
+
+    #[test]
+    fn pad_in_place_test() {
+        let mut options = WriteOptions::new();
+        options.set_width(6);
+        check(b"-1.5", 1, true, &options, b"  -1.5");
+        check(b"-123456", 1, true, &options, b"-123456");
+
+        options.set_fill(b'*');
+        options.set_alignment(Alignment::Left);
+        check(b"-1.5", 1, true, &options, b"-1.5**");
+
+        options.set_alignment(Alignment::Center);
+        check(b"inf", 0, false, &options, b"*inf**");
+
+        options.set_zero_pad(true);
+        check(b"-1.5", 1, true, &options, b"-001.5");
+        check(b"1.5", 0, true, &options, b"0001.5");
+        check(b"-inf", 1, false, &options, b"*-inf*");
+
+        options.set_width(9);
+        check(b"-0x1p+0", 3, true, &options, b"-0x001p+0");
+    }
+}
@@ -1,6 +1,7 @@
 //! FLoating point power utilities.
 
 use super::cast::*;
+use super::half::*;
 use super::num::*;
 use super::table::*;
 
@@ -17,6 +18,12 @@ mod private {
     pub(crate) trait StablePowerImpl: Float + ExactExponent + TablePower {
     }
 
+    impl StablePowerImpl for f16 {
+    }
+
+    impl StablePowerImpl for bf16 {
+    }
+
     impl StablePowerImpl for f32 {
     }
 
@@ -145,6 +152,53 @@ pub(crate) trait StablePower: private::StablePowerImpl {
     }
 }
 
+// F16
+
+impl StablePower for f16 {
+    fn iterative_max<T: Integer>(radix: T) -> i32 {
+        // Cached max exponents.
+        // Make sure the value is >= 2*log(2^24, radix), which guarantees the
+        // value overflows or underflows.
+        const MAX: [i32; 35] = [
+            48, 31, 24, 21, 19, 18, 16, 16, 15, 14, 14, 13,
+            13, 13, 12, 12, 12, 12, 12, 11, 11, 11, 11, 11,
+            11, 11, 10, 10, 10, 10, 10, 10, 10, 10, 10
+        ];
+
+        debug_assert_radix!(radix);
+        let idx: usize = as_cast(radix.as_i32() - 2);
+        MAX[idx]
+    }
+
+    fn iterative_step<T: Integer>(radix: T) -> i32 {
+        // Cached powers to get the desired exponent.
+        // Make sure all values are <= 65504, the largest finite value.
+        const STEP: [i32; 35] = [
+            15, 10, 7, 6, 6, 5, 5, 5, 4, 4, 4, 4,
+            4, 4, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3
+        ];
+
+        debug_assert_radix!(radix);
+        let idx: usize = as_cast(radix.as_i32() - 2);
+        STEP[idx]
+    }
+}
+
+// BF16
+
+impl StablePower for bf16 {
+    fn iterative_max<T: Integer>(radix: T) -> i32 {
+        // Same exponent range as an f32.
+        f32::iterative_max(radix)
+    }
+
+    fn iterative_step<T: Integer>(radix: T) -> i32 {
+        // Same exponent range as an f32.
+        f32::iterative_step(radix)
+    }
+}
+
 // F32
 
 impl StablePower for f32 {
@@ -0,0 +1,142 @@
+//! Base prefixes for non-decimal number parsing.
+
+use lib::result::Result as StdResult;
+use super::error::ErrorCode;
+use super::options::ParseOptions;
+
+/// Handling of base prefixes, such as `0x`, during number parsing.
+///
+/// Base prefixes occur after the sign, and are `0b` for binary, `0o` for
+/// octal, and `0x` for hexadecimal numbers. Radixes other than 2, 8 and
+/// 16 do not have a base prefix, and parse as if base prefixes are disabled.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BasePrefix {
+    /// Base prefixes are not allowed.
+    Disabled,
+    /// Base prefix for the radix is allowed, but not required.
+    Optional,
+    /// Base prefix for the radix is required.
+    Required,
+    /// Radix is detected from the base prefix, like `strtol` with a base of 0.
+    ///
+    /// Numbers without a base prefix are decimal, except integers with a
+    /// leading `0`, which are parsed as C-style octal numbers, like `017`.
+    /// The radix of the options is ignored.
+    Detect,
+}
+
+/// Get the base prefix character for the radix, or `0` if the radix has no base prefix.
+#[inline]
+pub(crate) fn base_prefix_char(radix: u32) -> u8 {
+    match radix {
+        2  => b'b',
+        8  => b'o',
+        16 => b'x',
+        _  => 0,
+    }
+}
+
+/// Get the radix for the base prefix character, or `0` if it is not a base prefix.
+#[inline]
+pub(crate) fn base_prefix_radix(ch: u8) -> u32 {
+    match ch {
+        b'b' => 2,
+        b'o' => 8,
+        b'x' => 16,
+        _    => 0,
+    }
+}
+
+// PARSER
+
+// Parse the base prefix from the digits after the sign.
+//
+// Returns the radix for the digits, and the digits after the base
+// prefix. C-style octal numbers keep the leading `0`, since it is
+// a valid digit, and are only detected for integers.
+perftools_inline!{
+pub(crate) fn parse_base_prefix<'a>(digits: &'a [u8], options: &ParseOptions, is_integer: bool)
+    -> StdResult<(u32, &'a [u8]), (ErrorCode, *const u8)>
+{
+    // Get the radix of the base prefix, or 0 if there is no base prefix.
+    let prefix_radix = match (digits.first(), digits.get(1)) {
+        (Some(&b'0'), Some(&c)) if options.prefix_case_sensitive => base_prefix_radix(c),
+        (Some(&b'0'), Some(&c)) => base_prefix_radix(c.to_ascii_lowercase()),
+        _ => 0,
+    };
+
+    let radix = options.radix;
+    let radix = match options.base_prefix {
+        BasePrefix::Disabled => return Ok((radix, digits)),
+        BasePrefix::Optional if prefix_radix != radix => return Ok((radix, digits)),
+        BasePrefix::Required if prefix_radix != radix && base_prefix_char(radix) != 0 => {
+            return Err((ErrorCode::InvalidBasePrefix, digits.as_ptr()));
+        },
+        BasePrefix::Required if prefix_radix != radix => return Ok((radix, digits)),
+        BasePrefix::Detect if prefix_radix == 0 => {
+            let is_octal = is_integer && digits.len() > 1 && index!(digits[0]) == b'0';
+            return Ok((if is_octal { 8 } else { 10 }, digits));
+        },
+        BasePrefix::Detect => prefix_radix,
+        _ => radix,
+    };
+
+    // Have a valid base prefix, filter out empty digits after the prefix.
+    let digits = &index!(digits[2..]);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+    Ok((radix, digits))
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_prefix_char_test() {
+        assert_eq!(base_prefix_char(2), b'b');
+        assert_eq!(base_prefix_char(8), b'o');
+        assert_eq!(base_prefix_char(10), 0);
+        assert_eq!(base_prefix_char(16), b'x');
+        assert_eq!(base_prefix_char(36), 0);
+    }
+
+    #[test]
+    fn base_prefix_radix_test() {
+        for &radix in [2, 8, 16].iter() {
+            assert_eq!(base_prefix_radix(base_prefix_char(radix)), radix);
+        }
+        assert_eq!(base_prefix_radix(b'X'), 0);
+        assert_eq!(base_prefix_radix(b'd'), 0);
+    }
+
+    #[test]
+    fn parse_base_prefix_test() {
+        let mut options = ParseOptions::new();
+        options.set_base_prefix(BasePrefix::Detect);
+        assert_eq!(parse_base_prefix(b"0x1F", &options, true), Ok((16, &b"1F"[..])));
+        assert_eq!(parse_base_prefix(b"0B1", &options, true), Ok((2, &b"1"[..])));
+        assert_eq!(parse_base_prefix(b"017", &options, true), Ok((8, &b"017"[..])));
+        assert_eq!(parse_base_prefix(b"017", &options, false), Ok((10, &b"017"[..])));
+        assert_eq!(parse_base_prefix(b"0", &options, true), Ok((10, &b"0"[..])));
+        assert_eq!(parse_base_prefix(b"0x", &options, true).map_err(|e| e.0), Err(ErrorCode::Empty));
+
+        options.set_prefix_case_sensitive(true);
+        assert_eq!(parse_base_prefix(b"0X1F", &options, false), Ok((10, &b"0X1F"[..])));
+
+        let mut options = ParseOptions::new();
+        options.set_radix(2);
+        options.set_base_prefix(BasePrefix::Required);
+        assert_eq!(parse_base_prefix(b"0b1", &options, true), Ok((2, &b"1"[..])));
+        assert_eq!(parse_base_prefix(b"0x1", &options, true).map_err(|e| e.0), Err(ErrorCode::InvalidBasePrefix));
+        assert_eq!(parse_base_prefix(b"1", &options, true).map_err(|e| e.0), Err(ErrorCode::InvalidBasePrefix));
+
+        options.set_base_prefix(BasePrefix::Optional);
+        assert_eq!(parse_base_prefix(b"0b1", &options, true), Ok((2, &b"1"[..])));
+        assert_eq!(parse_base_prefix(b"1", &options, true), Ok((2, &b"1"[..])));
+    }
+}
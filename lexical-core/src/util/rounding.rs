@@ -7,7 +7,9 @@
 /// Defines the IEEE754 rounding scheme to be used during float parsing.
 /// In general, this should be set to `NearestTieEven`, the default
 /// recommended rounding scheme by IEEE754 for binary and decimal
-/// operations.
+/// operations. When writing floats, it defines how digits removed by
+/// a fixed precision or a maximum number of significant digits are
+/// rounded.
 ///
 /// # FFI
 ///
@@ -9,7 +9,7 @@ use super::pointer_methods::PointerMethods;
 use super::range_bounds::RangeBounds;
 use super::slice_index::SliceIndex;
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 use lib::Vec;
 
 // ARRVEC
@@ -285,7 +285,7 @@ impl<T> SliceLikeImpl<T> for [T] {
     }
 }
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 impl<T> SliceLikeImpl<T> for Vec<T> {
     // AS SLICE
 
@@ -974,7 +974,7 @@ impl<T> SliceLike<T> for [T] {
     }
 }
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 impl<T> SliceLike<T> for Vec<T> {
     // GET
 
@@ -1250,7 +1250,7 @@ pub trait VecLike<T>:
     fn remove_many<R: RangeBounds<usize>>(&mut self, range: R);
 }
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 impl<T> VecLike<T> for Vec<T> {
     #[inline]
     fn new() -> Vec<T> {
@@ -1430,7 +1430,7 @@ pub trait CloneableVecLike<T: Clone + Copy + Send>: Send + VecLike<T>
     fn resize(&mut self, len: usize, value: T);
 }
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 impl<T> CloneableVecLike<T> for Vec<T>
     where T: Clone + Copy + Send
 {
@@ -1486,7 +1486,7 @@ mod tests {
         assert_eq!(&v[..], &[0, 5, 6, 1, 2, 3]);
     }
 
-    #[cfg(all(feature = "correct", feature = "radix"))]
+    #[cfg(feature = "radix")]
     #[test]
     fn remove_many_test() {
         let mut x = vec![0, 1, 2, 3, 4, 5];
@@ -0,0 +1,170 @@
+//! Temporary buffers for writers that stream into a sink.
+
+use super::config::BUFFER_SIZE;
+use super::error::ErrorCode;
+use super::options::WriteOptions;
+use super::result::Result;
+use super::traits::ToLexical;
+
+// Write a number to a temporary buffer, and pass the bytes to the sink.
+//
+// Uses a stack buffer of `BUFFER_SIZE` bytes, unless the options require
+// a larger buffer, such as for a large fixed precision or minimum width,
+// which is allocated. Without `std`, use `try_write_buffered` with a
+// stack buffer, which fails if the exact number of bytes does not fit.
+perftools_inline!{
+#[cfg(feature = "std")]
+pub(crate) fn write_buffered<N: ToLexical, T, Cb: FnOnce(&[u8]) -> T>(n: N, options: &WriteOptions, cb: Cb)
+    -> T
+{
+    let size = N::buffer_size(options);
+    if size > BUFFER_SIZE {
+        let mut buffer = vec![b'\0'; size];
+        return cb(n.to_lexical_with_options(options, &mut buffer));
+    }
+
+    let mut buffer: [u8; BUFFER_SIZE] = [b'\0'; BUFFER_SIZE];
+    cb(n.to_lexical_with_options(options, &mut buffer))
+}}
+
+// Get the minimum buffer size to write any number of the type in the radix.
+perftools_inline!{
+fn formatted_size<N: ToLexical>(options: &WriteOptions)
+    -> usize
+{
+    #[cfg(feature = "radix")] {
+        if options.radix != 10 {
+            return <N as ToLexical>::FORMATTED_SIZE;
+        }
+    }
+
+    let _ = options;
+    <N as ToLexical>::FORMATTED_SIZE_DECIMAL
+}}
+
+// Write a number to a buffer that may be too small, without panicking.
+//
+// Writes directly to the buffer if it may hold any value, or if it holds
+// the exact number of bytes required and `FORMATTED_SIZE` bytes. A
+// smaller buffer is written through a temporary stack buffer, since the
+// number then fits in `BUFFER_SIZE` bytes.
+perftools_inline!{
+pub(crate) fn try_write_buffered<'a, N: ToLexical>(n: N, options: &WriteOptions, bytes: &'a mut [u8])
+    -> Result<&'a mut [u8]>
+{
+    if bytes.len() >= N::buffer_size(options) {
+        return Ok(n.to_lexical_with_options(options, bytes));
+    }
+
+    let len = n.formatted_len(options);
+    if len > bytes.len() {
+        return Err((ErrorCode::BufferTooSmall, len).into());
+    } else if bytes.len() >= formatted_size::<N>(options) {
+        return Ok(n.to_lexical_with_options(options, bytes));
+    }
+
+    let mut buffer: [u8; BUFFER_SIZE] = [b'\0'; BUFFER_SIZE];
+    let written = n.to_lexical_with_options(options, &mut buffer);
+    debug_assert_eq!(written.len(), len);
+    index_mut!(bytes[..len]).copy_from_slice(written);
+    Ok(&mut index_mut!(bytes[..len]))
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "std"))]
+    use lib::fmt;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_buffered_test() {
+        let options = WriteOptions::new();
+        assert!(write_buffered(-42i32, &options, |bytes| bytes == b"-42"));
+        assert_eq!(write_buffered(1.5f64, &options, |bytes| bytes.len()), 3);
+    }
+
+    #[test]
+    fn try_write_buffered_test() {
+        let mut options = WriteOptions::new();
+        let mut buffer = [b'\0'; 64];
+        assert_eq!(try_write_buffered(-12345i32, &options, &mut buffer[..6]), Ok(&mut b"-12345".clone()[..]));
+        assert_eq!(try_write_buffered(-12345i32, &options, &mut buffer[..5]), Err((ErrorCode::BufferTooSmall, 6).into()));
+        assert_eq!(try_write_buffered(1.5f64, &options, &mut buffer[..3]), Ok(&mut b"1.5".clone()[..]));
+        assert_eq!(try_write_buffered(1.5f64, &options, &mut buffer[..0]), Err((ErrorCode::BufferTooSmall, 3).into()));
+
+        options.set_width(8);
+        assert_eq!(try_write_buffered(42u8, &options, &mut buffer[..8]), Ok(&mut b"      42".clone()[..]));
+        assert_eq!(try_write_buffered(42u8, &options, &mut buffer[..7]), Err((ErrorCode::BufferTooSmall, 8).into()));
+    }
+
+    #[test]
+    fn try_write_buffered_fixed_test() {
+        let mut options = WriteOptions::new();
+        options.set_fixed_precision(Some(2));
+        let mut buffer = [b'\0'; 8];
+        assert_eq!(try_write_buffered(-1.5f64, &options, &mut buffer), Ok(&mut b"-1.50".clone()[..]));
+        assert_eq!(try_write_buffered(1e10f64, &options, &mut buffer), Err((ErrorCode::BufferTooSmall, 14).into()));
+    }
+
+    #[test]
+    fn try_write_buffered_large_test() {
+        let mut options = WriteOptions::new();
+        options.set_fixed_precision(Some(300));
+        assert!(f64::buffer_size(&options) > BUFFER_SIZE);
+        let mut buffer = [b'\0'; 400];
+        assert_eq!(try_write_buffered(1.5f64, &options, &mut buffer).map(|b| b.len()), Ok(302));
+        assert_eq!(try_write_buffered(1e100f64, &options, &mut buffer), Err((ErrorCode::BufferTooSmall, 402).into()));
+
+        options.set_fixed_precision(None);
+        options.set_width(300);
+        assert_eq!(try_write_buffered(42u8, &options, &mut buffer[..8]), Err((ErrorCode::BufferTooSmall, 300).into()));
+        assert_eq!(try_write_buffered(42u8, &options, &mut buffer[..300]).map(|b| b.len()), Ok(300));
+    }
+
+    #[cfg(not(feature = "std"))]
+    struct Sink {
+        buffer: [u8; 512],
+        len: usize,
+    }
+
+    #[cfg(not(feature = "std"))]
+    impl fmt::Write for Sink {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let len = self.len + s.len();
+            self.buffer[self.len..len].copy_from_slice(s.as_bytes());
+            self.len = len;
+            Ok(())
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn write_to_fmt_stack_test() {
+        let mut options = WriteOptions::new();
+        options.set_fixed_precision(Some(2));
+        let mut sink = Sink { buffer: [b'\0'; 512], len: 0 };
+        assert_eq!(::write_to_fmt(-1.5f64, &options, &mut sink), Ok(5));
+        assert_eq!(::write_to_fmt(1e300f64, &options, &mut sink), Err(fmt::Error));
+        assert_eq!(&sink.buffer[..sink.len], b"-1.50");
+
+        options.set_fixed_precision(None);
+        options.set_width(300);
+        assert_eq!(::write_to_fmt(42u8, &options, &mut sink), Err(fmt::Error));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_buffered_heap_test() {
+        let mut options = WriteOptions::new();
+        options.set_width(1000);
+        let len = write_buffered(42u8, &options, |bytes| {
+            assert!(bytes.iter().take(998).all(|&c| c == b' '));
+            bytes.len()
+        });
+        assert_eq!(len, 1000);
+    }
+}
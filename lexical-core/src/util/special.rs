@@ -0,0 +1,100 @@
+//! Alternative spellings of special values, such as `1.#INF`.
+
+use super::algorithm::{case_insensitive_equal_to_slice, equal_to_slice};
+use super::options::ParseOptions;
+
+/// Kind of special float value denoted by an alias.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SpecialKind {
+    /// Not a Number.
+    NaN,
+    /// Infinity.
+    Infinity,
+}
+
+/// Alternative spelling of a special value, such as `1.#INF` or `∞`.
+///
+/// Aliases are matched after the sign, and must span the rest of the
+/// input, like the NaN and infinity strings. Therefore, `-1.#IND` is
+/// parsed as a negative NaN with the `1.#IND` alias. Aliases may start
+/// with any character, including digits and non-ASCII characters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SpecialAlias {
+    /// Characters of the alias.
+    pub alias: &'static [u8],
+    /// Special value denoted by the alias.
+    pub kind: SpecialKind,
+}
+
+// Create a special alias (for use in the presets).
+macro_rules! special_alias {
+    ($alias:expr, $kind:ident) => (
+        SpecialAlias { alias: $alias, kind: SpecialKind::$kind }
+    );
+}
+
+impl SpecialAlias {
+    /// MSVC runtime special values, such as `1.#INF` and `-1.#IND`.
+    pub const MSVC: &'static [SpecialAlias] = &[
+        special_alias!(b"1.#INF", Infinity),
+        special_alias!(b"1.#QNAN", NaN),
+        special_alias!(b"1.#SNAN", NaN),
+        special_alias!(b"1.#IND", NaN),
+    ];
+
+    /// Mathematical infinity symbol, `∞`, in UTF-8.
+    pub const SYMBOLS: &'static [SpecialAlias] = &[
+        special_alias!(b"\xE2\x88\x9E", Infinity),
+    ];
+}
+
+/// Determine if the bytes are a special string, respecting the case sensitivity.
+#[inline]
+pub(crate) fn is_special_string(bytes: &[u8], string: &[u8], options: &ParseOptions) -> bool {
+    if options.special_case_sensitive {
+        equal_to_slice(bytes, string)
+    } else {
+        case_insensitive_equal_to_slice(bytes, string)
+    }
+}
+
+// Find the kind of special value denoted by an alias spanning the bytes.
+perftools_inline!{
+pub(crate) fn find_special_alias(bytes: &[u8], options: &ParseOptions)
+    -> Option<SpecialKind>
+{
+    options.special_aliases.iter()
+        .find(|alias| is_special_string(bytes, alias.alias, options))
+        .map(|alias| alias.kind)
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALIASES: &'static [SpecialAlias] = &[
+        special_alias!(b"1.#INF", Infinity),
+        special_alias!(b"NA", NaN),
+    ];
+
+    #[test]
+    fn find_special_alias_test() {
+        let mut options = ParseOptions::new();
+        assert_eq!(find_special_alias(b"1.#INF", &options), None);
+
+        options.set_special_aliases(ALIASES);
+        assert_eq!(find_special_alias(b"1.#INF", &options), Some(SpecialKind::Infinity));
+        assert_eq!(find_special_alias(b"1.#inf", &options), Some(SpecialKind::Infinity));
+        assert_eq!(find_special_alias(b"na", &options), Some(SpecialKind::NaN));
+        assert_eq!(find_special_alias(b"NAN", &options), None);
+        assert_eq!(find_special_alias(b"1.#IN", &options), None);
+
+        options.set_special_case_sensitive(true);
+        assert_eq!(find_special_alias(b"1.#INF", &options), Some(SpecialKind::Infinity));
+        assert_eq!(find_special_alias(b"1.#inf", &options), None);
+        assert_eq!(find_special_alias(b"na", &options), None);
+    }
+}
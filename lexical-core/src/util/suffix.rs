@@ -0,0 +1,346 @@
+//! Literal type suffixes, such as `u32` in `10u32`.
+
+use super::cast::{as_cast, AsCast};
+use super::error::ErrorCode;
+use super::options::ParseOptions;
+use super::result::Result;
+use super::traits::FromLexical;
+use super::whitespace::{whitespace_count, Whitespace};
+
+// Number of bits in a pointer-sized integer.
+#[cfg(target_pointer_width = "16")]
+const POINTER_BITS: u32 = 16;
+#[cfg(target_pointer_width = "32")]
+const POINTER_BITS: u32 = 32;
+#[cfg(target_pointer_width = "64")]
+const POINTER_BITS: u32 = 64;
+
+/// Numeric type denoted by a literal type suffix.
+///
+/// The width is the number of bits in the type, and must be the width
+/// of a primitive type to validate the value against the type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SuffixType {
+    /// Signed integer with the number of bits.
+    Signed(u32),
+    /// Unsigned integer with the number of bits.
+    Unsigned(u32),
+    /// Float with the number of bits.
+    Float(u32),
+}
+
+impl SuffixType {
+    /// Determine if every value of the suffix type may be stored in `ty`.
+    #[inline]
+    pub fn fits(self, ty: SuffixType) -> bool {
+        match (self, ty) {
+            (SuffixType::Signed(x), SuffixType::Signed(y))     => x <= y,
+            (SuffixType::Unsigned(x), SuffixType::Unsigned(y)) => x <= y,
+            (SuffixType::Float(x), SuffixType::Float(y))       => x <= y,
+            _ => false,
+        }
+    }
+}
+
+/// Literal type suffix, such as `u32` in `10u32`.
+///
+/// Type suffixes are matched exactly after the digits, so `1f32` in
+/// radix 16 is an integer without a suffix, like in Rust.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TypeSuffix {
+    /// Characters of the suffix.
+    pub suffix: &'static [u8],
+    /// Numeric type denoted by the suffix.
+    pub suffix_type: SuffixType,
+}
+
+// Create a type suffix (for use in the presets).
+macro_rules! type_suffix {
+    ($suffix:expr, $kind:ident($bits:expr)) => (
+        TypeSuffix { suffix: $suffix, suffix_type: SuffixType::$kind($bits) }
+    );
+}
+
+impl TypeSuffix {
+    /// Rust literal type suffixes, such as `10u32` and `1.5f32`.
+    pub const RUST: &'static [TypeSuffix] = &[
+        type_suffix!(b"i8", Signed(8)),
+        type_suffix!(b"i16", Signed(16)),
+        type_suffix!(b"i32", Signed(32)),
+        type_suffix!(b"i64", Signed(64)),
+        type_suffix!(b"i128", Signed(128)),
+        type_suffix!(b"isize", Signed(POINTER_BITS)),
+        type_suffix!(b"u8", Unsigned(8)),
+        type_suffix!(b"u16", Unsigned(16)),
+        type_suffix!(b"u32", Unsigned(32)),
+        type_suffix!(b"u64", Unsigned(64)),
+        type_suffix!(b"u128", Unsigned(128)),
+        type_suffix!(b"usize", Unsigned(POINTER_BITS)),
+        type_suffix!(b"f32", Float(32)),
+        type_suffix!(b"f64", Float(64)),
+    ];
+
+    /// C literal type suffixes, such as `100UL` and `1.5f`.
+    ///
+    /// Assumes the LP64 data model, where `int` is 32 bits, and `long`
+    /// and `long long` are 64 bits. The `long double` suffix is parsed
+    /// as a 64-bit float.
+    pub const C: &'static [TypeSuffix] = &[
+        type_suffix!(b"u", Unsigned(32)),
+        type_suffix!(b"U", Unsigned(32)),
+        type_suffix!(b"l", Signed(64)),
+        type_suffix!(b"L", Signed(64)),
+        type_suffix!(b"ll", Signed(64)),
+        type_suffix!(b"LL", Signed(64)),
+        type_suffix!(b"ul", Unsigned(64)),
+        type_suffix!(b"uL", Unsigned(64)),
+        type_suffix!(b"Ul", Unsigned(64)),
+        type_suffix!(b"UL", Unsigned(64)),
+        type_suffix!(b"lu", Unsigned(64)),
+        type_suffix!(b"lU", Unsigned(64)),
+        type_suffix!(b"Lu", Unsigned(64)),
+        type_suffix!(b"LU", Unsigned(64)),
+        type_suffix!(b"ull", Unsigned(64)),
+        type_suffix!(b"uLL", Unsigned(64)),
+        type_suffix!(b"Ull", Unsigned(64)),
+        type_suffix!(b"ULL", Unsigned(64)),
+        type_suffix!(b"llu", Unsigned(64)),
+        type_suffix!(b"llU", Unsigned(64)),
+        type_suffix!(b"LLu", Unsigned(64)),
+        type_suffix!(b"LLU", Unsigned(64)),
+        type_suffix!(b"f", Float(32)),
+        type_suffix!(b"F", Float(32)),
+        type_suffix!(b"l", Float(64)),
+        type_suffix!(b"L", Float(64)),
+    ];
+
+    /// Java literal type suffixes, such as `100L` and `2.0d`.
+    pub const JAVA: &'static [TypeSuffix] = &[
+        type_suffix!(b"l", Signed(64)),
+        type_suffix!(b"L", Signed(64)),
+        type_suffix!(b"f", Float(32)),
+        type_suffix!(b"F", Float(32)),
+        type_suffix!(b"d", Float(64)),
+        type_suffix!(b"D", Float(64)),
+    ];
+
+    /// Kotlin literal type suffixes, such as `100L`, `10u` and `1.5f`.
+    pub const KOTLIN: &'static [TypeSuffix] = &[
+        type_suffix!(b"L", Signed(64)),
+        type_suffix!(b"u", Unsigned(32)),
+        type_suffix!(b"U", Unsigned(32)),
+        type_suffix!(b"uL", Unsigned(64)),
+        type_suffix!(b"UL", Unsigned(64)),
+        type_suffix!(b"f", Float(32)),
+        type_suffix!(b"F", Float(32)),
+    ];
+}
+
+// PARSER
+
+// Find the longest type suffix at the start of the bytes.
+//
+// Suffixes with the same spelling, like C's `L`, may denote different
+// types, so prefer the suffix that fits the parsed type.
+fn find_suffix(bytes: &[u8], suffixes: &[TypeSuffix], ty: SuffixType)
+    -> Option<TypeSuffix>
+{
+    let mut result: Option<TypeSuffix> = None;
+    for &suffix in suffixes.iter() {
+        if suffix.suffix.is_empty() || !bytes.starts_with(suffix.suffix) {
+            continue;
+        }
+        result = match result {
+            Some(r) if r.suffix.len() > suffix.suffix.len() => Some(r),
+            Some(r) if r.suffix.len() == suffix.suffix.len() && r.suffix_type.fits(ty) => Some(r),
+            _ => Some(suffix),
+        };
+    }
+    result
+}
+
+// Parse the value as type `U` and convert it to `T`.
+perftools_inline!{
+fn parse_cast<U, T>(bytes: &[u8], options: &ParseOptions)
+    -> Result<T>
+    where U: FromLexical,
+          T: AsCast
+{
+    U::from_lexical_partial_with_options(bytes, options).map(|(value, _)| as_cast(value))
+}}
+
+// Parse the value as the type denoted by the suffix.
+//
+// The suffix type must be narrower than `T`, so the value is
+// validated against the suffix type, and floats are only rounded once.
+fn parse_suffix_type<T>(bytes: &[u8], options: &ParseOptions, suffix_type: SuffixType, value: T)
+    -> Result<T>
+    where T: AsCast
+{
+    match suffix_type {
+        SuffixType::Signed(8)    => parse_cast::<i8, T>(bytes, options),
+        SuffixType::Signed(16)   => parse_cast::<i16, T>(bytes, options),
+        SuffixType::Signed(32)   => parse_cast::<i32, T>(bytes, options),
+        SuffixType::Signed(64)   => parse_cast::<i64, T>(bytes, options),
+        SuffixType::Unsigned(8)  => parse_cast::<u8, T>(bytes, options),
+        SuffixType::Unsigned(16) => parse_cast::<u16, T>(bytes, options),
+        SuffixType::Unsigned(32) => parse_cast::<u32, T>(bytes, options),
+        SuffixType::Unsigned(64) => parse_cast::<u64, T>(bytes, options),
+        SuffixType::Float(32)    => parse_cast::<f32, T>(bytes, options),
+        _                        => Ok(value),
+    }
+}
+
+/// Parse a value and the literal type suffix after the digits.
+///
+/// `ty` is the numeric type of `T`. The suffix must fit the parsed
+/// type, and the value must fit the type denoted by the suffix.
+pub(crate) fn parse_partial_with_suffix<T>(bytes: &[u8], options: &ParseOptions, ty: SuffixType)
+    -> Result<(T, usize, Option<TypeSuffix>)>
+    where T: FromLexical
+{
+    // The suffix must directly follow the number, so skip trailing
+    // whitespace after the suffix instead.
+    let mut number_options = *options;
+    number_options.whitespace = match options.whitespace.is_leading() {
+        true  => Whitespace::Leading,
+        false => Whitespace::Disabled,
+    };
+    let (value, count) = T::from_lexical_partial_with_options(bytes, &number_options)?;
+    let trailing = | count: usize | match options.whitespace.is_trailing() {
+        true  => count + whitespace_count(&index!(bytes[count..]), options.whitespace_set),
+        false => count,
+    };
+    let suffix = match find_suffix(&index!(bytes[count..]), options.type_suffixes, ty) {
+        Some(suffix) => suffix,
+        None         => return Ok((value, trailing(count), None)),
+    };
+
+    if !suffix.suffix_type.fits(ty) {
+        return Err((ErrorCode::InvalidTypeSuffix, count).into());
+    }
+    let value = match suffix.suffix_type == ty {
+        true  => value,
+        false => parse_suffix_type(&index!(bytes[..count]), options, suffix.suffix_type, value)?,
+    };
+    if is_float_overflow(&index!(bytes[..count]), &number_options, suffix.suffix_type, value) {
+        return Err((ErrorCode::Overflow, count).into());
+    }
+    Ok((value, trailing(count + suffix.suffix.len()), Some(suffix)))
+}
+
+// Determine if a finite float literal overflows the suffix type.
+//
+// The value is infinite for both an overflowing literal and a special
+// value, so the literal is parsed again without special values.
+fn is_float_overflow<T>(bytes: &[u8], options: &ParseOptions, suffix_type: SuffixType, value: T)
+    -> bool
+    where T: AsCast
+{
+    match suffix_type {
+        SuffixType::Float(_) if as_cast::<f64, T>(value).is_infinite() => {
+            let mut finite_options = *options;
+            finite_options.no_special = true;
+            match f64::from_lexical_partial_with_options(bytes, &finite_options) {
+                Ok((_, count)) => count == bytes.len(),
+                Err(_)         => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fits_test() {
+        assert!(SuffixType::Signed(32).fits(SuffixType::Signed(64)));
+        assert!(SuffixType::Signed(64).fits(SuffixType::Signed(64)));
+        assert!(!SuffixType::Signed(64).fits(SuffixType::Signed(32)));
+        assert!(!SuffixType::Unsigned(8).fits(SuffixType::Signed(64)));
+        assert!(SuffixType::Float(32).fits(SuffixType::Float(64)));
+        assert!(!SuffixType::Float(32).fits(SuffixType::Signed(32)));
+    }
+
+    #[test]
+    fn find_suffix_test() {
+        let int = SuffixType::Signed(64);
+        let float = SuffixType::Float(64);
+        assert_eq!(find_suffix(b"u8", TypeSuffix::RUST, int).map(|s| s.suffix), Some(&b"u8"[..]));
+        assert_eq!(find_suffix(b"i128;", TypeSuffix::RUST, int).map(|s| s.suffix), Some(&b"i128"[..]));
+        assert_eq!(find_suffix(b"u", TypeSuffix::RUST, int), None);
+        assert_eq!(find_suffix(b"ULL", TypeSuffix::C, int).map(|s| s.suffix), Some(&b"ULL"[..]));
+        assert_eq!(find_suffix(b"L", TypeSuffix::C, int).map(|s| s.suffix_type), Some(SuffixType::Signed(64)));
+        assert_eq!(find_suffix(b"L", TypeSuffix::C, float).map(|s| s.suffix_type), Some(SuffixType::Float(64)));
+        assert_eq!(find_suffix(b"uL", TypeSuffix::KOTLIN, int).map(|s| s.suffix), Some(&b"uL"[..]));
+    }
+
+    fn suffix_of<T: FromLexical>(bytes: &[u8], options: &ParseOptions)
+        -> Result<(T, usize, Option<&'static [u8]>)>
+    {
+        T::from_lexical_partial_with_suffix(bytes, options).map(|(v, n, s)| (v, n, s.map(|s| s.suffix)))
+    }
+
+    #[test]
+    fn parse_partial_with_suffix_test() {
+        let mut options = ParseOptions::new();
+        options.set_type_suffixes(TypeSuffix::RUST);
+        assert_eq!(suffix_of::<u32>(b"10u32", &options), Ok((10, 5, Some(&b"u32"[..]))));
+        assert_eq!(suffix_of::<u64>(b"10u32;", &options), Ok((10, 5, Some(&b"u32"[..]))));
+        assert_eq!(suffix_of::<i32>(b"-10i8", &options), Ok((-10, 5, Some(&b"i8"[..]))));
+        assert_eq!(suffix_of::<u32>(b"10", &options), Ok((10, 2, None)));
+        assert_eq!(suffix_of::<u32>(b"10u", &options), Ok((10, 2, None)));
+        assert_eq!(suffix_of::<f64>(b"1.5f64", &options), Ok((1.5, 6, Some(&b"f64"[..]))));
+        assert_eq!(suffix_of::<f64>(b"1f32", &options), Ok((1.0, 4, Some(&b"f32"[..]))));
+        assert_eq!(suffix_of::<f64>(b"0.1f32", &options), Ok((0.1f32 as f64, 6, Some(&b"f32"[..]))));
+        assert_eq!(suffix_of::<u32>(b"300u8", &options), Err((ErrorCode::Overflow, 2).into()));
+        assert_eq!(suffix_of::<u8>(b"10u32", &options), Err((ErrorCode::InvalidTypeSuffix, 2).into()));
+        assert_eq!(suffix_of::<i32>(b"10u32", &options), Err((ErrorCode::InvalidTypeSuffix, 2).into()));
+        assert_eq!(suffix_of::<i32>(b"10f32", &options), Err((ErrorCode::InvalidTypeSuffix, 2).into()));
+        assert_eq!(suffix_of::<f32>(b"1.5f64", &options), Err((ErrorCode::InvalidTypeSuffix, 3).into()));
+        assert_eq!(suffix_of::<f64>(b"1.5u8", &options), Err((ErrorCode::InvalidTypeSuffix, 3).into()));
+        assert_eq!(suffix_of::<f64>(b"1e40f32", &options), Err((ErrorCode::Overflow, 4).into()));
+
+        options.set_type_suffixes(TypeSuffix::C);
+        assert_eq!(suffix_of::<u64>(b"100ULL", &options), Ok((100, 6, Some(&b"ULL"[..]))));
+        assert_eq!(suffix_of::<i64>(b"100L", &options), Ok((100, 4, Some(&b"L"[..]))));
+        assert_eq!(suffix_of::<f64>(b"1.5L", &options), Ok((1.5, 4, Some(&b"L"[..]))));
+        assert_eq!(suffix_of::<f32>(b"1e5f", &options), Ok((1e5, 4, Some(&b"f"[..]))));
+        assert_eq!(suffix_of::<f32>(b"1e40f", &options), Err((ErrorCode::Overflow, 4).into()));
+
+        options.set_type_suffixes(TypeSuffix::JAVA);
+        assert_eq!(suffix_of::<f64>(b"2.0d", &options), Ok((2.0, 4, Some(&b"d"[..]))));
+        assert_eq!(suffix_of::<i64>(b"100l", &options), Ok((100, 4, Some(&b"l"[..]))));
+        assert_eq!(suffix_of::<i32>(b"100L", &options), Err((ErrorCode::InvalidTypeSuffix, 3).into()));
+        assert_eq!(suffix_of::<f32>(b"1e40f", &options), Err((ErrorCode::Overflow, 4).into()));
+        assert_eq!(suffix_of::<f64>(b"1e40f", &options), Err((ErrorCode::Overflow, 4).into()));
+        assert_eq!(suffix_of::<f64>(b"1e400d", &options), Err((ErrorCode::Overflow, 5).into()));
+        assert_eq!(suffix_of::<f64>(b"1e40d", &options), Ok((1e40, 5, Some(&b"d"[..]))));
+
+        options.set_type_suffixes(TypeSuffix::KOTLIN);
+        assert_eq!(suffix_of::<u64>(b"100uL", &options), Ok((100, 5, Some(&b"uL"[..]))));
+        assert_eq!(suffix_of::<u64>(b"100u", &options), Ok((100, 4, Some(&b"u"[..]))));
+        assert_eq!(suffix_of::<i64>(b"100l", &options), Ok((100, 3, None)));
+
+        options.set_type_suffixes(TypeSuffix::RUST);
+        options.set_whitespace(Whitespace::Both);
+        assert_eq!(suffix_of::<u32>(b" 10u8 ", &options), Ok((10, 6, Some(&b"u8"[..]))));
+        assert_eq!(suffix_of::<u32>(b" 10 u8", &options), Ok((10, 4, None)));
+        assert_eq!(suffix_of::<u32>(b" 300u8 ", &options), Err((ErrorCode::Overflow, 3).into()));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn parse_partial_with_suffix_radix_test() {
+        let mut options = ParseOptions::radix(16);
+        options.set_type_suffixes(TypeSuffix::RUST);
+        assert_eq!(suffix_of::<u32>(b"1Fu8", &options), Ok((0x1F, 4, Some(&b"u8"[..]))));
+        assert_eq!(suffix_of::<u32>(b"1f32", &options), Ok((0x1F32, 4, None)));
+        assert_eq!(suffix_of::<u32>(b"FFFu8", &options), Err((ErrorCode::Overflow, 2).into()));
+    }
+}
@@ -292,6 +292,210 @@ impl ExactExponent for f64 {
     }
 }
 
+impl ExactExponent for f16 {
+    #[inline]
+    fn exponent_limit<T: Integer>(radix: T) -> (i32, i32) {
+        debug_assert_radix!(radix);
+        #[cfg(not(feature = "radix"))] {
+            (-4, 4)
+        }
+
+        #[cfg(feature = "radix")] {
+            match radix.as_i32() {
+                2  => (-24, 15),
+                3  => (-6, 6),
+                4  => (-12, 7),
+                5  => (-4, 4),
+                6  => (-6, 6),
+                7  => (-3, 3),
+                8  => (-8, 5),
+                9  => (-3, 3),
+                10 => (-4, 4),
+                11 => (-3, 3),
+                12 => (-4, 4),
+                13 => (-2, 2),
+                14 => (-3, 3),
+                15 => (-2, 2),
+                16 => (-6, 3),
+                17 => (-2, 2),
+                18 => (-3, 3),
+                19 => (-2, 2),
+                20 => (-3, 3),
+                21 => (-2, 2),
+                22 => (-3, 3),
+                23 => (-2, 2),
+                24 => (-3, 3),
+                25 => (-2, 2),
+                26 => (-2, 2),
+                27 => (-2, 2),
+                28 => (-3, 3),
+                29 => (-2, 2),
+                30 => (-2, 2),
+                31 => (-2, 2),
+                32 => (-4, 3),
+                33 => (-2, 2),
+                34 => (-2, 2),
+                35 => (-2, 2),
+                36 => (-3, 3),
+                // Invalid radix
+                _  => unreachable!(),
+            }
+        }
+    }
+
+    #[inline]
+    fn mantissa_limit<T: Integer>(radix: T) -> i32 {
+        debug_assert_radix!(radix);
+        #[cfg(not(feature = "radix"))] {
+            3
+        }
+
+        #[cfg(feature = "radix")] {
+            match radix.as_i32() {
+                2  => 10,
+                3  => 6,
+                4  => 5,
+                5  => 4,
+                6  => 4,
+                7  => 3,
+                8  => 3,
+                9  => 3,
+                10 => 3,
+                11 => 3,
+                12 => 3,
+                13 => 2,
+                14 => 2,
+                15 => 2,
+                16 => 2,
+                17 => 2,
+                18 => 2,
+                19 => 2,
+                20 => 2,
+                21 => 2,
+                22 => 2,
+                23 => 2,
+                24 => 2,
+                25 => 2,
+                26 => 2,
+                27 => 2,
+                28 => 2,
+                29 => 2,
+                30 => 2,
+                31 => 2,
+                32 => 2,
+                33 => 2,
+                34 => 2,
+                35 => 2,
+                36 => 2,
+                // Invalid radix
+                _  => unreachable!(),
+            }
+        }
+    }
+}
+
+impl ExactExponent for bf16 {
+    #[inline]
+    fn exponent_limit<T: Integer>(radix: T) -> (i32, i32) {
+        debug_assert_radix!(radix);
+        #[cfg(not(feature = "radix"))] {
+            (-3, 3)
+        }
+
+        #[cfg(feature = "radix")] {
+            match radix.as_i32() {
+                2  => (-133, 127),
+                3  => (-5, 5),
+                4  => (-66, 63),
+                5  => (-3, 3),
+                6  => (-5, 5),
+                7  => (-2, 2),
+                8  => (-44, 42),
+                9  => (-2, 2),
+                10 => (-3, 3),
+                11 => (-2, 2),
+                12 => (-5, 5),
+                13 => (-2, 2),
+                14 => (-2, 2),
+                15 => (-2, 2),
+                16 => (-33, 31),
+                17 => (-1, 1),
+                18 => (-2, 2),
+                19 => (-1, 1),
+                20 => (-3, 3),
+                21 => (-1, 1),
+                22 => (-2, 2),
+                23 => (-1, 1),
+                24 => (-5, 5),
+                25 => (-1, 1),
+                26 => (-2, 2),
+                27 => (-1, 1),
+                28 => (-2, 2),
+                29 => (-1, 1),
+                30 => (-2, 2),
+                31 => (-1, 1),
+                32 => (-26, 25),
+                33 => (-1, 1),
+                34 => (-1, 1),
+                35 => (-1, 1),
+                36 => (-2, 2),
+                // Invalid radix
+                _  => unreachable!(),
+            }
+        }
+    }
+
+    #[inline]
+    fn mantissa_limit<T: Integer>(radix: T) -> i32 {
+        debug_assert_radix!(radix);
+        #[cfg(not(feature = "radix"))] {
+            2
+        }
+
+        #[cfg(feature = "radix")] {
+            match radix.as_i32() {
+                2  => 7,
+                3  => 5,
+                4  => 3,
+                5  => 3,
+                6  => 3,
+                7  => 2,
+                8  => 2,
+                9  => 2,
+                10 => 2,
+                11 => 2,
+                12 => 2,
+                13 => 2,
+                14 => 2,
+                15 => 2,
+                16 => 1,
+                17 => 1,
+                18 => 1,
+                19 => 1,
+                20 => 1,
+                21 => 1,
+                22 => 1,
+                23 => 1,
+                24 => 1,
+                25 => 1,
+                26 => 1,
+                27 => 1,
+                28 => 1,
+                29 => 1,
+                30 => 1,
+                31 => 1,
+                32 => 1,
+                33 => 1,
+                34 => 1,
+                35 => 1,
+                36 => 1,
+                // Invalid radix
+                _  => unreachable!(),
+            }
+        }
+    }
+}
+
 // Conditionally compile the radix POWI tables.
 // These tables contain all the values that can be exactly represented
 // by a given float of a certain size.
@@ -3224,4 +3428,41 @@ impl TablePower for f64 {
     }
 }
 
+// F16
+
+// The exact powers for the 16-bit floats are a subset of those for an
+// f32, so convert the f32 powers rather than storing more tables.
+
+impl TablePower for f16 {
+    const POW2_EXPONENT_BIAS: i32 = f32::POW2_EXPONENT_BIAS;
+
+    #[cfg(feature = "radix")]
+    #[inline]
+    fn table_pow2(exponent: i32) -> f16 {
+        f16::from_f32(f32::table_pow2(exponent))
+    }
+
+    #[inline]
+    fn table_pow<T: Integer>(radix: T, exponent: i32) -> f16 {
+        f16::from_f32(f32::table_pow(radix, exponent))
+    }
+}
+
+// BF16
+
+impl TablePower for bf16 {
+    const POW2_EXPONENT_BIAS: i32 = f32::POW2_EXPONENT_BIAS;
+
+    #[cfg(feature = "radix")]
+    #[inline]
+    fn table_pow2(exponent: i32) -> bf16 {
+        bf16::from_f32(f32::table_pow2(exponent))
+    }
+
+    #[inline]
+    fn table_pow<T: Integer>(radix: T, exponent: i32) -> bf16 {
+        bf16::from_f32(f32::table_pow(radix, exponent))
+    }
+}
+
 }}   // cfg_if
@@ -1,12 +1,8 @@
 //! Test utilities.
 
+use arrayvec;
 use super::config::BUFFER_SIZE;
-
-cfg_if! {
-if #[cfg(feature = "correct")] {
-    use arrayvec;
-    use super::sequence::{CloneableVecLike, VecLike};
-}}  // cfg_if
+use super::sequence::{CloneableVecLike, VecLike};
 
 // BASES
 
@@ -40,9 +36,6 @@ pub(crate) fn as_slice<'a, T>(x: &'a [T]) -> &'a [T] {
     x
 }
 
-cfg_if! {
-if #[cfg(feature = "correct")] {
-
 // FROM U32
 
 #[cfg(limb_width_32)]
@@ -84,8 +77,6 @@ pub(crate) fn deduce_from_u32<T: CloneableVecLike<u64>>(x: &[u32]) -> T
     from_u32(x).iter().cloned().collect()
 }
 
-}}  // cfg_if
-
 // FLOATING-POINT EQUALITY
 
 cfg_if! {
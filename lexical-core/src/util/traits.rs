@@ -1,14 +1,18 @@
 //! Wrap the low-level API into idiomatic serializers.
 
+use super::formatted::FormattedBuffer;
 use super::result::Result;
-use super::num::Number;
+use super::num::{Float, Number};
+use super::options::{ParseOptions, WriteOptions};
+use super::primitive::Primitive;
+use super::suffix::TypeSuffix;
 
 // HELPERS
 
 /// Map partial result to complete result.
 macro_rules! to_complete {
-    ($cb:expr, $bytes:expr, $radix:expr) => {
-        match $cb($bytes, $radix) {
+    ($cb:expr, $bytes:expr, $options:expr) => {
+        match $cb($bytes, $options) {
             Err(e)                  => Err(e),
             Ok((value, processed))  => if processed == $bytes.len() {
                 Ok(value)
@@ -88,36 +92,101 @@ pub trait FromLexical: Number {
     /// Panics if the radix is not in the range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_partial_radix(bytes: &[u8], radix: u8) -> Result<(Self, usize)>;
+
+    /// Checked parser for a string-to-number conversion with custom options.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. The options are
+    /// used instead of the global configuration, and therefore this
+    /// method is thread-safe.
+    ///
+    /// Returns a `Result` containing either the parsed value,
+    /// or an error containing any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    fn from_lexical_with_options(bytes: &[u8], options: &ParseOptions) -> Result<Self>;
+
+    /// Checked parser for a string-to-number conversion with custom options.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point. The options are
+    /// used instead of the global configuration, and therefore this
+    /// method is thread-safe.
+    ///
+    /// Returns a `Result` containing either the parsed value
+    /// and the number of processed digits, or an error containing
+    /// any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    fn from_lexical_partial_with_options(bytes: &[u8], options: &ParseOptions) -> Result<(Self, usize)>;
+
+    /// Checked parser for a string-to-number conversion with a literal type suffix.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), and then matches the longest type suffix from
+    /// the options after the digits, such as `u32` in `10u32`. The
+    /// suffix must fit the parsed type, and the parsed value must fit
+    /// the type denoted by the suffix.
+    ///
+    /// Returns a `Result` containing either the parsed value, the
+    /// number of processed bytes including the suffix, and the suffix,
+    /// or an error containing any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    fn from_lexical_partial_with_suffix(bytes: &[u8], options: &ParseOptions) -> Result<(Self, usize, Option<TypeSuffix>)>;
 }
 
 // Implement FromLexical for numeric type.
 macro_rules! from_lexical {
-    ($cb:expr, $t:ty) => (
+    ($cb:expr, $t:ty, $kind:ident) => (
         impl FromLexical for $t {
             #[inline]
             fn from_lexical(bytes: &[u8]) -> Result<$t>
             {
-                to_complete!($cb, bytes, 10)
+                to_complete!($cb, bytes, &ParseOptions::new())
             }
 
             #[inline]
             fn from_lexical_partial(bytes: &[u8]) -> Result<($t, usize)>
             {
-                $cb(bytes, 10)
+                $cb(bytes, &ParseOptions::new())
             }
 
             #[cfg(feature = "radix")]
             #[inline]
             fn from_lexical_radix(bytes: &[u8], radix: u8) -> Result<$t>
             {
-                to_complete!($cb, bytes, radix.as_u32())
+                to_complete!($cb, bytes, &ParseOptions::radix(radix))
             }
 
             #[cfg(feature = "radix")]
             #[inline]
             fn from_lexical_partial_radix(bytes: &[u8], radix: u8) -> Result<($t, usize)>
             {
-                $cb(bytes, radix.as_u32())
+                $cb(bytes, &ParseOptions::radix(radix))
+            }
+
+            #[inline]
+            fn from_lexical_with_options(bytes: &[u8], options: &ParseOptions) -> Result<$t>
+            {
+                to_complete!($cb, bytes, options)
+            }
+
+            #[inline]
+            fn from_lexical_partial_with_options(bytes: &[u8], options: &ParseOptions) -> Result<($t, usize)>
+            {
+                $cb(bytes, options)
+            }
+
+            #[inline]
+            fn from_lexical_partial_with_suffix(bytes: &[u8], options: &ParseOptions) -> Result<($t, usize, Option<TypeSuffix>)>
+            {
+                let bits = mem::size_of::<$t>() as u32 * 8;
+                parse_partial_with_suffix(bytes, options, SuffixType::$kind(bits))
             }
         }
     )
@@ -190,6 +259,38 @@ pub trait FromLexicalLossy: FromLexical {
     /// Panics if the radix is not in the range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_partial_lossy_radix(bytes: &[u8], radix: u8) -> Result<(Self, usize)>;
+
+    /// Lossy, checked parser for a string-to-number conversion with custom options.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. This parser is
+    /// lossy, so numerical rounding may occur during parsing. The
+    /// options are used instead of the global configuration, and
+    /// therefore this method is thread-safe.
+    ///
+    /// Returns a `Result` containing either the parsed value,
+    /// or an error containing any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    fn from_lexical_lossy_with_options(bytes: &[u8], options: &ParseOptions) -> Result<Self>;
+
+    /// Lossy, checked parser for a string-to-number conversion with custom options.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point. This parser is
+    /// lossy, so numerical rounding may occur during parsing. The
+    /// options are used instead of the global configuration, and
+    /// therefore this method is thread-safe.
+    ///
+    /// Returns a `Result` containing either the parsed value
+    /// and the number of processed digits, or an error containing
+    /// any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    fn from_lexical_partial_lossy_with_options(bytes: &[u8], options: &ParseOptions) -> Result<(Self, usize)>;
 }
 
 // Implement FromLexicalLossy for numeric type.
@@ -199,27 +300,39 @@ macro_rules! from_lexical_lossy {
             #[inline]
             fn from_lexical_lossy(bytes: &[u8]) -> Result<$t>
             {
-                to_complete!($cb, bytes, 10)
+                to_complete!($cb, bytes, &ParseOptions::new())
             }
 
             #[inline]
             fn from_lexical_partial_lossy(bytes: &[u8]) -> Result<($t, usize)>
             {
-                $cb(bytes, 10)
+                $cb(bytes, &ParseOptions::new())
             }
 
             #[cfg(feature = "radix")]
             #[inline]
             fn from_lexical_lossy_radix(bytes: &[u8], radix: u8) -> Result<$t>
             {
-                to_complete!($cb, bytes, radix.as_u32())
+                to_complete!($cb, bytes, &ParseOptions::radix(radix))
             }
 
             #[cfg(feature = "radix")]
             #[inline]
             fn from_lexical_partial_lossy_radix(bytes: &[u8], radix: u8) -> Result<($t, usize)>
             {
-                $cb(bytes, radix.as_u32())
+                $cb(bytes, &ParseOptions::radix(radix))
+            }
+
+            #[inline]
+            fn from_lexical_lossy_with_options(bytes: &[u8], options: &ParseOptions) -> Result<$t>
+            {
+                to_complete!($cb, bytes, options)
+            }
+
+            #[inline]
+            fn from_lexical_partial_lossy_with_options(bytes: &[u8], options: &ParseOptions) -> Result<($t, usize)>
+            {
+                $cb(bytes, options)
             }
         }
     )
@@ -230,13 +343,25 @@ macro_rules! from_lexical_lossy {
 /// Trait for numerical types that can be serialized to bytes.
 ///
 /// To determine the number of bytes required to serialize a value to
-/// string, check the associated constants from a required trait:
+/// string, check the associated constants:
 /// - [`FORMATTED_SIZE`]
 /// - [`FORMATTED_SIZE_DECIMAL`]
 ///
-/// [`FORMATTED_SIZE`]: trait.Number.html#associatedconstant.FORMATTED_SIZE
-/// [`FORMATTED_SIZE_DECIMAL`]: trait.Number.html#associatedconstant.FORMATTED_SIZE_DECIMAL
-pub trait ToLexical: Number {
+/// [`FORMATTED_SIZE`]: #associatedconstant.FORMATTED_SIZE
+/// [`FORMATTED_SIZE_DECIMAL`]: #associatedconstant.FORMATTED_SIZE_DECIMAL
+pub trait ToLexical: Primitive {
+    /// Maximum number of bytes required to serialize a number to string.
+    const FORMATTED_SIZE: usize;
+
+    /// Maximum number of bytes required to serialize a number to a decimal string.
+    const FORMATTED_SIZE_DECIMAL: usize;
+
+    /// Byte array of [`FORMATTED_SIZE`] elements, to store a number inline.
+    ///
+    /// [`FORMATTED_SIZE`]: #associatedconstant.FORMATTED_SIZE
+    #[doc(hidden)]
+    type Buffer: FormattedBuffer;
+
     /// Serializer for a number-to-string conversion.
     ///
     /// Returns a subslice of the input buffer containing the written bytes,
@@ -252,7 +377,7 @@ pub trait ToLexical: Number {
     /// the function will not panic, ensure the buffer has at least
     /// [`FORMATTED_SIZE_DECIMAL`] elements.
     ///
-    /// [`FORMATTED_SIZE_DECIMAL`]: trait.Number.html#associatedconstant.FORMATTED_SIZE_DECIMAL
+    /// [`FORMATTED_SIZE_DECIMAL`]: #associatedconstant.FORMATTED_SIZE_DECIMAL
     fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8];
 
      /// Serializer for a number-to-string conversion.
@@ -273,22 +398,68 @@ pub trait ToLexical: Number {
     /// the function will not panic, ensure the buffer has at least
     /// [`FORMATTED_SIZE`] elements.
     ///
-    /// [`FORMATTED_SIZE`]: trait.Number.html#associatedconstant.FORMATTED_SIZE
+    /// [`FORMATTED_SIZE`]: #associatedconstant.FORMATTED_SIZE
     #[cfg(feature = "radix")]
     fn to_lexical_radix<'a>(self, radix: u8, bytes: &'a mut [u8]) -> &'a mut [u8];
+
+    /// Serializer for a number-to-string conversion with custom options.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice. The
+    /// options are used instead of the global configuration, and
+    /// therefore this method is thread-safe.
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `options` - Options to customize number serialization.
+    /// * `bytes`   - Slice containing a numeric string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size. The caller
+    /// must provide a slice of sufficient size. In order to ensure
+    /// the function will not panic, ensure the buffer has at least
+    /// [`FORMATTED_SIZE`] elements. A minimum width may require more
+    /// bytes, see [`integer_buffer_size`] and [`float_buffer_size`].
+    /// Floats with a fixed precision, significant digits, a notation or
+    /// minimum exponent digits require at least [`float_buffer_size`]
+    /// elements, or the [`formatted_len`] of the value.
+    ///
+    /// [`FORMATTED_SIZE`]: #associatedconstant.FORMATTED_SIZE
+    /// [`integer_buffer_size`]: struct.WriteOptions.html#method.integer_buffer_size
+    /// [`float_buffer_size`]: struct.WriteOptions.html#method.float_buffer_size
+    /// [`formatted_len`]: #tymethod.formatted_len
+    fn to_lexical_with_options<'a>(self, options: &WriteOptions, bytes: &'a mut [u8]) -> &'a mut [u8];
+
+    /// Get the buffer size required to serialize any value with custom options.
+    ///
+    /// This is [`integer_buffer_size`] or [`float_buffer_size`].
+    ///
+    /// [`integer_buffer_size`]: struct.WriteOptions.html#method.integer_buffer_size
+    /// [`float_buffer_size`]: struct.WriteOptions.html#method.float_buffer_size
+    fn buffer_size(options: &WriteOptions) -> usize;
+
+    /// Get the number of bytes written by [`to_lexical_with_options`].
+    ///
+    /// The length is computed without writing the number, including any
+    /// sign, group separators and padding to the minimum width.
+    ///
+    /// [`to_lexical_with_options`]: #tymethod.to_lexical_with_options
+    fn formatted_len(self, options: &WriteOptions) -> usize;
 }
 
 // Implement ToLexical for numeric type.
 macro_rules! to_lexical {
-    ($cb:expr, $t:ty) => (
+    ($cb:expr, $len:expr, $size:ident, $t:ty, $radix_size:ident, $decimal_size:ident) => (
         impl ToLexical for $t {
+            const FORMATTED_SIZE: usize = $radix_size;
+            const FORMATTED_SIZE_DECIMAL: usize = $decimal_size;
+            type Buffer = [u8; $radix_size];
+
             #[inline]
             fn to_lexical<'a>(self, bytes: &'a mut [u8])
                 -> &'a mut [u8]
             {
-                assert_buffer!(10, bytes, $t);
-                let len = $cb(self, 10, bytes);
-                &mut index_mut!(bytes[..len])
+                self.to_lexical_with_options(&WriteOptions::new(), bytes)
             }
 
             #[cfg(feature = "radix")]
@@ -296,11 +467,93 @@ macro_rules! to_lexical {
             fn to_lexical_radix<'a>(self, radix: u8, bytes: &'a mut [u8])
                 -> &'a mut [u8]
             {
-                assert_radix!(radix);
-                assert_buffer!(radix, bytes, $t);
-                let len = $cb(self, radix.as_u32(), bytes);
+                self.to_lexical_with_options(&WriteOptions::radix(radix), bytes)
+            }
+
+            #[inline]
+            fn to_lexical_with_options<'a>(self, options: &WriteOptions, bytes: &'a mut [u8])
+                -> &'a mut [u8]
+            {
+                assert_buffer!(options.radix, bytes, $t);
+                assert!(bytes.len() >= options.width);
+                let len = $cb(self, options, bytes);
+                &mut index_mut!(bytes[..len])
+            }
+
+            #[inline]
+            fn buffer_size(options: &WriteOptions)
+                -> usize
+            {
+                options.$size::<$t>()
+            }
+
+            #[inline]
+            fn formatted_len(self, options: &WriteOptions)
+                -> usize
+            {
+                $len(self, options)
+            }
+        }
+    )
+}
+
+// TO LEXICAL EXACT
+
+/// Trait for floats that can be serialized to their exact decimal value.
+pub trait ToLexicalExact: Float {
+    /// Maximum number of bytes required to serialize the exact decimal value.
+    const FORMATTED_SIZE_EXACT: usize;
+
+    /// Serializer for the exact decimal value of a float.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice. Every
+    /// digit of the binary value is written, without rounding, such as
+    /// `0.1000000000000000055511151231257827021181583404541015625` for
+    /// `0.1`. Special values and zero are written like with [`to_lexical`].
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `bytes`   - Slice containing a numeric string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size. The caller
+    /// must provide a slice of sufficient size. In order to ensure
+    /// the function will not panic, ensure the buffer has at least
+    /// [`exact_len`] or [`FORMATTED_SIZE_EXACT`] elements.
+    ///
+    /// [`to_lexical`]: trait.ToLexical.html#tymethod.to_lexical
+    /// [`exact_len`]: #tymethod.exact_len
+    /// [`FORMATTED_SIZE_EXACT`]: #associatedconstant.FORMATTED_SIZE_EXACT
+    fn to_lexical_exact<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8];
+
+    /// Get the number of bytes written by [`to_lexical_exact`].
+    ///
+    /// The length is computed without writing the digits.
+    ///
+    /// [`to_lexical_exact`]: #tymethod.to_lexical_exact
+    fn exact_len(self) -> usize;
+}
+
+// Implement ToLexicalExact for float type.
+macro_rules! to_lexical_exact {
+    ($cb:expr, $len:expr, $t:ty, $size:ident) => (
+        impl ToLexicalExact for $t {
+            const FORMATTED_SIZE_EXACT: usize = $size;
+
+            #[inline]
+            fn to_lexical_exact<'a>(self, bytes: &'a mut [u8])
+                -> &'a mut [u8]
+            {
+                assert!(bytes.len() >= $len(self));
+                let len = $cb(self, bytes);
                 &mut index_mut!(bytes[..len])
             }
+
+            #[inline]
+            fn exact_len(self) -> usize {
+                $len(self)
+            }
         }
     )
 }
@@ -0,0 +1,146 @@
+//! Whitespace handling for number parsing.
+
+use super::algorithm::distance;
+use super::options::ParseOptions;
+
+/// Handling of whitespace surrounding numbers during parsing.
+///
+/// Skipped whitespace is included in the number of processed bytes,
+/// and error indexes always refer to the original input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Whitespace {
+    /// Whitespace is not allowed.
+    Disabled,
+    /// Whitespace before the number is skipped.
+    Leading,
+    /// Whitespace after the number is skipped.
+    Trailing,
+    /// Whitespace before and after the number is skipped.
+    Both,
+}
+
+impl Whitespace {
+    /// Get if whitespace before the number is skipped.
+    #[inline]
+    pub fn is_leading(&self) -> bool {
+        *self == Whitespace::Leading || *self == Whitespace::Both
+    }
+
+    /// Get if whitespace after the number is skipped.
+    #[inline]
+    pub fn is_trailing(&self) -> bool {
+        *self == Whitespace::Trailing || *self == Whitespace::Both
+    }
+}
+
+/// Characters considered whitespace during number parsing.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum WhitespaceSet {
+    /// Space, horizontal tab, line feed, and carriage return.
+    Ascii,
+    /// The `Ascii` characters, vertical tab, and form feed, like C's `isspace`.
+    C,
+}
+
+/// Determine if the character is whitespace in the whitespace set.
+#[inline]
+pub(crate) fn is_whitespace(ch: u8, set: WhitespaceSet) -> bool {
+    match ch {
+        b' ' | b'\t' | b'\n' | b'\r'    => true,
+        b'\x0B' | b'\x0C'               => set == WhitespaceSet::C,
+        _                               => false,
+    }
+}
+
+// Get the number of leading whitespace characters in the set.
+perftools_inline!{
+pub(crate) fn whitespace_count(bytes: &[u8], set: WhitespaceSet)
+    -> usize
+{
+    bytes.iter().take_while(|&&c| is_whitespace(c, set)).count()
+}}
+
+// Skip whitespace before the number, if enabled by the options.
+perftools_inline!{
+pub(crate) fn skip_leading_whitespace<'a>(bytes: &'a [u8], options: &ParseOptions)
+    -> &'a [u8]
+{
+    if options.whitespace.is_leading() {
+        &index!(bytes[whitespace_count(bytes, options.whitespace_set)..])
+    } else {
+        bytes
+    }
+}}
+
+// Trim whitespace from the end of the input, if enabled by the options.
+//
+// Used before matching special values, which must span the entire input.
+perftools_inline!{
+pub(crate) fn trim_trailing_whitespace<'a>(bytes: &'a [u8], options: &ParseOptions)
+    -> &'a [u8]
+{
+    if options.whitespace.is_trailing() {
+        let set = options.whitespace_set;
+        let count = bytes.iter().rev().take_while(|&&c| is_whitespace(c, set)).count();
+        &index!(bytes[..bytes.len() - count])
+    } else {
+        bytes
+    }
+}}
+
+// Skip whitespace after the number, if enabled by the options.
+//
+// `bytes` must start at the number, and `ptr` must point to the end
+// of the parsed number, within `bytes`. Returns a pointer to the end
+// of any trailing whitespace, which is only skipped after a number.
+perftools_inline!{
+pub(crate) fn skip_trailing_whitespace(bytes: &[u8], ptr: *const u8, options: &ParseOptions)
+    -> *const u8
+{
+    if options.whitespace.is_trailing() && ptr != bytes.as_ptr() {
+        let rest = &index!(bytes[distance(bytes.as_ptr(), ptr)..]);
+        index!(rest[whitespace_count(rest, options.whitespace_set)..]).as_ptr()
+    } else {
+        ptr
+    }
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_whitespace_test() {
+        assert!(is_whitespace(b' ', WhitespaceSet::Ascii));
+        assert!(is_whitespace(b'\n', WhitespaceSet::Ascii));
+        assert!(!is_whitespace(b'\x0B', WhitespaceSet::Ascii));
+        assert!(!is_whitespace(b'\x0C', WhitespaceSet::Ascii));
+        assert!(is_whitespace(b'\x0B', WhitespaceSet::C));
+        assert!(is_whitespace(b'\x0C', WhitespaceSet::C));
+        assert!(!is_whitespace(b'0', WhitespaceSet::C));
+        assert!(!is_whitespace(0xA0, WhitespaceSet::C));
+    }
+
+    #[test]
+    fn skip_whitespace_test() {
+        let mut options = ParseOptions::new();
+        let bytes = b" \t42\r\n";
+        assert_eq!(skip_leading_whitespace(bytes, &options), &bytes[..]);
+        assert_eq!(skip_trailing_whitespace(bytes, bytes[4..].as_ptr(), &options), bytes[4..].as_ptr());
+
+        options.set_whitespace(Whitespace::Both);
+        assert_eq!(skip_leading_whitespace(bytes, &options), &bytes[2..]);
+        assert_eq!(skip_trailing_whitespace(bytes, bytes[4..].as_ptr(), &options), bytes[6..].as_ptr());
+        assert_eq!(skip_trailing_whitespace(&bytes[2..], bytes[2..].as_ptr(), &options), bytes[2..].as_ptr());
+        assert_eq!(trim_trailing_whitespace(bytes, &options), &bytes[..4]);
+
+        let bytes = b"\x0B42\x0C";
+        assert_eq!(skip_leading_whitespace(bytes, &options), &bytes[..]);
+        options.set_whitespace_set(WhitespaceSet::C);
+        assert_eq!(skip_leading_whitespace(bytes, &options), &bytes[1..]);
+        assert_eq!(skip_trailing_whitespace(bytes, bytes[3..].as_ptr(), &options), bytes[4..].as_ptr());
+    }
+}
@@ -287,6 +287,7 @@ ops_assign_impl! {
     SubAssign, sub_assign ;
 }
 
+#[allow(deprecated)]
 impl<T: Float> Number for WrappedFloat<T> {
     const FORMATTED_SIZE: usize = T::FORMATTED_SIZE;
     const FORMATTED_SIZE_DECIMAL: usize = T::FORMATTED_SIZE_DECIMAL;
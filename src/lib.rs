@@ -27,6 +27,17 @@
 //! **To String**
 //! - [`to_string`]
 #![cfg_attr(feature = "radix", doc = " - [`to_string_radix`]")]
+//! - [`to_string_with_options`]
+//! - [`to_string_exact`]
+//! - [`write_to_vec`]
+//! - [`write_to_string`]
+//! - [`write_to_fmt`]
+#![cfg_attr(feature = "std", doc = " - [`write_to_io`]")]
+//! - [`write_str`]
+#![cfg_attr(feature = "radix", doc = " - [`write_str_radix`]")]
+//! - [`write_str_with_options`]
+//! - [`formatted_len`]
+//! - [`FormattedNumber`]
 //!
 //! **From String**
 //! - [`parse`]
@@ -37,6 +48,22 @@
 #![cfg_attr(feature = "radix", doc = " - [`parse_lossy_radix`]")]
 //! - [`parse_partial_lossy`]
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_lossy_radix`]")]
+//! - [`parse_with_options`]
+//! - [`parse_partial_with_options`]
+//! - [`parse_partial_with_suffix`]
+//! - [`parse_lossy_with_options`]
+//! - [`parse_partial_lossy_with_options`]
+//!
+//! **16-Bit Floats**
+//! - [`f16`]
+//! - [`bf16`]
+//!
+//! # Options
+//!
+//! The global configuration settings below are shared by every thread,
+//! and changing them is unsafe. To use different settings per call,
+//! create a [`ParseOptions`] or [`WriteOptions`] and pass it to the
+//! `*_with_options` functions.
 //!
 //! # Configuration Settings
 //!
@@ -66,6 +93,27 @@
 #![cfg_attr(feature = "radix", doc = " [`parse_lossy_radix`]: fn.parse_lossy_radix.html")]
 //! [`parse_partial_lossy`]: fn.parse_partial_lossy.html
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_lossy_radix`]: fn.parse_partial_lossy_radix.html")]
+//! [`to_string_with_options`]: fn.to_string_with_options.html
+//! [`to_string_exact`]: fn.to_string_exact.html
+//! [`write_to_vec`]: fn.write_to_vec.html
+//! [`write_to_string`]: fn.write_to_string.html
+//! [`write_to_fmt`]: fn.write_to_fmt.html
+#![cfg_attr(feature = "std", doc = " [`write_to_io`]: fn.write_to_io.html")]
+//! [`write_str`]: fn.write_str.html
+#![cfg_attr(feature = "radix", doc = " [`write_str_radix`]: fn.write_str_radix.html")]
+//! [`write_str_with_options`]: fn.write_str_with_options.html
+//! [`formatted_len`]: fn.formatted_len.html
+//! [`FormattedNumber`]: struct.FormattedNumber.html
+//! [`f16`]: struct.f16.html
+//! [`bf16`]: struct.bf16.html
+//! [`parse_with_options`]: fn.parse_with_options.html
+//! [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+//! [`parse_partial_with_suffix`]: fn.parse_partial_with_suffix.html
+//! [`parse_lossy_with_options`]: fn.parse_lossy_with_options.html
+//! [`parse_partial_lossy_with_options`]: fn.parse_partial_lossy_with_options.html
+//!
+//! [`ParseOptions`]: struct.ParseOptions.html
+//! [`WriteOptions`]: struct.WriteOptions.html
 //!
 //! [`get_exponent_default_char`]: fn.get_exponent_default_char.html
 #![cfg_attr(feature = "radix", doc = " [`get_exponent_backup_char`]: fn.get_exponent_backup_char.html")]
@@ -135,11 +183,55 @@ pub use lexical_core::{set_inf_string, set_infinity_string, set_nan_string};
 #[cfg(all(feature = "correct", feature = "rounding"))]
 pub use lexical_core::{get_float_rounding, set_float_rounding, RoundingKind};
 
+// Re-export the per-call parse and write options.
+pub use lexical_core::{ParseOptions, WriteOptions};
+
+// Re-export the number format.
+#[cfg(feature = "format")]
+pub use lexical_core::NumberFormat;
+
+// Re-export the base prefix handling.
+#[cfg(feature = "radix")]
+pub use lexical_core::BasePrefix;
+
+// Re-export the literal type suffixes.
+pub use lexical_core::{SuffixType, TypeSuffix};
+
+// Re-export the whitespace handling.
+pub use lexical_core::{Whitespace, WhitespaceSet};
+
+// Re-export the special value aliases.
+pub use lexical_core::{SpecialAlias, SpecialKind};
+
+// Re-export the float notation, letter case, trailing zero and alignment.
+pub use lexical_core::{Alignment, LetterCase, Notation, TrailingZero};
+
 // Re-export the Result, Error and ErrorCode globally.
 pub use lexical_core::{Error, ErrorCode, Result};
 
+// Re-export the writers to `fmt::Write` and `io::Write` sinks.
+pub use lexical_core::write_to_fmt;
+
+#[cfg(feature = "std")]
+pub use lexical_core::write_to_io;
+
+// Re-export the writers to string slices of a caller-provided buffer.
+pub use lexical_core::{write_str, write_str_with_options};
+
+#[cfg(feature = "radix")]
+pub use lexical_core::write_str_radix;
+
+// Re-export the number of bytes written with the write options.
+pub use lexical_core::formatted_len;
+
+// Re-export the stack-allocated formatted number.
+pub use lexical_core::FormattedNumber;
+
+// Re-export the 16-bit float types.
+pub use lexical_core::{bf16, f16};
+
 // Publicly expose traits so they may be used for generic programming.
-pub use lexical_core::{FromLexical, FromLexicalLossy, ToLexical};
+pub use lexical_core::{FromLexical, FromLexicalLossy, ToLexical, ToLexicalExact};
 
 // HELPERS
 
@@ -152,6 +244,16 @@ unsafe fn vector_as_slice<'a, T>(buf: &'a mut Vec<T>)
     lib::slice::from_raw_parts_mut(first, buf.capacity())
 }
 
+/// Get the unused capacity of a vector as a slice.
+#[inline]
+unsafe fn vector_spare_slice<'a, T>(buf: &'a mut Vec<T>)
+    -> &'a mut [T]
+{
+    let len = buf.len();
+    let first = buf.as_mut_ptr().offset(len as isize);
+    lib::slice::from_raw_parts_mut(first, buf.capacity() - len)
+}
+
 // HIGH LEVEL
 
 use lib::convert::AsRef;
@@ -574,3 +676,278 @@ pub fn parse_partial_lossy_radix<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Byte
 {
     N::from_lexical_partial_radix(bytes.as_ref(), radix)
 }
+
+/// High-level conversion of a number to string with custom options.
+///
+/// * `n`       - Number to convert to string.
+/// * `options` - Options to control number formatting.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::WriteOptions;
+/// # pub fn main() {
+/// let mut options = WriteOptions::new();
+/// options.set_nan_string(b"nan");
+/// assert_eq!(lexical::to_string_with_options(5, &options), "5");
+/// assert_eq!(lexical::to_string_with_options(::std::f64::NAN, &options), "nan");
+/// # }
+/// ```
+#[inline]
+pub fn to_string_with_options<N: ToLexical>(n: N, options: &WriteOptions) -> lib::String {
+    let mut string = lib::String::new();
+    write_to_string(n, options, &mut string);
+    string
+}
+
+/// Append a number to a byte vector with custom options.
+///
+/// Returns the number of bytes written. The vector only grows if its
+/// spare capacity may be too small, so a single vector may be reused
+/// to write many numbers.
+///
+/// * `n`       - Number to convert to string.
+/// * `options` - Options to control number formatting.
+/// * `vec`     - Vector to append the written bytes to.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::WriteOptions;
+/// # pub fn main() {
+/// let options = WriteOptions::new();
+/// let mut vec = b"[".to_vec();
+/// assert_eq!(lexical::write_to_vec(-5, &options, &mut vec), 2);
+/// vec.push(b',');
+/// assert_eq!(lexical::write_to_vec(1.5, &options, &mut vec), 3);
+/// vec.push(b']');
+/// assert_eq!(vec, b"[-5,1.5]");
+/// # }
+/// ```
+#[inline]
+pub fn write_to_vec<N: ToLexical>(n: N, options: &WriteOptions, vec: &mut lib::Vec<u8>) -> usize {
+    unsafe {
+        let start = vec.len();
+        vec.reserve(N::buffer_size(options));
+        let len = lexical_core::write_with_options(n, options, vector_spare_slice(vec)).len();
+        vec.set_len(start + len);
+        len
+    }
+}
+
+/// Append a number to a string with custom options.
+///
+/// Returns the number of bytes written. The string only grows if its
+/// spare capacity may be too small, so a single string may be reused
+/// to write many numbers.
+///
+/// * `n`       - Number to convert to string.
+/// * `options` - Options to control number formatting.
+/// * `string`  - String to append the written number to.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::WriteOptions;
+/// # pub fn main() {
+/// let options = WriteOptions::new();
+/// let mut string = String::from("x=");
+/// assert_eq!(lexical::write_to_string(42u8, &options, &mut string), 2);
+/// assert_eq!(string, "x=42");
+/// # }
+/// ```
+#[inline]
+pub fn write_to_string<N: ToLexical>(n: N, options: &WriteOptions, string: &mut lib::String) -> usize {
+    unsafe {
+        write_to_vec(n, options, string.as_mut_vec())
+    }
+}
+
+/// High-level conversion of a float to its exact decimal value.
+///
+/// * `n`       - Float to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_exact(0.1f32), "0.100000001490116119384765625");
+/// # }
+/// ```
+#[inline]
+pub fn to_string_exact<N: ToLexicalExact>(n: N) -> lib::String {
+    unsafe {
+        let len = lexical_core::exact_len(n);
+        let mut buf = lib::Vec::<u8>::with_capacity(len);
+        lexical_core::write_exact(n, vector_as_slice(&mut buf));
+        buf.set_len(len);
+        lib::String::from_utf8_unchecked(buf)
+    }
+}
+
+/// High-level conversion of bytes to a number with custom options.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to control number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{ErrorCode, ParseOptions};
+/// # pub fn main() {
+/// // Create our error wrapper.
+/// fn err_code<T>(r: lexical::Result<T>) -> ErrorCode {
+///     r.err().unwrap().code
+/// }
+///
+/// let mut options = ParseOptions::new();
+/// options.set_exponent_default_char(b'^');
+/// assert_eq!(lexical::parse_with_options::<i32, _>("5", &options), Ok(5));
+/// assert_eq!(lexical::parse_with_options::<f32, _>("1.5^2", &options), Ok(150.0));
+/// assert_eq!(err_code(lexical::parse_with_options::<f32, _>("1.5e2", &options)), ErrorCode::InvalidDigit);
+/// # }
+/// ```
+#[inline]
+pub fn parse_with_options<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &ParseOptions)
+    -> Result<N>
+{
+    N::from_lexical_with_options(bytes.as_ref(), options)
+}
+
+/// High-level, partial conversion of bytes to a number with custom options.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to control number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ParseOptions;
+/// # pub fn main() {
+/// let mut options = ParseOptions::new();
+/// options.set_exponent_default_char(b'^');
+/// assert_eq!(lexical::parse_partial_with_options::<i32, _>("5", &options), Ok((5, 1)));
+/// assert_eq!(lexical::parse_partial_with_options::<f32, _>("1.5^2", &options), Ok((150.0, 5)));
+/// assert_eq!(lexical::parse_partial_with_options::<f32, _>("1.5e2", &options), Ok((1.5, 3)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial_with_options<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &ParseOptions)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_with_options(bytes.as_ref(), options)
+}
+
+/// High-level, partial conversion of bytes to a number and literal type suffix.
+///
+/// This functions parses as many digits as possible, and then matches
+/// the longest type suffix from the options after the digits, returning
+/// the parsed value, the number of bytes processed including the suffix,
+/// and the suffix. If the suffix does not fit the number type, or the
+/// value does not fit the type denoted by the suffix, this function
+/// returns the error code and the index at which the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to control number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{ErrorCode, ParseOptions, TypeSuffix};
+/// # pub fn main() {
+/// let mut options = ParseOptions::new();
+/// options.set_type_suffixes(TypeSuffix::KOTLIN);
+/// let (value, count, suffix) = lexical::parse_partial_with_suffix::<f32, _>("1.5f", &options).unwrap();
+/// assert_eq!((value, count), (1.5, 4));
+/// assert_eq!(suffix.unwrap().suffix, b"f");
+///
+/// let result = lexical::parse_partial_with_suffix::<i32, _>("100L", &options);
+/// assert_eq!(result.map_err(|e| e.code), Err(ErrorCode::InvalidTypeSuffix));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial_with_suffix<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &ParseOptions)
+    -> Result<(N, usize, Option<TypeSuffix>)>
+{
+    N::from_lexical_partial_with_suffix(bytes.as_ref(), options)
+}
+
+/// High-level, lossy conversion of bytes to a number with custom options.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse_with_options`].
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to control number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ParseOptions;
+/// # pub fn main() {
+/// let mut options = ParseOptions::new();
+/// options.set_exponent_default_char(b'^');
+/// assert_eq!(lexical::parse_lossy_with_options::<f32, _>("1.5^2", &options), Ok(150.0));
+/// # }
+/// ```
+///
+/// [`parse_with_options`]: fn.parse_with_options.html
+#[inline]
+pub fn parse_lossy_with_options<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &ParseOptions)
+    -> Result<N>
+{
+    N::from_lexical_lossy_with_options(bytes.as_ref(), options)
+}
+
+/// High-level, partial, lossy conversion of bytes to a number with custom options.
+///
+/// This function uses aggressive optimizations to avoid worst-case
+/// scenarios, and can return inaccurate results. For guaranteed accurate
+/// floats, use [`parse_partial_with_options`].
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to control number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ParseOptions;
+/// # pub fn main() {
+/// let mut options = ParseOptions::new();
+/// options.set_exponent_default_char(b'^');
+/// assert_eq!(lexical::parse_partial_lossy_with_options::<f32, _>("1.5^2", &options), Ok((150.0, 5)));
+/// # }
+/// ```
+///
+/// [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+#[inline]
+pub fn parse_partial_lossy_with_options<N: FromLexicalLossy, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &ParseOptions)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_lossy_with_options(bytes.as_ref(), options)
+}